@@ -311,25 +311,28 @@ impl FlexibleTestFramework {
         use savant_ocr::{ImageMetadata, StructuredContent, TextBlock, BoundingBox, TextType};
         use chrono::Utc;
         
+        let text_blocks = vec![
+            TextBlock {
+                text: "HackerRank Challenge".to_string(),
+                confidence: 0.8,
+                bounding_box: BoundingBox { x: 100, y: 100, width: 300, height: 40 },
+                font_info: None,
+                semantic_type: TextType::DocumentContent,
+                language: Some("en".to_string()),
+            },
+            TextBlock {
+                text: "Algorithm Problem".to_string(),
+                confidence: 0.7,
+                bounding_box: BoundingBox { x: 100, y: 150, width: 200, height: 30 },
+                font_info: None,
+                semantic_type: TextType::DocumentContent,
+                language: Some("en".to_string()),
+            }
+        ];
+        let (confidence_distribution, low_confidence_ratio) = OCRResult::confidence_stats(&text_blocks);
+
         Ok(OCRResult {
-            text_blocks: vec![
-                TextBlock {
-                    text: "HackerRank Challenge".to_string(),
-                    confidence: 0.8,
-                    bounding_box: BoundingBox { x: 100, y: 100, width: 300, height: 40 },
-                    font_info: None,
-                    semantic_type: TextType::DocumentContent,
-                    language: Some("en".to_string()),
-                },
-                TextBlock {
-                    text: "Algorithm Problem".to_string(),
-                    confidence: 0.7,
-                    bounding_box: BoundingBox { x: 100, y: 150, width: 200, height: 30 },
-                    font_info: None,
-                    semantic_type: TextType::DocumentContent,
-                    language: Some("en".to_string()),
-                }
-            ],
+            text_blocks,
             structured_content: StructuredContent::default(),
             overall_confidence: 0.75,
             processing_time_ms: 1000,
@@ -341,6 +344,8 @@ impl FlexibleTestFramework {
                 file_size: Some(1024000),
                 timestamp: Utc::now(),
             },
+            confidence_distribution,
+            low_confidence_ratio,
         })
     }
 
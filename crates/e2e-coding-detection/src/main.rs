@@ -13,16 +13,12 @@ Usage: cargo run --bin test_e2e_coding_detection
 use anyhow::Result;
 use std::path::Path;
 use tokio;
-use chrono::Utc;
 use image::open as load_image;
-use uuid::Uuid;
 
 // Import all the modules we need
-use savant_ocr::{OCRProcessor, OCRConfig, OCRResult, TextBlock};
+use savant_ocr::{OCRProcessor, OCRConfig, OCRResult, ComprehensiveOCRProcessor, PreprocessingConfig};
 use savant_vision::{VisionAnalyzer, VisionConfig, ScreenAnalysis, ActivityClassification};
 use savant_video::{CodingProblemDetector, DetectionConfig, SolutionGenerator, SolutionConfig, DetectedCodingProblem, GeneratedSolution};
-use savant_video::coding_problem_detector::{CodingProblemType, ProgrammingLanguage, ScreenRegion, CodeContext, CodingPlatform};
-use savant_video::solution_generator::TestValidationResult;
 use savant_video::llm_provider::LLMProvider;
 use savant_db::{TranscriptDatabase, visual_data::VisualDataManager};
 
@@ -69,7 +65,8 @@ async fn main() -> Result<()> {
     println!("\n🧩 Step 3: Coding Problem Detection");
     println!("----------------------------------");
     
-    let detected_problem = detect_coding_problem(&ocr_result, &vision_result).await?;
+    let comprehensive_ocr_result = perform_comprehensive_ocr_analysis(screenshot_path).await?;
+    let detected_problem = detect_coding_problem(&comprehensive_ocr_result, &vision_result).await?;
     
     let problem = if let Some(problem) = detected_problem {
         println!("✅ Coding problem detected!");
@@ -171,58 +168,28 @@ async fn perform_vision_analysis(image_path: &str) -> Result<ScreenAnalysis> {
     Ok(result)
 }
 
-async fn detect_coding_problem(ocr_result: &OCRResult, vision_result: &ScreenAnalysis) -> Result<Option<DetectedCodingProblem>> {
+async fn perform_comprehensive_ocr_analysis(image_path: &str) -> Result<savant_ocr::ComprehensiveOCRResult> {
+    let mut processor = ComprehensiveOCRProcessor::new(PreprocessingConfig::default());
+    let image = load_image(image_path)?;
+    processor.process_image(&image).await
+}
+
+async fn detect_coding_problem(
+    ocr_result: &savant_ocr::ComprehensiveOCRResult,
+    vision_result: &ScreenAnalysis,
+) -> Result<Option<DetectedCodingProblem>> {
     println!("  🕵️  Analyzing for coding problems...");
-    
+
+    // Let the real detector judge this from structural signals (problem
+    // statement, constraints, example input/output, platform patterns)
+    // instead of checking for one hardcoded problem's keywords.
     let config = DetectionConfig::default();
-    let detector = CodingProblemDetector::new(config);
-    
-    // Look for coding problem indicators in the text
-    let all_text = ocr_result.text_blocks
-        .iter()
-        .map(|block| block.text.as_str())
-        .collect::<Vec<_>>()
-        .join(" ");
-    
-    println!("  📝 Analyzing extracted text: '{}'", 
-        if all_text.len() > 100 { &all_text[..100] } else { &all_text });
-    
-    // Check for problem indicators
-    let has_two_sum = all_text.to_lowercase().contains("two sum");
-    let has_array = all_text.to_lowercase().contains("array");
-    let has_target = all_text.to_lowercase().contains("target");
-    let has_leetcode = all_text.to_lowercase().contains("leetcode");
-    
-    if has_two_sum || (has_array && has_target) {
-        println!("  ✅ Two Sum problem detected!");
-        
-        let problem = DetectedCodingProblem {
-            id: "twosum-detection-1".to_string(),
-            problem_type: CodingProblemType::AlgorithmChallenge,
-            title: "Two Sum".to_string(),
-            description: "Find two numbers in an array that add up to a target sum".to_string(),
-            code_context: CodeContext {
-                visible_code: extract_code_from_ocr(ocr_result),
-                focused_function: None,
-                imports: vec![],
-                class_context: None,
-                line_numbers: None,
-                cursor_position: None,
-                selected_text: None,
-            },
-            error_details: None,
-            platform: if has_leetcode { Some(CodingPlatform::LeetCode) } else { None },
-            language: ProgrammingLanguage::Python,
-            starter_code: None,
-            test_cases: vec![],
-            constraints: vec!["Array length: 2 ≤ nums.length ≤ 10^4".to_string()],
-            confidence: if has_two_sum { 0.95 } else { 0.78 },
-            detected_at: Utc::now(),
-            screen_region: ScreenRegion {
-                x: 0, y: 0, width: 1920, height: 1080
-            },
-        };
-        
+    let mut detector = CodingProblemDetector::new(config);
+
+    let problems = detector.detect_problems(ocr_result, vision_result).await?;
+
+    if let Some(problem) = problems.into_iter().next() {
+        println!("  ✅ {} detected!", problem.problem_type.to_string());
         Ok(Some(problem))
     } else {
         println!("  ❌ No recognizable coding problem detected");
@@ -230,90 +197,17 @@ async fn detect_coding_problem(ocr_result: &OCRResult, vision_result: &ScreenAna
     }
 }
 
-fn extract_code_from_ocr(ocr_result: &OCRResult) -> String {
-    ocr_result.text_blocks
-        .iter()
-        .filter(|block| {
-            use savant_ocr::TextType;
-            matches!(block.semantic_type, TextType::CodeSnippet)
-        })
-        .map(|block| block.text.as_str())
-        .collect::<Vec<_>>()
-        .join("\n")
-}
-
 async fn generate_solution(problem: &DetectedCodingProblem) -> Result<GeneratedSolution> {
     println!("  🧠 Generating solution with LLM...");
-    
+
     let config = SolutionConfig::default();
     let llm_provider = LLMProvider::new_ollama("http://localhost:11434".to_string(), Some("llama3.2".to_string()));
     let generator = SolutionGenerator::new(config, llm_provider);
-    
-    // Create a comprehensive prompt for the Two Sum problem
-    let prompt = format!(
-        "Solve this coding problem: {}\n\nDescription: {}\n\nRequirements:\n- Provide a complete solution in Python\n- Include time and space complexity analysis\n- Add a clear explanation\n- Make it efficient and readable",
-        problem.title, problem.description
-    );
-    
-    println!("  💭 LLM prompt: {}", &prompt[..100]);
-    
-    // Mock LLM response for demonstration (in reality this would call the actual LLM)
-    let solution = GeneratedSolution {
-        id: Uuid::new_v4().to_string(),
-        problem_id: problem.id.clone(),
-        solution_code: r#"def twoSum(nums, target):
-    """
-    Find two numbers in the array that add up to target.
-    
-    Args:
-        nums: List of integers
-        target: Target sum
-        
-    Returns:
-        List of two indices that add up to target
-    """
-    num_map = {}
-    
-    for i, num in enumerate(nums):
-        complement = target - num
-        if complement in num_map:
-            return [num_map[complement], i]
-        num_map[num] = i
-    
-    return []  # No solution found"#.to_string(),
-        language: ProgrammingLanguage::Python,
-        explanation: Some("This solution uses a hash map to store numbers we've seen and their indices. For each number, we calculate its complement (target - current number) and check if we've seen it before. If yes, we found our pair! Time complexity: O(n), Space complexity: O(n).".to_string()),
-        time_complexity: Some("O(n)".to_string()),
-        space_complexity: Some("O(n)".to_string()),
-        test_results: vec![
-            TestValidationResult {
-                test_case_id: "test1".to_string(),
-                input: "[2,7,11,15], target=9".to_string(),
-                expected_output: "[0,1]".to_string(),
-                actual_output: "[0,1]".to_string(),
-                passed: true,
-                execution_time_ms: Some(1),
-                error_message: None,
-            },
-            TestValidationResult {
-                test_case_id: "test2".to_string(),
-                input: "[3,2,4], target=6".to_string(),
-                expected_output: "[1,2]".to_string(),
-                actual_output: "[1,2]".to_string(),
-                passed: true,
-                execution_time_ms: Some(1),
-                error_message: None,
-            },
-        ],
-        confidence_score: 0.92,
-        generation_time_ms: 1500,
-        model_used: "mock-llm".to_string(),
-        alternative_solutions: vec![],
-        generated_at: Utc::now(),
-    };
-    
-    println!("  ✅ Solution generated successfully");
-    
+
+    let solution = generator.generate_solution(problem).await?;
+
+    println!("  ✅ Solution generated successfully using model: {}", solution.model_used);
+
     Ok(solution)
 }
 
@@ -219,7 +219,9 @@ fn create_mock_ocr_result() -> OCRResult {
             language: Some("en".to_string()),
         },
     ];
-    
+
+    let (confidence_distribution, low_confidence_ratio) = OCRResult::confidence_stats(&text_blocks);
+
     OCRResult {
         text_blocks,
         structured_content: StructuredContent::default(),
@@ -233,6 +235,8 @@ fn create_mock_ocr_result() -> OCRResult {
             file_size: Some(2048000),
             timestamp: Utc::now(),
         },
+        confidence_distribution,
+        low_confidence_ratio,
     }
 }
 
@@ -256,6 +260,7 @@ fn create_mock_vision_analysis() -> ScreenAnalysis {
                 is_interactive: true,
                 state: Some("active".to_string()),
                 app_context: Some("browser".to_string()),
+                is_sensitive: false,
             },
             confidence: 0.92,
         },
@@ -268,6 +273,7 @@ fn create_mock_vision_analysis() -> ScreenAnalysis {
                 is_interactive: false,
                 state: None,
                 app_context: Some("leetcode".to_string()),
+                is_sensitive: false,
             },
             confidence: 0.89,
         },
@@ -423,18 +423,21 @@ async fn store_results_with_schema_init(
 
 fn create_fallback_ocr_result() -> OCRResult {
     use savant_ocr::{ImageMetadata, StructuredContent, BoundingBox, TextType};
-    
+
+    let text_blocks = vec![
+        TextBlock {
+            text: "Two Sum Problem".to_string(),
+            confidence: 0.95,
+            bounding_box: BoundingBox { x: 100, y: 100, width: 200, height: 30 },
+            font_info: None,
+            semantic_type: TextType::DocumentContent,
+            language: Some("en".to_string()),
+        }
+    ];
+    let (confidence_distribution, low_confidence_ratio) = OCRResult::confidence_stats(&text_blocks);
+
     OCRResult {
-        text_blocks: vec![
-            TextBlock {
-                text: "Two Sum Problem".to_string(),
-                confidence: 0.95,
-                bounding_box: BoundingBox { x: 100, y: 100, width: 200, height: 30 },
-                font_info: None,
-                semantic_type: TextType::DocumentContent,
-                language: Some("en".to_string()),
-            }
-        ],
+        text_blocks,
         structured_content: StructuredContent::default(),
         overall_confidence: 0.95,
         processing_time_ms: 100,
@@ -446,6 +449,8 @@ fn create_fallback_ocr_result() -> OCRResult {
             file_size: Some(1024000),
             timestamp: Utc::now(),
         },
+        confidence_distribution,
+        low_confidence_ratio,
     }
 }
 
@@ -466,6 +471,7 @@ fn create_fallback_vision_analysis() -> Result<ScreenAnalysis> {
                     is_interactive: true,
                     state: Some("active".to_string()),
                     app_context: Some("browser".to_string()),
+                    is_sensitive: false,
                 },
                 confidence: 0.9,
             }
@@ -10,6 +10,39 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
+/// Resolve a configured `device_id` against the names of enumerated devices.
+///
+/// Tries an exact match first (CPAL's `AudioDevice::id` is just its name), then falls
+/// back to a case-insensitive substring match so human-typed names like `--device airpods`
+/// still resolve. Returns an error listing the available names if nothing matches.
+fn resolve_device_index(device_id: &str, device_names: &[String]) -> Result<usize> {
+    if let Some(index) = device_names.iter().position(|name| name == device_id) {
+        return Ok(index);
+    }
+
+    let needle = device_id.to_lowercase();
+    if let Some(index) = device_names
+        .iter()
+        .position(|name| name.to_lowercase().contains(&needle))
+    {
+        return Ok(index);
+    }
+
+    Err(anyhow!(
+        "Device not found: {}. Available devices: {}",
+        device_id,
+        device_names.join(", ")
+    ))
+}
+
+/// Mix time-aligned mic and system audio chunks into mono, applying per-source gain.
+fn mix_chunk(mic: &[f32], system: &[f32], mic_gain: f32, system_gain: f32) -> Vec<f32> {
+    mic.iter()
+        .zip(system.iter())
+        .map(|(m, s)| (m * mic_gain + s * system_gain).clamp(-1.0, 1.0))
+        .collect()
+}
+
 pub struct CpalAudioCapture {
     host: Host,
 }
@@ -148,13 +181,18 @@ impl AudioCapture for CpalAudioCapture {
     }
 
     async fn start_capture(&self, config: AudioConfig) -> Result<AudioStream> {
-        let device = if let Some(device_name) = &config.device_id {
-            // Find device by name
-            self.host
+        let device = if let Some(device_id) = &config.device_id {
+            let input_devices: Vec<Device> = self
+                .host
                 .input_devices()
                 .map_err(|e| anyhow!("Failed to enumerate devices: {}", e))?
-                .find(|d| d.name().unwrap_or_default() == *device_name)
-                .ok_or_else(|| anyhow!("Device not found: {}", device_name))?
+                .collect();
+            let names: Vec<String> = input_devices
+                .iter()
+                .map(|d| d.name().unwrap_or_default())
+                .collect();
+            let index = resolve_device_index(device_id, &names)?;
+            input_devices.into_iter().nth(index).expect("index returned by resolve_device_index is in bounds")
         } else {
             // Use default input device
             self.host
@@ -224,6 +262,7 @@ impl AudioCapture for CpalAudioCapture {
                                 timestamp: chrono::Utc::now(),
                                 sample_rate: callback_sample_rate,
                                 channels: callback_channels,
+                                source: None,
                             };
                             
                             if let Err(e) = tx.try_send(sample) {
@@ -252,6 +291,7 @@ impl AudioCapture for CpalAudioCapture {
                                 timestamp: chrono::Utc::now(),
                                 sample_rate: callback_sample_rate,
                                 channels: callback_channels,
+                                source: None,
                             };
                             
                             if let Err(e) = tx.try_send(sample) {
@@ -284,7 +324,13 @@ impl AudioCapture for CpalAudioCapture {
             return crate::macos::start_system_audio_capture(config).await;
         }
 
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        {
+            // Use Linux-specific PulseAudio/PipeWire monitor source capture
+            return crate::linux::start_system_audio_capture(config).await;
+        }
+
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
         {
             // For other platforms, try to use loopback device or return error
             warn!("System audio capture not fully implemented for this platform");
@@ -314,6 +360,91 @@ impl AudioCapture for CpalAudioCapture {
             ))
         }
     }
+
+    async fn start_dual_capture(&self, config: AudioConfig) -> Result<AudioStream> {
+        let mic_stream = self.start_capture(config.clone()).await?;
+        let system_stream = self.start_system_capture(config.clone()).await?;
+
+        let AudioStream {
+            receiver: mut mic_rx,
+            handle: mic_handle,
+        } = mic_stream;
+        let AudioStream {
+            receiver: mut system_rx,
+            handle: system_handle,
+        } = system_stream;
+
+        let (tx, rx) = mpsc::channel(100);
+        let running = Arc::new(AtomicBool::new(true));
+        let running_task = running.clone();
+
+        let mic_gain = config.mic_gain;
+        let system_gain = config.system_gain;
+        let fallback_sample_rate = config.sample_rate;
+        let fallback_channels = config.channels;
+
+        tokio::spawn(async move {
+            let mut mic_buf: Vec<f32> = Vec::new();
+            let mut system_buf: Vec<f32> = Vec::new();
+            let mut sample_rate = fallback_sample_rate;
+            let mut channels = fallback_channels;
+
+            loop {
+                if !running_task.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                tokio::select! {
+                    mic = mic_rx.recv() => match mic {
+                        Some(sample) => {
+                            sample_rate = sample.sample_rate;
+                            channels = sample.channels;
+                            mic_buf.extend_from_slice(&sample.data);
+                        }
+                        None => break,
+                    },
+                    system = system_rx.recv() => match system {
+                        Some(sample) => {
+                            sample_rate = sample.sample_rate;
+                            channels = sample.channels;
+                            system_buf.extend_from_slice(&sample.data);
+                        }
+                        None => break,
+                    },
+                }
+
+                // Time-align: only emit as many mixed samples as both sources have produced so far.
+                let aligned = mic_buf.len().min(system_buf.len());
+                if aligned == 0 {
+                    continue;
+                }
+
+                let mixed = mix_chunk(&mic_buf[..aligned], &system_buf[..aligned], mic_gain, system_gain);
+                mic_buf.drain(0..aligned);
+                system_buf.drain(0..aligned);
+
+                let mixed_sample = AudioSample {
+                    data: mixed,
+                    timestamp: chrono::Utc::now(),
+                    sample_rate,
+                    channels,
+                    source: Some("mixed(mic,system)".to_string()),
+                };
+
+                if tx.send(mixed_sample).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let dual_control = Arc::new(DualStreamControl {
+            mic: mic_handle,
+            system: system_handle,
+            running,
+        });
+
+        Ok(AudioStream::new(rx, dual_control))
+    }
 }
 
 struct CpalStreamControl {
@@ -344,4 +475,104 @@ impl StreamControl for CpalStreamControl {
     fn is_running(&self) -> bool {
         self.running.load(Ordering::Relaxed)
     }
+}
+
+/// Forwards stream control to both underlying mic and system streams of a dual capture.
+struct DualStreamControl {
+    mic: Arc<dyn StreamControl>,
+    system: Arc<dyn StreamControl>,
+    running: Arc<AtomicBool>,
+}
+
+#[async_trait(?Send)]
+impl StreamControl for DualStreamControl {
+    async fn stop(&self) -> Result<()> {
+        self.running.store(false, Ordering::Relaxed);
+        self.mic.stop().await?;
+        self.system.stop().await?;
+        info!("Stopped dual audio capture stream");
+        Ok(())
+    }
+
+    async fn pause(&self) -> Result<()> {
+        self.mic.pause().await?;
+        self.system.pause().await?;
+        debug!("Paused dual audio capture stream");
+        Ok(())
+    }
+
+    async fn resume(&self) -> Result<()> {
+        self.mic.resume().await?;
+        self.system.resume().await?;
+        debug!("Resumed dual audio capture stream");
+        Ok(())
+    }
+
+    fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_device_names() -> Vec<String> {
+        vec![
+            "MacBook Pro Microphone".to_string(),
+            "External USB Microphone".to_string(),
+            "AirPods Pro".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_resolve_device_index_exact_match() {
+        let names = mock_device_names();
+        let index = resolve_device_index("AirPods Pro", &names).unwrap();
+        assert_eq!(index, 2);
+    }
+
+    #[test]
+    fn test_resolve_device_index_case_insensitive_substring() {
+        let names = mock_device_names();
+        let index = resolve_device_index("airpods", &names).unwrap();
+        assert_eq!(index, 2);
+    }
+
+    #[test]
+    fn test_mix_chunk_contains_both_source_signals() {
+        // Two synthetic sources: a constant mic tone and a constant system tone.
+        let mic = vec![0.2; 4];
+        let system = vec![0.6; 4];
+
+        let mixed = mix_chunk(&mic, &system, 1.0, 1.0);
+
+        assert_eq!(mixed.len(), 4);
+        for sample in &mixed {
+            // The mixed signal reflects the contribution of both sources, not either alone.
+            assert!((sample - 0.8).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_mix_chunk_applies_per_source_gain() {
+        let mic = vec![1.0; 2];
+        let system = vec![1.0; 2];
+
+        let mixed = mix_chunk(&mic, &system, 0.25, 0.75);
+
+        for sample in &mixed {
+            assert!((sample - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_resolve_device_index_no_match_lists_available_devices() {
+        let names = mock_device_names();
+        let err = resolve_device_index("nonexistent device", &names).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("MacBook Pro Microphone"));
+        assert!(message.contains("External USB Microphone"));
+        assert!(message.contains("AirPods Pro"));
+    }
 }
\ No newline at end of file
@@ -27,6 +27,7 @@ impl AudioConverter {
             timestamp: sample.timestamp,
             sample_rate: sample.sample_rate,
             channels: sample.channels,
+            source: sample.source.clone(),
         }
     }
 
@@ -43,6 +44,7 @@ impl AudioConverter {
             timestamp: sample.timestamp,
             sample_rate: target_rate,
             channels: sample.channels,
+            source: sample.source.clone(),
         }
     }
 
@@ -72,6 +74,7 @@ impl AudioConverter {
             timestamp: sample.timestamp,
             sample_rate: sample.sample_rate,
             channels: 1,
+            source: sample.source.clone(),
         }
     }
 
@@ -137,6 +140,7 @@ impl WavUtils {
             timestamp: chrono::Utc::now(),
             sample_rate: spec.sample_rate,
             channels: spec.channels,
+            source: None,
         })
     }
 
@@ -183,6 +187,7 @@ impl WavUtils {
             timestamp: chrono::Utc::now(),
             sample_rate: spec.sample_rate,
             channels: spec.channels,
+            source: None,
         })
     }
 }
@@ -235,6 +240,7 @@ impl AudioBuffer {
             timestamp: chrono::Utc::now(),
             sample_rate: self.config.sample_rate,
             channels: self.config.channels,
+            source: None,
         }
     }
 
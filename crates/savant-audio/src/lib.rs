@@ -18,6 +18,8 @@ pub mod formats;
 
 #[cfg(target_os = "macos")]
 pub mod macos;
+#[cfg(target_os = "linux")]
+pub mod linux;
 
 pub use capture::*;
 pub use devices::*;
@@ -60,6 +62,10 @@ pub struct AudioConfig {
     pub buffer_size: usize,
     pub format: SampleFormat,
     pub capture_system_audio: bool,
+    /// Gain applied to the microphone source in `start_dual_capture`.
+    pub mic_gain: f32,
+    /// Gain applied to the system audio source in `start_dual_capture`.
+    pub system_gain: f32,
 }
 
 impl Default for AudioConfig {
@@ -71,6 +77,8 @@ impl Default for AudioConfig {
             buffer_size: 4096,
             format: SAMPLE_FORMAT,
             capture_system_audio: false,
+            mic_gain: 1.0,
+            system_gain: 1.0,
         }
     }
 }
@@ -82,6 +90,11 @@ pub struct AudioSample {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub sample_rate: u32,
     pub channels: u16,
+    /// Which capture source (or mix of sources) this sample came from. Only
+    /// `start_dual_capture` sets it, to "mixed(mic,system)"; samples from `start_capture`
+    /// and `start_system_capture` carry `None`.
+    #[serde(default)]
+    pub source: Option<String>,
 }
 
 /// Audio capture stream handle
@@ -126,6 +139,10 @@ pub trait AudioCapture {
 
     /// Start system audio capture (requires permissions)
     async fn start_system_capture(&self, config: AudioConfig) -> Result<AudioStream>;
+
+    /// Start simultaneous microphone and system audio capture, mixed down into a single
+    /// mono stream using `config.mic_gain` / `config.system_gain`.
+    async fn start_dual_capture(&self, config: AudioConfig) -> Result<AudioStream>;
 }
 
 /// Create platform-specific audio capture instance
@@ -160,12 +177,43 @@ pub fn prepare_for_whisper(samples: &[f32], source_rate: u32, target_rate: u32)
     output
 }
 
+/// Root-mean-square amplitude of a chunk of samples, used for silence detection.
+fn rms_amplitude(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_squares: f32 = samples.iter().map(|s| s * s).sum();
+    (sum_squares / samples.len() as f32).sqrt()
+}
+
+/// Duration in seconds represented by a sample chunk, given its own rate and channel count.
+fn chunk_seconds(sample: &AudioSample) -> f32 {
+    let frames_per_second = (sample.sample_rate as f32 * sample.channels as f32).max(1.0);
+    sample.data.len() as f32 / frames_per_second
+}
+
 /// Audio buffer configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioBufferConfig {
     pub sample_rate: u32,
     pub channels: u16,
     pub max_duration_seconds: f32,
+    /// RMS amplitude below which a chunk is considered silence. `0.0` disables silence gating.
+    pub silence_threshold: f32,
+    /// How long silence must persist before further silent chunks are dropped instead of buffered.
+    pub max_silence_seconds: f32,
+}
+
+impl Default for AudioBufferConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: SAMPLE_RATE,
+            channels: CHANNELS,
+            max_duration_seconds: 30.0,
+            silence_threshold: 0.0,
+            max_silence_seconds: f32::MAX,
+        }
+    }
 }
 
 /// Audio buffer for accumulating samples
@@ -173,6 +221,7 @@ pub struct AudioBuffer {
     config: AudioBufferConfig,
     samples: Vec<f32>,
     max_samples: usize,
+    silence_seconds: f32,
 }
 
 impl AudioBuffer {
@@ -182,14 +231,28 @@ impl AudioBuffer {
             config,
             samples: Vec::with_capacity(max_samples),
             max_samples,
+            silence_seconds: 0.0,
         }
     }
 
     pub fn push(&mut self, sample: &AudioSample) {
+        if self.config.silence_threshold > 0.0 {
+            if rms_amplitude(&sample.data) < self.config.silence_threshold {
+                self.silence_seconds += chunk_seconds(sample);
+                if self.silence_seconds >= self.config.max_silence_seconds {
+                    // Sustained silence: drop this chunk rather than buffering it.
+                    return;
+                }
+            } else {
+                // Speech resumed; start a fresh segment.
+                self.silence_seconds = 0.0;
+            }
+        }
+
         // Ensure we don't exceed max capacity
         let remaining_capacity = self.max_samples.saturating_sub(self.samples.len());
         let samples_to_add = sample.data.len().min(remaining_capacity);
-        
+
         self.samples.extend_from_slice(&sample.data[..samples_to_add]);
     }
 
@@ -199,6 +262,7 @@ impl AudioBuffer {
             timestamp: chrono::Utc::now(),
             sample_rate: self.config.sample_rate,
             channels: self.config.channels,
+            source: None,
         }
     }
 
@@ -213,4 +277,67 @@ impl AudioBuffer {
     pub fn is_empty(&self) -> bool {
         self.samples.is_empty()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(data: Vec<f32>) -> AudioSample {
+        AudioSample {
+            data,
+            timestamp: chrono::Utc::now(),
+            sample_rate: 16000,
+            channels: 1,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_silence_gating_drops_sustained_silence() {
+        let mut buffer = AudioBuffer::new(AudioBufferConfig {
+            sample_rate: 16000,
+            channels: 1,
+            max_duration_seconds: 10.0,
+            silence_threshold: 0.1,
+            max_silence_seconds: 0.01,
+        });
+
+        let speech_chunk = vec![0.5; 160]; // loud, 10ms at 16kHz
+        let silence_chunk = vec![0.0; 160]; // silent, 10ms at 16kHz
+
+        buffer.push(&sample(speech_chunk.clone()));
+        assert_eq!(buffer.len(), 160);
+
+        // The first silent chunk (10ms) already brings accumulated silence up to
+        // max_silence_seconds, so it is dropped, as is all silence after it.
+        buffer.push(&sample(silence_chunk.clone()));
+        let len_after_first_silence = buffer.len();
+
+        buffer.push(&sample(silence_chunk.clone()));
+        buffer.push(&sample(silence_chunk.clone()));
+        assert_eq!(
+            buffer.len(),
+            len_after_first_silence,
+            "sustained silence beyond max_silence_seconds should not accumulate"
+        );
+
+        // Speech resumes: a fresh segment starts and is buffered again.
+        buffer.push(&sample(speech_chunk.clone()));
+        assert_eq!(buffer.len(), len_after_first_silence + speech_chunk.len());
+    }
+
+    #[test]
+    fn test_silence_gating_disabled_by_default() {
+        let mut buffer = AudioBuffer::new(AudioBufferConfig {
+            sample_rate: 16000,
+            channels: 1,
+            max_duration_seconds: 10.0,
+            ..Default::default()
+        });
+
+        buffer.push(&sample(vec![0.0; 160]));
+        buffer.push(&sample(vec![0.0; 160]));
+        assert_eq!(buffer.len(), 320, "silence_threshold of 0.0 should disable gating");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,122 @@
+//! Linux-specific audio capture implementation using PulseAudio/PipeWire monitor sources
+
+use crate::{AudioCapture, AudioConfig, AudioStream, StreamControl};
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Linux system audio capture via a PulseAudio/PipeWire monitor source
+pub struct LinuxSystemCapture {
+    running: Arc<AtomicBool>,
+}
+
+impl LinuxSystemCapture {
+    pub fn new() -> Self {
+        Self {
+            running: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StreamControl for LinuxSystemCapture {
+    async fn stop(&self) -> Result<()> {
+        self.running.store(false, Ordering::Relaxed);
+        info!("Stopped Linux system audio capture");
+        Ok(())
+    }
+
+    async fn pause(&self) -> Result<()> {
+        self.running.store(false, Ordering::Relaxed);
+        debug!("Paused Linux system audio capture");
+        Ok(())
+    }
+
+    async fn resume(&self) -> Result<()> {
+        self.running.store(true, Ordering::Relaxed);
+        debug!("Resumed Linux system audio capture");
+        Ok(())
+    }
+
+    fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+}
+
+/// Start system audio capture on Linux by capturing from a PulseAudio/PipeWire monitor source
+pub async fn start_system_audio_capture(config: AudioConfig) -> Result<AudioStream> {
+    info!("Starting Linux system audio capture via PulseAudio/PipeWire monitor");
+
+    let cpal_capture = crate::capture::CpalAudioCapture::new()?;
+    let monitor_device = find_monitor_device(&cpal_capture).await?;
+
+    info!("Using monitor source for system audio: {}", monitor_device.name);
+
+    let mut system_config = config;
+    system_config.device_id = Some(monitor_device.id.clone());
+
+    let stream = cpal_capture.start_capture(system_config).await?;
+
+    let linux_control = Arc::new(LinuxSystemCapture::new());
+    linux_control.running.store(true, Ordering::Relaxed);
+
+    Ok(AudioStream::new(stream.receiver, linux_control))
+}
+
+/// Find a PulseAudio/PipeWire monitor source among enumerated devices
+async fn find_monitor_device(cpal_capture: &crate::capture::CpalAudioCapture) -> Result<crate::AudioDevice> {
+    let devices = cpal_capture.list_devices().await?;
+
+    devices
+        .into_iter()
+        .find(|d| is_monitor_source_name(&d.name))
+        .ok_or_else(|| {
+            anyhow!(
+                "No PulseAudio/PipeWire monitor source found. System audio capture on Linux \
+                 requires a '.monitor' source for the default sink. Check availability with \
+                 `pactl list sources short`, or enable one with \
+                 `pactl load-module module-loopback`."
+            )
+        })
+}
+
+/// Whether a device name looks like a PulseAudio/PipeWire monitor source
+fn is_monitor_source_name(name: &str) -> bool {
+    let name_lower = name.to_lowercase();
+    name_lower.contains(".monitor") || name_lower.contains("monitor of")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_monitor_source_name_matches_pulseaudio_naming() {
+        assert!(is_monitor_source_name(
+            "alsa_output.pci-0000_00_1f.3.analog-stereo.monitor"
+        ));
+        assert!(is_monitor_source_name("Monitor of Built-in Audio Analog Stereo"));
+        assert!(!is_monitor_source_name("Built-in Microphone"));
+    }
+
+    #[tokio::test]
+    async fn test_find_monitor_device_lists_and_opens_capture_if_present() {
+        let cpal_capture = match crate::capture::CpalAudioCapture::new() {
+            Ok(c) => c,
+            Err(_) => return, // No audio host available in this environment.
+        };
+        let devices = match cpal_capture.list_devices().await {
+            Ok(d) => d,
+            Err(_) => return,
+        };
+        if !devices.iter().any(|d| is_monitor_source_name(&d.name)) {
+            // No monitor source on this machine/CI runner; nothing to assert.
+            return;
+        }
+
+        let stream = start_system_audio_capture(AudioConfig::default()).await;
+        assert!(stream.is_ok());
+    }
+}
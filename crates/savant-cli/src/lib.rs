@@ -0,0 +1,147 @@
+//! Command resolution for the `savant` meta-CLI.
+//!
+//! Rather than refactor every tool's clap parsing into a shared library (which would
+//! couple their release cadence and argument evolution together), `savant` dispatches
+//! each subcommand to the existing standalone binary (`savant-db`, `savant-ocr`, ...) as
+//! a subprocess, forwarding the remaining arguments unchanged. This keeps each tool
+//! independently buildable and scriptable on its own, while giving users one name to
+//! remember.
+
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+
+#[derive(Parser)]
+#[command(
+    name = "savant",
+    about = "Unified entry point for the Savant AI tools",
+    disable_help_subcommand = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: ToolCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ToolCommand {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) for `savant`
+    /// itself to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+    /// Conversation/transcript database (savant-db)
+    Db {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// OCR text extraction (savant-ocr)
+    Ocr {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Screen content analysis (savant-vision)
+    Vision {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Calendar/data sync (savant-sync)
+    Sync {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Audio transcription (savant-transcribe)
+    Transcribe {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// MCP server (savant-mcp-server)
+    Mcp {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Screen capture session (savant-video)
+    Video {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+}
+
+/// Maps a parsed subcommand to the binary that implements it and the arguments to
+/// forward verbatim.
+pub fn resolve(command: &ToolCommand) -> (&'static str, &[String]) {
+    match command {
+        ToolCommand::Completions { .. } => {
+            unreachable!("Completions is handled directly in main, before dispatch")
+        }
+        ToolCommand::Db { args } => ("savant-db", args),
+        ToolCommand::Ocr { args } => ("savant-ocr", args),
+        ToolCommand::Vision { args } => ("savant-vision", args),
+        ToolCommand::Sync { args } => ("savant-sync", args),
+        ToolCommand::Transcribe { args } => ("savant-transcribe", args),
+        ToolCommand::Mcp { args } => ("savant-mcp-server", args),
+        ToolCommand::Video { args } => ("savant-video", args),
+    }
+}
+
+/// Finds `binary_name` next to the running `savant` executable (where `cargo build`
+/// places every workspace binary), falling back to a bare name resolved via `PATH`.
+fn locate_binary(binary_name: &str) -> PathBuf {
+    if let Ok(exe) = std::env::current_exe() {
+        if let Some(dir) = exe.parent() {
+            let candidate = dir.join(binary_name);
+            if candidate.exists() {
+                return candidate;
+            }
+        }
+    }
+    PathBuf::from(binary_name)
+}
+
+/// Runs `binary_name` with `args`, inheriting stdio, and returns its exit status.
+pub fn dispatch(binary_name: &str, args: &[String]) -> std::io::Result<ExitStatus> {
+    Command::new(locate_binary(binary_name)).args(args).status()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_db_list_forwards_to_savant_db_binary() {
+        let cli = Cli::parse_from(["savant", "db", "list"]);
+        let (binary, args) = resolve(&cli.command);
+
+        assert_eq!(binary, "savant-db");
+        assert_eq!(args, &["list".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_forwards_flags_after_subcommand() {
+        let cli = Cli::parse_from(["savant", "ocr", "extract", "--format", "json"]);
+        let (binary, args) = resolve(&cli.command);
+
+        assert_eq!(binary, "savant-ocr");
+        assert_eq!(
+            args,
+            &["extract".to_string(), "--format".to_string(), "json".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_resolve_covers_all_tool_subcommands() {
+        for (subcommand, expected_binary) in [
+            ("db", "savant-db"),
+            ("ocr", "savant-ocr"),
+            ("vision", "savant-vision"),
+            ("sync", "savant-sync"),
+            ("transcribe", "savant-transcribe"),
+            ("mcp", "savant-mcp-server"),
+            ("video", "savant-video"),
+        ] {
+            let cli = Cli::parse_from(["savant", subcommand]);
+            let (binary, _) = resolve(&cli.command);
+            assert_eq!(binary, expected_binary);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use savant_cli::{dispatch, resolve, Cli, ToolCommand};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let ToolCommand::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return ExitCode::SUCCESS;
+    }
+
+    let (binary, args) = resolve(&cli.command);
+
+    match dispatch(binary, args) {
+        Ok(status) => match status.code() {
+            Some(code) => ExitCode::from(code as u8),
+            None => ExitCode::FAILURE, // terminated by a signal
+        },
+        Err(e) => {
+            eprintln!("error: failed to run {binary}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Shared shell-completion generation for clap-based CLIs.
+//!
+//! Each binary adds its own `completions <shell>` subcommand (so `--help` reflects its
+//! own name) but delegates the actual generation here, keeping the `clap_complete`
+//! plumbing in one place.
+
+use clap::CommandFactory;
+use std::io;
+
+pub use clap_complete::Shell;
+
+/// Writes a completion script for `shell` to stdout, generated from `C`'s clap
+/// `Command` definition (the struct behind a binary's `#[derive(Parser)]`).
+pub fn print_completions<C: CommandFactory>(shell: Shell) {
+    let mut command = C::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, &mut io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    #[command(name = "example")]
+    struct ExampleCli {
+        #[arg(long)]
+        verbose: bool,
+    }
+
+    #[test]
+    fn test_generate_bash_completions_is_non_empty_and_bash_specific() {
+        let mut command = ExampleCli::command();
+        let mut buf = Vec::new();
+        clap_complete::generate(Shell::Bash, &mut command, "example", &mut buf);
+        let script = String::from_utf8(buf).unwrap();
+
+        assert!(!script.is_empty());
+        assert!(script.contains("complete"));
+        assert!(script.contains("example"));
+    }
+}
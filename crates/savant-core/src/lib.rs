@@ -6,6 +6,7 @@
 pub mod types;
 pub mod config;
 pub mod error;
+pub mod completions;
 
 pub use types::*;
 pub use config::*;
@@ -2,12 +2,13 @@
 //!
 //! Database management for audio transcription data with rich querying capabilities
 
-use anyhow::Result;
 use chrono::{DateTime, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::fs::File;
 use std::path::PathBuf;
+use tokio::sync::{mpsc, RwLock};
 use uuid::Uuid;
 
 pub use savant_stt::{TranscriptionResult, TranscriptionSegment, SessionMetadata, AudioSource};
@@ -17,20 +18,51 @@ pub mod semantic_search;
 pub mod security;
 pub mod natural_query;
 pub mod llm_client;
+pub mod retry;
 pub mod visual_data;
+pub mod sentiment;
 
-pub use speaker_identification::{Speaker, SpeakerIdentifier, SpeakerMatch, MatchMethod};
-pub use semantic_search::{SemanticSearchEngine, SearchResult, ConversationAnalysis, Topic};
+pub use speaker_identification::{Speaker, SpeakerIdentifier, SpeakerMatch, MatchMethod, SpeakerExport};
+pub use semantic_search::{SemanticSearchEngine, SearchResult, ConversationAnalysis, Topic, DEFAULT_CONTEXT_CHARS, EmbeddingModel, EmbeddingProvider, DistanceMetric};
 pub use security::{QuerySecurityManager, SecurityError, QueryComplexity};
-pub use natural_query::{NaturalLanguageQueryParser, QueryIntent, IntentType, QueryResult, QueryProcessor, ConversationContextManager, QueryOptimizer, UserFeedback, LLMQueryResult};
+pub use natural_query::{NaturalLanguageQueryParser, QueryIntent, IntentType, QueryResult, QueryProcessor, ConversationContextManager, QueryOptimizer, UserFeedback, LLMQueryResult, QueryParam};
 pub use llm_client::{LLMClient, LLMClientFactory, LLMConfig, OllamaClient, OpenAIClient, MockLLMClient};
 pub use visual_data::{VisualDataManager, VideoQuery, VideoStats, ApplicationUsage, ActivitySummary, CodeAnalysis};
+pub use sentiment::score_sentiment;
 
 /// Database connection manager with speaker identification and semantic search
 pub struct TranscriptDatabase {
     pub pool: SqlitePool,
     speaker_identifier: Option<SpeakerIdentifier>,
     semantic_engine: Option<SemanticSearchEngine>,
+    db_path: PathBuf,
+    alerts: RwLock<Vec<AlertPattern>>,
+    alert_tx: RwLock<Option<mpsc::UnboundedSender<AlertEvent>>>,
+}
+
+/// A keyword/phrase pattern registered via [`TranscriptDatabase::register_alert`]. The
+/// pattern is always compiled as a regex, so a plain word like `"deadline"` matches as a
+/// literal substring while a caller who wants real regex syntax can still use it.
+struct AlertPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+/// Emitted on [`TranscriptDatabase::subscribe_alerts`]'s channel whenever a newly stored
+/// segment matches a registered alert pattern.
+#[derive(Debug, Clone)]
+pub struct AlertEvent {
+    pub conversation_id: String,
+    pub segment_text: String,
+    pub pattern: String,
+}
+
+/// Result of [`TranscriptDatabase::health_check`]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub healthy: bool,
+    pub latency_ms: u64,
+    pub error: Option<String>,
 }
 
 /// Conversation record for grouping related segments
@@ -44,6 +76,10 @@ pub struct Conversation {
     pub context: Option<String>,
     pub segment_count: i64,
     pub total_duration: f64,
+    /// Path to the saved recording (e.g. a WAV written by `savant-transcribe`), if one
+    /// was registered via [`TranscriptDatabase::set_conversation_audio_path`]. Lets
+    /// [`TranscriptDatabase::export_player_manifest`] pair segment text with audio offsets.
+    pub audio_path: Option<String>,
 }
 
 /// Query builder for complex searches
@@ -57,6 +93,24 @@ pub struct TranscriptQuery {
     pub text_contains: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Only return segments whose conversation has at least one (OR) or all
+    /// (AND, see `tags_match_all`) of these tags. See
+    /// [`TranscriptDatabase::add_tags`].
+    pub tags: Option<Vec<String>>,
+    /// When `true` and `tags` is set, a conversation must carry every listed
+    /// tag (AND); when `false`, any one of them is enough (OR).
+    pub tags_match_all: bool,
+}
+
+/// Query builder for joining transcript segments against the visual
+/// `video_frames` table by timestamp overlap, e.g. "what did I say while in
+/// Zoom". See [`TranscriptDatabase::query_segments_by_app`].
+#[derive(Debug, Default)]
+pub struct SegmentsByAppQuery {
+    pub active_application: Option<String>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
 }
 
 /// Statistics about conversations
@@ -69,6 +123,99 @@ pub struct ConversationStats {
     pub avg_confidence: f64,
 }
 
+/// A speaker's share of talk time within a single conversation
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpeakerShare {
+    pub speaker: String,
+    pub segment_count: i64,
+    pub total_duration_seconds: f64,
+    pub percentage: f64,
+}
+
+/// A single segment as written by [`TranscriptDatabase::export_all`] and read back by
+/// [`TranscriptDatabase::import_all`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedSegment {
+    pub speaker: String,
+    pub text: String,
+    pub timestamp: DateTime<Utc>,
+    pub confidence: Option<f64>,
+    pub start_time: f64,
+    pub end_time: f64,
+}
+
+/// One line of the NDJSON stream produced by [`TranscriptDatabase::export_all`]: a
+/// conversation together with all of its segments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedConversation {
+    pub id: String,
+    pub title: Option<String>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub context: Option<String>,
+    pub segments: Vec<ExportedSegment>,
+}
+
+/// Counts returned by [`TranscriptDatabase::export_all`] and [`TranscriptDatabase::import_all`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportSummary {
+    pub conversations: usize,
+    pub segments: usize,
+}
+
+/// Typed errors for `TranscriptDatabase`'s public API, so callers (the Tauri command
+/// layer, the MCP server) can match on a specific failure mode — e.g. a missing
+/// conversation vs. a dropped connection — instead of inspecting an opaque
+/// `anyhow::Error`. Anything that doesn't cleanly fit one of the specific variants is
+/// wrapped in `Other` rather than forcing every internal call site to be reclassified.
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Connection error: {0}")]
+    Connection(String),
+
+    #[error("Migration failed: {0}")]
+    Migration(String),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Constraint violation: {0}")]
+    Constraint(String),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<sqlx::Error> for DbError {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::RowNotFound => DbError::NotFound(e.to_string()),
+            sqlx::Error::Database(db_err) if db_err.message().to_lowercase().contains("constraint") => {
+                DbError::Constraint(db_err.message().to_string())
+            }
+            _ => DbError::Connection(e.to_string()),
+        }
+    }
+}
+
+impl From<std::io::Error> for DbError {
+    fn from(e: std::io::Error) -> Self {
+        DbError::Other(e.into())
+    }
+}
+
+impl From<regex::Error> for DbError {
+    fn from(e: regex::Error) -> Self {
+        DbError::Other(e.into())
+    }
+}
+
+/// Result alias used throughout this module's public API; see [`DbError`].
+pub type Result<T> = std::result::Result<T, DbError>;
+
 impl TranscriptDatabase {
     /// Create new database connection
     pub async fn new(db_path: Option<PathBuf>) -> Result<Self> {
@@ -92,11 +239,14 @@ impl TranscriptDatabase {
         
         let database_url = format!("sqlite://{}", path.display());
         let pool = SqlitePool::connect(&database_url).await?;
-        
-        let db = Self { 
+
+        let db = Self {
             pool: pool.clone(),
             speaker_identifier: Some(SpeakerIdentifier::new(pool.clone())),
             semantic_engine: Some(SemanticSearchEngine::new(pool)),
+            db_path: path,
+            alerts: RwLock::new(Vec::new()),
+            alert_tx: RwLock::new(None),
         };
         db.migrate().await?;
         
@@ -105,6 +255,42 @@ impl TranscriptDatabase {
         Ok(db)
     }
 
+    /// Check whether the connection pool can still reach the database, by running a
+    /// trivial `SELECT 1` and timing it. Never returns `Err` for a dead connection;
+    /// a failed query is reported as `healthy: false` with the error message attached,
+    /// so callers (including the Tauri command layer) can surface it without a panic.
+    pub async fn health_check(&self) -> Result<HealthStatus> {
+        let start = std::time::Instant::now();
+        let result = sqlx::query("SELECT 1").fetch_one(&self.pool).await;
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        Ok(match result {
+            Ok(_) => HealthStatus { healthy: true, latency_ms, error: None },
+            Err(e) => HealthStatus { healthy: false, latency_ms, error: Some(e.to_string()) },
+        })
+    }
+
+    /// Re-establish the connection pool (and the engines built on top of it) if
+    /// [`health_check`](Self::health_check) reports the current one is unusable, e.g.
+    /// because the underlying file was moved or the pool was closed. Takes `&mut self`
+    /// like [`init_speaker_identification`](Self::init_speaker_identification) and
+    /// [`init_semantic_search`](Self::init_semantic_search), since swapping the pool
+    /// out from under in-flight queries requires exclusive access.
+    pub async fn ensure_connected(&mut self) -> Result<()> {
+        if self.health_check().await?.healthy {
+            return Ok(());
+        }
+
+        let database_url = format!("sqlite://{}", self.db_path.display());
+        let pool = SqlitePool::connect(&database_url).await?;
+
+        self.pool = pool.clone();
+        self.speaker_identifier = Some(SpeakerIdentifier::new(pool.clone()));
+        self.semantic_engine = Some(SemanticSearchEngine::new(pool));
+
+        Ok(())
+    }
+
     /// Run database migrations
     async fn migrate(&self) -> Result<()> {
         // Create migration tracking table if it doesn't exist
@@ -119,6 +305,12 @@ impl TranscriptDatabase {
         self.run_migration("001", "../migrations/001_initial.sql").await?;
         self.run_migration("002", "../migrations/002_speaker_identification.sql").await?;
         self.run_migration("005", "../migrations/005_visual_data.sql").await?;
+        self.run_migration("013", "../migrations/013_sentiment.sql").await?;
+        self.run_migration("014", "../migrations/014_analysis_cache.sql").await?;
+        self.run_migration("009", "../migrations/009_tags.sql").await?;
+        self.run_migration("010", "../migrations/010_speaker_name_unique.sql").await?;
+        self.run_migration("011", "../migrations/011_conversation_audio_path.sql").await?;
+        self.run_migration("012", "../migrations/012_embedding_model.sql").await?;
         // Skip complex migrations for now due to SQL parsing issues
         // self.run_migration("003", "../migrations/003_llm_integration.sql").await?;
         // self.run_migration("004", "../migrations/004_database_optimizations.sql").await?;
@@ -158,7 +350,13 @@ impl TranscriptDatabase {
             "../migrations/003_llm_integration.sql" => include_str!("../migrations/003_llm_integration.sql"),
             "../migrations/004_database_optimizations.sql" => include_str!("../migrations/004_database_optimizations.sql"),
             "../migrations/005_visual_data.sql" => include_str!("../migrations/005_visual_data.sql"),
-            _ => return Err(anyhow::anyhow!("Unknown migration file: {}", file_path)),
+            "../migrations/013_sentiment.sql" => include_str!("../migrations/013_sentiment.sql"),
+            "../migrations/014_analysis_cache.sql" => include_str!("../migrations/014_analysis_cache.sql"),
+            "../migrations/009_tags.sql" => include_str!("../migrations/009_tags.sql"),
+            "../migrations/010_speaker_name_unique.sql" => include_str!("../migrations/010_speaker_name_unique.sql"),
+            "../migrations/011_conversation_audio_path.sql" => include_str!("../migrations/011_conversation_audio_path.sql"),
+            "../migrations/012_embedding_model.sql" => include_str!("../migrations/012_embedding_model.sql"),
+            _ => return Err(DbError::Migration(format!("Unknown migration file: {}", file_path))),
         };
         
         // Parse SQL statements more carefully
@@ -176,11 +374,11 @@ impl TranscriptDatabase {
                             continue; // Skip "already exists" errors
                         }
                     }
-                    return Err(e.into());
+                    return Err(DbError::Migration(e.to_string()));
                 }
             }
         }
-        
+
         Ok(())
     }
     
@@ -284,6 +482,134 @@ impl TranscriptDatabase {
         Ok(id)
     }
 
+    /// Records where `conversation_id`'s saved recording lives on disk, so
+    /// [`Self::export_player_manifest`] can pair its segments with audio offsets.
+    pub async fn set_conversation_audio_path(&self, conversation_id: &str, audio_path: &str) -> Result<()> {
+        sqlx::query("UPDATE conversations SET audio_path = ? WHERE id = ?")
+            .bind(audio_path)
+            .bind(conversation_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Adds `tags` to `conversation_id`. Tags already present are left alone.
+    pub async fn add_tags(&self, conversation_id: &str, tags: &[String]) -> Result<()> {
+        for tag in tags {
+            sqlx::query(
+                "INSERT OR IGNORE INTO conversation_tags (conversation_id, tag) VALUES (?, ?)"
+            )
+            .bind(conversation_id)
+            .bind(tag)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes `tags` from `conversation_id`. Tags not currently present are ignored.
+    pub async fn remove_tags(&self, conversation_id: &str, tags: &[String]) -> Result<()> {
+        for tag in tags {
+            sqlx::query("DELETE FROM conversation_tags WHERE conversation_id = ? AND tag = ?")
+                .bind(conversation_id)
+                .bind(tag)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Lists the tags assigned to `conversation_id`, alphabetically.
+    pub async fn list_tags(&self, conversation_id: &str) -> Result<Vec<String>> {
+        let rows = sqlx::query("SELECT tag FROM conversation_tags WHERE conversation_id = ? ORDER BY tag")
+            .bind(conversation_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(|row| row.get("tag")).collect())
+    }
+
+    /// Generate a short title for `conversation_id` from its segment text and store it.
+    /// Conversations with six words or fewer are too short to meaningfully summarize, so
+    /// the content itself becomes the title without calling the LLM. If the LLM call
+    /// fails, the title is left unchanged and the existing (or "Untitled") title is
+    /// returned rather than propagating the error.
+    pub async fn generate_title(&self, conversation_id: &str, llm: &dyn LLMClient) -> Result<String> {
+        let segments = self.get_conversation_segments(conversation_id).await?;
+        let full_text = segments
+            .iter()
+            .filter_map(|s| s["text"].as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let title = if full_text.is_empty() {
+            "Untitled".to_string()
+        } else if full_text.split_whitespace().count() <= 6 {
+            full_text
+        } else {
+            let prompt = format!(
+                "Summarize the following conversation in a short title (5 words or fewer), \
+                 with no surrounding punctuation or quotes:\n\n{}",
+                full_text
+            );
+
+            match llm.complete(&prompt).await {
+                Ok(generated) => generated.trim().trim_matches('"').to_string(),
+                Err(_) => {
+                    let current = self.get_conversation(conversation_id).await?;
+                    return Ok(current
+                        .and_then(|c| c["title"].as_str().map(|s| s.to_string()))
+                        .unwrap_or_else(|| "Untitled".to_string()));
+                }
+            }
+        };
+
+        sqlx::query("UPDATE conversations SET title = ? WHERE id = ?")
+            .bind(&title)
+            .bind(conversation_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(title)
+    }
+
+    /// Merge `source_ids` into `target_id`: re-parent all of their segments onto the
+    /// target conversation (segment timestamps are untouched, so chronological order is
+    /// preserved) and delete the now-empty source conversations. Participants are not
+    /// stored directly on a conversation; they're derived from `GROUP_CONCAT` over
+    /// segments, so they recompute automatically once the segments are re-parented.
+    /// Runs in a single transaction so a failure partway through leaves nothing merged.
+    pub async fn merge_conversations(&self, target_id: &str, source_ids: &[String]) -> Result<()> {
+        if self.get_conversation(target_id).await?.is_none() {
+            return Err(DbError::NotFound(format!("Conversation not found: {}", target_id)));
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        for source_id in source_ids {
+            if source_id == target_id {
+                continue;
+            }
+
+            sqlx::query("UPDATE segments SET conversation_id = ? WHERE conversation_id = ?")
+                .bind(target_id)
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query("DELETE FROM conversations WHERE id = ?")
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
     /// Store a single transcript segment
     async fn store_segment(
         &self,
@@ -293,16 +619,22 @@ impl TranscriptDatabase {
     ) -> Result<()> {
         let id = Uuid::new_v4().to_string();
         let metadata = result.session_metadata.as_ref();
-        
-        let speaker = metadata.and_then(|m| m.speaker.as_ref()).map_or("unknown", |s| s.as_str());
-        let audio_source = metadata.map(|m| serde_json::to_string(&m.audio_source).unwrap())
+
+        let speaker = segment
+            .speaker_label
+            .as_deref()
+            .or_else(|| metadata.and_then(|m| m.speaker.as_deref()))
+            .unwrap_or("unknown");
+        let audio_source = segment.audio_source.as_ref()
+            .map(|s| serde_json::to_string(s).unwrap())
+            .or_else(|| metadata.map(|m| serde_json::to_string(&m.audio_source).unwrap()))
             .unwrap_or_else(|| "\"Unknown\"".to_string());
         let timestamp = metadata.map(|m| m.timestamp).unwrap_or_else(Utc::now);
 
         sqlx::query(
-            r#"INSERT INTO segments 
-               (id, conversation_id, timestamp, speaker, audio_source, text, 
-                start_time, end_time, confidence, metadata) 
+            r#"INSERT INTO segments
+               (id, conversation_id, timestamp, speaker, audio_source, text,
+                start_time, end_time, confidence, metadata)
                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
         )
         .bind(&id)
@@ -318,9 +650,87 @@ impl TranscriptDatabase {
         .execute(&self.pool)
         .await?;
 
+        self.check_alerts(conversation_id, &segment.text).await;
+
+        Ok(())
+    }
+
+    /// Returns a channel whose consumer task inserts each received segment into
+    /// `conversation_id` as it arrives, so a streaming transcriber can persist
+    /// live output instead of batching it into a single `store_transcription`
+    /// call at the end. The consumer task holds its own clone of the
+    /// connection pool rather than borrowing `self`, so it keeps running
+    /// (and keeps draining buffered segments) until every sender is dropped
+    /// and the channel closes, which flushes anything still queued before the
+    /// task exits.
+    pub fn segment_sink(&self, conversation_id: String) -> mpsc::Sender<TranscriptionSegment> {
+        let (tx, mut rx) = mpsc::channel::<TranscriptionSegment>(32);
+        let pool = self.pool.clone();
+
+        tokio::spawn(async move {
+            while let Some(segment) = rx.recv().await {
+                if let Err(e) = insert_streamed_segment(&pool, &conversation_id, segment).await {
+                    log::warn!("Failed to persist streamed segment for conversation {}: {}", conversation_id, e);
+                }
+            }
+        });
+
+        tx
+    }
+
+    /// Register a keyword/phrase alert. `pattern` is compiled as a regex, so a plain
+    /// word matches as a literal substring while a caller who wants real regex syntax
+    /// (e.g. `\bblocker\b`) can still use it. Matches against segments stored after
+    /// registration are pushed to whatever receiver [`subscribe_alerts`](Self::subscribe_alerts)
+    /// last handed out; there's no effect on already-stored segments.
+    pub async fn register_alert(&self, pattern: &str, case_sensitive: bool) -> Result<()> {
+        let regex_source = if case_sensitive {
+            pattern.to_string()
+        } else {
+            format!("(?i){}", pattern)
+        };
+        let regex = Regex::new(&regex_source)?;
+
+        self.alerts.write().await.push(AlertPattern {
+            pattern: pattern.to_string(),
+            regex,
+        });
+
         Ok(())
     }
 
+    /// Subscribe to alert matches. Returns a fresh channel and replaces any previous
+    /// subscriber, so only the most recent caller receives events (single-consumer, like
+    /// the progress channels elsewhere in the codebase).
+    pub async fn subscribe_alerts(&self) -> mpsc::UnboundedReceiver<AlertEvent> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.alert_tx.write().await = Some(tx);
+        rx
+    }
+
+    /// Check `text` against every registered alert pattern and push a match for each one
+    /// that hits to the current subscriber, if any. Never fails the caller: a dropped
+    /// receiver (send error) is silently ignored, same as a missing subscriber.
+    async fn check_alerts(&self, conversation_id: &str, text: &str) {
+        let alerts = self.alerts.read().await;
+        if alerts.is_empty() {
+            return;
+        }
+
+        let tx_guard = self.alert_tx.read().await;
+        if let Some(tx) = tx_guard.as_ref() {
+            for alert in alerts.iter() {
+                if alert.regex.is_match(text) {
+                    let _ = tx.send(AlertEvent {
+                        conversation_id: conversation_id.to_string(),
+                        segment_text: text.to_string(),
+                        pattern: alert.pattern.clone(),
+                    });
+                }
+            }
+        }
+    }
+
     /// Query segments with flexible filters
     pub async fn query_segments(&self, query: &TranscriptQuery) -> Result<Vec<TranscriptionSegment>> {
         let mut sql = "SELECT metadata FROM segments WHERE 1=1".to_string();
@@ -351,6 +761,24 @@ impl TranscriptDatabase {
             params.push(end.to_rfc3339());
         }
 
+        if let Some(tags) = &query.tags {
+            if !tags.is_empty() {
+                let placeholders = tags.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+                if query.tags_match_all {
+                    sql.push_str(&format!(
+                        " AND conversation_id IN (SELECT conversation_id FROM conversation_tags WHERE tag IN ({}) GROUP BY conversation_id HAVING COUNT(DISTINCT tag) = {})",
+                        placeholders, tags.len()
+                    ));
+                } else {
+                    sql.push_str(&format!(
+                        " AND conversation_id IN (SELECT conversation_id FROM conversation_tags WHERE tag IN ({}))",
+                        placeholders
+                    ));
+                }
+                params.extend(tags.iter().cloned());
+            }
+        }
+
         sql.push_str(" ORDER BY timestamp");
 
         if let Some(limit) = query.limit {
@@ -377,6 +805,70 @@ impl TranscriptDatabase {
         Ok(segments)
     }
 
+    /// Returns transcript segments whose audio time range overlapped a captured
+    /// screen frame focused on `active_application` (e.g. "what did I say while
+    /// in Zoom"). Joins `segments` against the visual `video_frames` table
+    /// (stored in the same database, see [`crate::visual_data`]) by comparing
+    /// each frame's timestamp to the segment's absolute time range
+    /// (`segments.timestamp + start_time/end_time` seconds).
+    pub async fn query_segments_by_app(&self, query: &SegmentsByAppQuery) -> Result<Vec<TranscriptionSegment>> {
+        let mut sql = r#"
+            SELECT DISTINCT s.text, s.start_time, s.end_time, s.confidence, s.speaker, s.timestamp, s.audio_source
+            FROM segments s
+            JOIN video_frames vf
+                ON vf.timestamp >= datetime(s.timestamp, '+' || s.start_time || ' seconds')
+               AND vf.timestamp <= datetime(s.timestamp, '+' || s.end_time || ' seconds')
+            WHERE 1=1
+        "#.to_string();
+
+        let mut params: Vec<String> = Vec::new();
+
+        if let Some(app) = &query.active_application {
+            sql.push_str(" AND vf.active_application = ?");
+            params.push(app.clone());
+        }
+
+        if let Some(start) = &query.start_time {
+            sql.push_str(" AND s.timestamp >= ?");
+            params.push(start.to_rfc3339());
+        }
+
+        if let Some(end) = &query.end_time {
+            sql.push_str(" AND s.timestamp <= ?");
+            params.push(end.to_rfc3339());
+        }
+
+        sql.push_str(" ORDER BY s.timestamp");
+
+        if let Some(limit) = query.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        let mut query_builder = sqlx::query(&sql);
+        for param in params {
+            query_builder = query_builder.bind(param);
+        }
+
+        let rows = query_builder.fetch_all(&self.pool).await?;
+        let mut segments = Vec::new();
+
+        for row in rows {
+            let audio_source = row.get::<Option<String>, _>("audio_source")
+                .and_then(|json| serde_json::from_str(&json).ok());
+            segments.push(TranscriptionSegment {
+                text: row.get("text"),
+                start_time: row.get("start_time"),
+                end_time: row.get("end_time"),
+                confidence: row.get::<Option<f32>, _>("confidence"),
+                words: None,
+                speaker_label: Some(row.get::<String, _>("speaker")),
+                audio_source,
+            });
+        }
+
+        Ok(segments)
+    }
+
     /// Get conversation statistics by speaker
     pub async fn get_speaker_stats(&self) -> Result<Vec<ConversationStats>> {
         let rows = sqlx::query(
@@ -407,12 +899,72 @@ impl TranscriptDatabase {
         Ok(stats)
     }
 
+    /// [`Self::get_speaker_stats`] rendered as RFC-4180 CSV, for analysts who want to
+    /// open it in a spreadsheet rather than read the CLI's fixed-width table.
+    pub async fn speaker_stats_csv(&self) -> Result<String> {
+        let stats = self.get_speaker_stats().await?;
+
+        let mut csv = String::from("speaker,conversation_count,total_duration_seconds,total_segments,avg_confidence\n");
+        for stat in &stats {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                csv_field(&stat.speaker),
+                stat.conversation_count,
+                stat.total_duration_seconds,
+                stat.total_segments,
+                stat.avg_confidence
+            ));
+        }
+
+        Ok(csv)
+    }
+
+    /// Breakdown of talk time by speaker within a single conversation, as a percentage
+    /// of that conversation's total duration. Conversations with a single speaker report
+    /// 100% for that speaker; segments with no speaker are grouped under "unknown".
+    pub async fn conversation_speaker_breakdown(&self, conversation_id: &str) -> Result<Vec<SpeakerShare>> {
+        let rows = sqlx::query(
+            r#"SELECT
+                COALESCE(speaker, 'unknown') as speaker,
+                COUNT(*) as segment_count,
+                SUM(end_time - start_time) as total_duration
+               FROM segments
+               WHERE conversation_id = ?
+               GROUP BY speaker
+               ORDER BY total_duration DESC"#
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let durations: Vec<f64> = rows.iter().map(|row| row.get::<f64, _>("total_duration")).collect();
+        let conversation_total: f64 = durations.iter().sum();
+
+        let mut breakdown = Vec::new();
+        for (row, total_duration) in rows.iter().zip(durations) {
+            let percentage = if conversation_total > 0.0 {
+                total_duration / conversation_total * 100.0
+            } else {
+                0.0
+            };
+
+            breakdown.push(SpeakerShare {
+                speaker: row.get("speaker"),
+                segment_count: row.get("segment_count"),
+                total_duration_seconds: total_duration,
+                percentage,
+            });
+        }
+
+        Ok(breakdown)
+    }
+
     /// List all conversations
     pub async fn list_conversations(&self, limit: Option<i64>) -> Result<Vec<Conversation>> {
         let sql = if let Some(limit) = limit {
             format!(
                 r#"SELECT 
-                    c.id, c.title, c.start_time, c.end_time, c.context,
+                    c.id, c.title, c.start_time, c.end_time, c.context, c.audio_path,
                     COUNT(s.id) as segment_count,
                     SUM(s.end_time - s.start_time) as total_duration,
                     GROUP_CONCAT(DISTINCT s.speaker) as participants
@@ -425,7 +977,7 @@ impl TranscriptDatabase {
             )
         } else {
             r#"SELECT 
-                c.id, c.title, c.start_time, c.end_time, c.context,
+                c.id, c.title, c.start_time, c.end_time, c.context, c.audio_path,
                 COUNT(s.id) as segment_count,
                 SUM(s.end_time - s.start_time) as total_duration,
                 GROUP_CONCAT(DISTINCT s.speaker) as participants
@@ -453,72 +1005,343 @@ impl TranscriptDatabase {
                 context: row.get("context"),
                 segment_count: row.get("segment_count"),
                 total_duration: row.get::<Option<f64>, _>("total_duration").unwrap_or(0.0),
+                audio_path: row.get("audio_path"),
             });
         }
 
         Ok(conversations)
     }
 
-    /// Export conversation to JSON for external processing
-    pub async fn export_conversation(&self, conversation_id: &str) -> Result<serde_json::Value> {
-        let query = TranscriptQuery {
-            conversation_id: Some(conversation_id.to_string()),
-            ..Default::default()
-        };
+    /// Lists conversations matching a tag filter: any one of `tags` when
+    /// `match_all` is `false` (OR), or all of `tags` when `true` (AND).
+    /// Falls back to [`Self::list_conversations`] if `tags` is empty.
+    pub async fn list_conversations_by_tags(
+        &self,
+        tags: &[String],
+        match_all: bool,
+        limit: Option<i64>,
+    ) -> Result<Vec<Conversation>> {
+        if tags.is_empty() {
+            return self.list_conversations(limit).await;
+        }
 
-        let segments = self.query_segments(&query).await?;
-        
-        Ok(serde_json::json!({
-            "conversation_id": conversation_id,
-            "exported_at": Utc::now(),
-            "segments": segments
-        }))
-    }
+        let placeholders = tags.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let id_sql = if match_all {
+            format!(
+                "SELECT conversation_id FROM conversation_tags WHERE tag IN ({}) GROUP BY conversation_id HAVING COUNT(DISTINCT tag) = {}",
+                placeholders, tags.len()
+            )
+        } else {
+            format!(
+                "SELECT DISTINCT conversation_id FROM conversation_tags WHERE tag IN ({})",
+                placeholders
+            )
+        };
 
-    /// Initialize speaker identification system
-    pub async fn init_speaker_identification(&mut self) -> Result<()> {
-        if let Some(ref mut identifier) = self.speaker_identifier {
-            identifier.load_embeddings().await?;
+        let mut id_query = sqlx::query(&id_sql);
+        for tag in tags {
+            id_query = id_query.bind(tag);
         }
-        Ok(())
-    }
+        let matching_ids: Vec<String> = id_query
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(|row| row.get("conversation_id"))
+            .collect();
 
-    /// Initialize semantic search engine
-    pub async fn init_semantic_search(&mut self) -> Result<()> {
-        if let Some(ref mut engine) = self.semantic_engine {
-            engine.load_embeddings().await?;
+        if matching_ids.is_empty() {
+            return Ok(Vec::new());
         }
-        Ok(())
-    }
 
-    /// Get speaker identifier (immutable access)
-    pub fn speaker_identifier(&self) -> Option<&SpeakerIdentifier> {
-        self.speaker_identifier.as_ref()
-    }
-
-    /// Get mutable speaker identifier
-    pub fn speaker_identifier_mut(&mut self) -> Option<&mut SpeakerIdentifier> {
-        self.speaker_identifier.as_mut()
-    }
-
-    /// Get semantic search engine (immutable access)
-    pub fn semantic_engine(&self) -> Option<&SemanticSearchEngine> {
-        self.semantic_engine.as_ref()
-    }
-
-    /// Get mutable semantic search engine
+        let id_placeholders = matching_ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+        let sql = if let Some(limit) = limit {
+            format!(
+                r#"SELECT
+                    c.id, c.title, c.start_time, c.end_time, c.context, c.audio_path,
+                    COUNT(s.id) as segment_count,
+                    SUM(s.end_time - s.start_time) as total_duration,
+                    GROUP_CONCAT(DISTINCT s.speaker) as participants
+                   FROM conversations c
+                   LEFT JOIN segments s ON c.id = s.conversation_id
+                   WHERE c.id IN ({})
+                   GROUP BY c.id
+                   ORDER BY c.start_time DESC
+                   LIMIT {}"#,
+                id_placeholders, limit
+            )
+        } else {
+            format!(
+                r#"SELECT
+                    c.id, c.title, c.start_time, c.end_time, c.context, c.audio_path,
+                    COUNT(s.id) as segment_count,
+                    SUM(s.end_time - s.start_time) as total_duration,
+                    GROUP_CONCAT(DISTINCT s.speaker) as participants
+                   FROM conversations c
+                   LEFT JOIN segments s ON c.id = s.conversation_id
+                   WHERE c.id IN ({})
+                   GROUP BY c.id
+                   ORDER BY c.start_time DESC"#,
+                id_placeholders
+            )
+        };
+
+        let mut query = sqlx::query(&sql);
+        for id in &matching_ids {
+            query = query.bind(id);
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+        let mut conversations = Vec::new();
+
+        for row in rows {
+            let participants_str: Option<String> = row.get("participants");
+            let participants = participants_str
+                .map(|s| s.split(',').map(|p| p.trim().to_string()).collect())
+                .unwrap_or_default();
+
+            conversations.push(Conversation {
+                id: row.get("id"),
+                title: row.get("title"),
+                start_time: row.get("start_time"),
+                end_time: row.get("end_time"),
+                participants,
+                context: row.get("context"),
+                segment_count: row.get("segment_count"),
+                total_duration: row.get::<Option<f64>, _>("total_duration").unwrap_or(0.0),
+                audio_path: row.get("audio_path"),
+            });
+        }
+
+        Ok(conversations)
+    }
+
+    /// Returns `segment_id`'s start/end offset in seconds, relative to the start of its
+    /// conversation's recording - the same `start_time`/`end_time` stored alongside it.
+    pub async fn segment_audio_offset(&self, segment_id: &str) -> Result<(f64, f64)> {
+        let row = sqlx::query("SELECT start_time, end_time FROM segments WHERE id = ?")
+            .bind(segment_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| DbError::NotFound(format!("segment {}", segment_id)))?;
+
+        Ok((row.get("start_time"), row.get("end_time")))
+    }
+
+    /// Builds a "player manifest" pairing each segment's text with its audio offset, for a
+    /// UI to jump straight to the right point in `conversation_id`'s saved recording.
+    /// Returns [`DbError::NotFound`] if the conversation has no registered
+    /// [`Self::set_conversation_audio_path`] audio file, since a manifest without
+    /// audio to play is useless.
+    pub async fn export_player_manifest(&self, conversation_id: &str) -> Result<serde_json::Value> {
+        let audio_path: Option<String> = sqlx::query("SELECT audio_path FROM conversations WHERE id = ?")
+            .bind(conversation_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| DbError::NotFound(format!("conversation {}", conversation_id)))?
+            .get("audio_path");
+
+        let audio_path = audio_path
+            .ok_or_else(|| DbError::NotFound(format!("audio path for conversation {}", conversation_id)))?;
+
+        let rows = sqlx::query(
+            "SELECT id, speaker, text, start_time, end_time FROM segments
+             WHERE conversation_id = ? ORDER BY start_time"
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let segments: Vec<serde_json::Value> = rows.into_iter().map(|row| {
+            serde_json::json!({
+                "segment_id": row.get::<String, _>("id"),
+                "speaker": row.get::<String, _>("speaker"),
+                "text": row.get::<String, _>("text"),
+                "start_offset_seconds": row.get::<f64, _>("start_time"),
+                "end_offset_seconds": row.get::<f64, _>("end_time"),
+            })
+        }).collect();
+
+        Ok(serde_json::json!({
+            "conversation_id": conversation_id,
+            "audio_path": audio_path,
+            "segments": segments
+        }))
+    }
+
+    /// Export conversation to JSON for external processing
+    pub async fn export_conversation(&self, conversation_id: &str) -> Result<serde_json::Value> {
+        let query = TranscriptQuery {
+            conversation_id: Some(conversation_id.to_string()),
+            ..Default::default()
+        };
+
+        let segments = self.query_segments(&query).await?;
+        
+        Ok(serde_json::json!({
+            "conversation_id": conversation_id,
+            "exported_at": Utc::now(),
+            "segments": segments
+        }))
+    }
+
+    /// Stream every conversation and its segments as NDJSON (one [`ExportedConversation`]
+    /// per line) for backup/migration. The conversation rows are loaded up front; each
+    /// conversation's segments are then read and written one conversation at a time.
+    pub async fn export_all(&self, mut writer: impl std::io::Write) -> Result<ExportSummary> {
+        let conversation_rows = sqlx::query(
+            "SELECT id, title, start_time, end_time, context FROM conversations ORDER BY start_time"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut summary = ExportSummary::default();
+
+        for conv_row in conversation_rows {
+            let id: String = conv_row.get("id");
+
+            let segment_rows = sqlx::query(
+                "SELECT speaker, text, timestamp, confidence, start_time, end_time
+                 FROM segments WHERE conversation_id = ? ORDER BY timestamp"
+            )
+            .bind(&id)
+            .fetch_all(&self.pool)
+            .await?;
+
+            let segments: Vec<ExportedSegment> = segment_rows
+                .into_iter()
+                .map(|row| ExportedSegment {
+                    speaker: row.get("speaker"),
+                    text: row.get("text"),
+                    timestamp: row.get("timestamp"),
+                    confidence: row.get::<Option<f64>, _>("confidence"),
+                    start_time: row.get("start_time"),
+                    end_time: row.get("end_time"),
+                })
+                .collect();
+            summary.segments += segments.len();
+
+            let exported = ExportedConversation {
+                id,
+                title: conv_row.get("title"),
+                start_time: conv_row.get("start_time"),
+                end_time: conv_row.get("end_time"),
+                context: conv_row.get("context"),
+                segments,
+            };
+            writeln!(writer, "{}", serde_json::to_string(&exported)?)?;
+            summary.conversations += 1;
+        }
+
+        Ok(summary)
+    }
+
+    /// Replay an NDJSON stream produced by [`export_all`](Self::export_all), inserting
+    /// each conversation with its original ID so restoring into a fresh database
+    /// reproduces the source's conversation grouping. Segments are inserted under newly
+    /// generated IDs, since the export does not carry segment IDs.
+    pub async fn import_all(&self, reader: impl std::io::BufRead) -> Result<ExportSummary> {
+        let mut summary = ExportSummary::default();
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let conversation: ExportedConversation = serde_json::from_str(&line)?;
+
+            sqlx::query(
+                "INSERT INTO conversations (id, title, start_time, end_time, context) VALUES (?, ?, ?, ?, ?)"
+            )
+            .bind(&conversation.id)
+            .bind(&conversation.title)
+            .bind(conversation.start_time)
+            .bind(conversation.end_time)
+            .bind(&conversation.context)
+            .execute(&self.pool)
+            .await?;
+
+            for segment in &conversation.segments {
+                sqlx::query(
+                    r#"INSERT INTO segments
+                       (id, conversation_id, timestamp, speaker, audio_source, text, start_time, end_time, confidence, metadata)
+                       VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#
+                )
+                .bind(Uuid::new_v4().to_string())
+                .bind(&conversation.id)
+                .bind(segment.timestamp)
+                .bind(&segment.speaker)
+                .bind("\"Unknown\"")
+                .bind(&segment.text)
+                .bind(segment.start_time)
+                .bind(segment.end_time)
+                .bind(segment.confidence)
+                .execute(&self.pool)
+                .await?;
+                summary.segments += 1;
+            }
+
+            summary.conversations += 1;
+        }
+
+        Ok(summary)
+    }
+
+    /// Initialize speaker identification system
+    pub async fn init_speaker_identification(&mut self) -> Result<()> {
+        if let Some(ref mut identifier) = self.speaker_identifier {
+            identifier.load_embeddings().await?;
+        }
+        Ok(())
+    }
+
+    /// Initialize semantic search engine
+    pub async fn init_semantic_search(&mut self) -> Result<()> {
+        if let Some(ref mut engine) = self.semantic_engine {
+            engine.load_embeddings().await?;
+        }
+        Ok(())
+    }
+
+    /// Switch the embedding model used for indexing and querying, e.g. for `reindex
+    /// --model <name>`. See [`SemanticSearchEngine::set_embedding_model`] -- this drops
+    /// the in-memory embedding cache, so call [`Self::init_semantic_search`] afterwards
+    /// to load anything already indexed under the new model.
+    pub fn set_embedding_model(&mut self, embedding_model: EmbeddingModel) {
+        if let Some(ref mut engine) = self.semantic_engine {
+            engine.set_embedding_model(embedding_model);
+        }
+    }
+
+    /// Get speaker identifier (immutable access)
+    pub fn speaker_identifier(&self) -> Option<&SpeakerIdentifier> {
+        self.speaker_identifier.as_ref()
+    }
+
+    /// Get mutable speaker identifier
+    pub fn speaker_identifier_mut(&mut self) -> Option<&mut SpeakerIdentifier> {
+        self.speaker_identifier.as_mut()
+    }
+
+    /// Get semantic search engine (immutable access)
+    pub fn semantic_engine(&self) -> Option<&SemanticSearchEngine> {
+        self.semantic_engine.as_ref()
+    }
+
+    /// Get mutable semantic search engine
     pub fn semantic_engine_mut(&mut self) -> Option<&mut SemanticSearchEngine> {
         self.semantic_engine.as_mut()
     }
 
-    /// Text-based search for conversation segments
+    /// Text-based search for conversation segments. `context_chars` caps how many
+    /// characters of the adjacent segments are returned on each side of a match.
     pub async fn text_search(
         &self,
         query: &str,
         limit: usize,
+        context_chars: usize,
     ) -> Result<Vec<SearchResult>> {
         if let Some(engine) = &self.semantic_engine {
-            engine.text_search(query, limit).await
+            Ok(engine.text_search(query, limit, context_chars).await?)
         } else {
             Ok(Vec::new())
         }
@@ -530,42 +1353,124 @@ impl TranscriptDatabase {
         query: &str,
         limit: usize,
         _min_similarity: f32,
+        context_chars: usize,
     ) -> Result<Vec<SearchResult>> {
         // For now, fall back to text search
-        self.text_search(query, limit).await
+        self.text_search(query, limit, context_chars).await
     }
 
     /// Analyze conversation and extract insights
     pub async fn analyze_conversation(&self, conversation_id: &str) -> Result<ConversationAnalysis> {
         if let Some(engine) = &self.semantic_engine {
-            engine.analyze_conversation(conversation_id).await
+            Ok(engine.analyze_conversation(conversation_id).await?)
+        } else {
+            Err(DbError::Other(anyhow::anyhow!("Semantic engine not initialized")))
+        }
+    }
+
+    /// Cached variant of [`analyze_conversation`](Self::analyze_conversation). The
+    /// analysis is keyed by conversation id plus a hash of its segment set, so it's only
+    /// recomputed when a segment has been added, removed, or edited since the last call
+    /// — repeatedly reopening an unchanged long conversation is then effectively free.
+    pub async fn analyze_conversation_cached(&self, conversation_id: &str) -> Result<ConversationAnalysis> {
+        let current_hash = self.hash_conversation_segments(conversation_id).await?;
+
+        let cached = sqlx::query(
+            "SELECT segment_hash, analysis_json FROM conversation_analysis_cache WHERE conversation_id = ?"
+        )
+        .bind(conversation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = &cached {
+            let cached_hash: String = row.get("segment_hash");
+            if cached_hash == current_hash {
+                let analysis_json: String = row.get("analysis_json");
+                return Ok(serde_json::from_str(&analysis_json)?);
+            }
+        }
+
+        let analysis = self.analyze_conversation(conversation_id).await?;
+        let analysis_json = serde_json::to_string(&analysis)?;
+
+        sqlx::query(
+            r#"INSERT INTO conversation_analysis_cache (conversation_id, segment_hash, analysis_json, computed_at)
+               VALUES (?, ?, ?, CURRENT_TIMESTAMP)
+               ON CONFLICT(conversation_id) DO UPDATE SET
+                   segment_hash = excluded.segment_hash,
+                   analysis_json = excluded.analysis_json,
+                   computed_at = excluded.computed_at"#
+        )
+        .bind(conversation_id)
+        .bind(&current_hash)
+        .bind(&analysis_json)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(analysis)
+    }
+
+    /// Hash the id and text of every segment in a conversation, so any addition,
+    /// removal, or edit to the segment set changes the hash.
+    async fn hash_conversation_segments(&self, conversation_id: &str) -> Result<String> {
+        use std::hash::{Hash, Hasher};
+
+        let rows = sqlx::query("SELECT id, text FROM segments WHERE conversation_id = ? ORDER BY timestamp ASC")
+            .bind(conversation_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for row in &rows {
+            row.get::<String, _>("id").hash(&mut hasher);
+            row.get::<String, _>("text").hash(&mut hasher);
+        }
+
+        Ok(format!("{:x}", hasher.finish()))
+    }
+
+    /// (Re)compute and store embeddings for segments that don't have one yet, or that
+    /// were indexed with a different embedding model than the current one. See
+    /// [`SemanticSearchEngine::reindex`] for batching and progress-reporting details.
+    pub async fn reindex(&self, batch_size: usize, progress: impl Fn(usize, usize)) -> Result<usize> {
+        if let Some(engine) = &self.semantic_engine {
+            Ok(engine.reindex(batch_size, progress).await?)
         } else {
-            Err(anyhow::anyhow!("Semantic engine not initialized"))
+            Err(DbError::Other(anyhow::anyhow!("Semantic engine not initialized")))
         }
     }
 
     /// List all speakers
     pub async fn list_speakers(&self) -> Result<Vec<Speaker>> {
         if let Some(identifier) = &self.speaker_identifier {
-            identifier.list_speakers().await
+            Ok(identifier.list_speakers().await?)
         } else {
             Ok(Vec::new())
         }
     }
 
-    /// Create new speaker
+    /// Create new speaker.
+    ///
+    /// A named speaker is created via [`SpeakerIdentifier::get_or_create_speaker`]
+    /// so that two callers racing to create the same name (e.g. two transcription
+    /// daemons) converge on one row instead of creating duplicates. An unnamed
+    /// speaker has nothing to converge on (the uniqueness constraint only applies
+    /// to non-null names), so it goes through the plain insert.
     pub async fn create_speaker(&mut self, name: Option<String>) -> Result<String> {
         if let Some(identifier) = &mut self.speaker_identifier {
-            identifier.create_speaker(name, None).await
+            match &name {
+                Some(name) => Ok(identifier.get_or_create_speaker(name).await?),
+                None => Ok(identifier.create_speaker(name, None).await?),
+            }
         } else {
-            Err(anyhow::anyhow!("Speaker identifier not initialized"))
+            Err(DbError::Other(anyhow::anyhow!("Speaker identifier not initialized")))
         }
     }
 
     /// Find potential speaker duplicates
     pub async fn find_speaker_duplicates(&self) -> Result<Vec<(String, String, f32)>> {
         if let Some(identifier) = &self.speaker_identifier {
-            identifier.find_potential_duplicates().await
+            Ok(identifier.find_potential_duplicates().await?)
         } else {
             Ok(Vec::new())
         }
@@ -574,16 +1479,45 @@ impl TranscriptDatabase {
     /// Merge two speakers
     pub async fn merge_speakers(&mut self, primary_id: &str, secondary_id: &str) -> Result<()> {
         if let Some(identifier) = &mut self.speaker_identifier {
-            identifier.merge_speakers(primary_id, secondary_id).await
+            Ok(identifier.merge_speakers(primary_id, secondary_id).await?)
+        } else {
+            Err(DbError::Other(anyhow::anyhow!("Speaker identifier not initialized")))
+        }
+    }
+
+    /// Export a speaker's profile (metadata + voice embedding) for transfer to another
+    /// machine's database
+    pub async fn export_speaker(&self, id: &str) -> Result<SpeakerExport> {
+        if let Some(identifier) = &self.speaker_identifier {
+            Ok(identifier.export_speaker(id).await?)
+        } else {
+            Err(DbError::Other(anyhow::anyhow!("Speaker identifier not initialized")))
+        }
+    }
+
+    /// Import a speaker profile exported from another database, merging into an
+    /// existing speaker with the same name if one is found
+    pub async fn import_speaker(&mut self, export: SpeakerExport) -> Result<String> {
+        if let Some(identifier) = &mut self.speaker_identifier {
+            Ok(identifier.import_speaker(export).await?)
         } else {
-            Err(anyhow::anyhow!("Speaker identifier not initialized"))
+            Err(DbError::Other(anyhow::anyhow!("Speaker identifier not initialized")))
+        }
+    }
+
+    /// Re-tune speaker confidence thresholds from their recorded voice samples
+    pub async fn tune_speaker_thresholds(&mut self) -> Result<Vec<(String, f32)>> {
+        if let Some(identifier) = &mut self.speaker_identifier {
+            Ok(identifier.tune_thresholds().await?)
+        } else {
+            Ok(Vec::new())
         }
     }
 
     /// Get conversation topics
     pub async fn get_conversation_topics(&self, conversation_id: &str) -> Result<Vec<Topic>> {
         if let Some(engine) = &self.semantic_engine {
-            engine.get_conversation_topics(conversation_id).await
+            Ok(engine.get_conversation_topics(conversation_id).await?)
         } else {
             Ok(Vec::new())
         }
@@ -629,16 +1563,16 @@ impl TranscriptDatabase {
     /// Get all segments for a conversation
     pub async fn get_conversation_segments(&self, conversation_id: &str) -> Result<Vec<serde_json::Value>> {
         let rows = sqlx::query(
-            r#"SELECT id, speaker, text, processed_text, timestamp, confidence, 
-                      start_time, end_time
-               FROM segments 
+            r#"SELECT id, speaker, text, processed_text, timestamp, confidence,
+                      start_time, end_time, sentiment
+               FROM segments
                WHERE conversation_id = ?
                ORDER BY timestamp ASC"#
         )
         .bind(conversation_id)
         .fetch_all(&self.pool)
         .await?;
-        
+
         let segments = rows
             .into_iter()
             .map(|row| {
@@ -650,11 +1584,779 @@ impl TranscriptDatabase {
                     "timestamp": row.get::<chrono::DateTime<chrono::Utc>, _>("timestamp"),
                     "confidence": row.get::<Option<f64>, _>("confidence"),
                     "start_time": row.get::<Option<f64>, _>("start_time"),
-                    "end_time": row.get::<Option<f64>, _>("end_time")
+                    "end_time": row.get::<Option<f64>, _>("end_time"),
+                    "sentiment": row.get::<Option<f64>, _>("sentiment")
                 })
             })
             .collect();
-        
+
         Ok(segments)
     }
+
+    /// Score and store sentiment for a single segment using the lightweight lexicon in
+    /// [`sentiment::score_sentiment`]. Opt-in: never called automatically from
+    /// `store_segment`/`import_all`, so bulk imports don't pay the scoring cost unless
+    /// the caller explicitly asks for it.
+    pub async fn score_segment_sentiment(&self, segment_id: &str) -> Result<f32> {
+        let row = sqlx::query("SELECT text FROM segments WHERE id = ?")
+            .bind(segment_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| DbError::NotFound(format!("Segment not found: {}", segment_id)))?;
+
+        let text: String = row.get("text");
+        let score = sentiment::score_sentiment(&text);
+
+        sqlx::query("UPDATE segments SET sentiment = ? WHERE id = ?")
+            .bind(score)
+            .bind(segment_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(score)
+    }
+
+    /// Score and store sentiment for every segment in a conversation. Returns the number
+    /// of segments scored.
+    pub async fn score_conversation_sentiment(&self, conversation_id: &str) -> Result<usize> {
+        let rows = sqlx::query("SELECT id FROM segments WHERE conversation_id = ?")
+            .bind(conversation_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        for row in &rows {
+            let segment_id: String = row.get("id");
+            self.score_segment_sentiment(&segment_id).await?;
+        }
+
+        Ok(rows.len())
+    }
+}
+
+/// Inserts a single live-streamed segment, used by [`TranscriptDatabase::segment_sink`].
+/// Mirrors `store_segment`'s schema but has no full `TranscriptionResult` context to draw
+/// speaker/audio-source metadata from, so those fall back to "unknown"/"Unknown".
+async fn insert_streamed_segment(
+    pool: &SqlitePool,
+    conversation_id: &str,
+    segment: TranscriptionSegment,
+) -> Result<()> {
+    let id = Uuid::new_v4().to_string();
+    let speaker = segment.speaker_label.clone().unwrap_or_else(|| "unknown".to_string());
+    let timestamp = Utc::now();
+    let result = TranscriptionResult {
+        text: segment.text.clone(),
+        language: None,
+        segments: vec![segment.clone()],
+        processing_time_ms: 0,
+        model_used: "streaming".to_string(),
+        session_metadata: None,
+    };
+
+    sqlx::query(
+        r#"INSERT INTO segments
+           (id, conversation_id, timestamp, speaker, audio_source, text,
+            start_time, end_time, confidence, metadata)
+           VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+    )
+    .bind(&id)
+    .bind(conversation_id)
+    .bind(timestamp)
+    .bind(&speaker)
+    .bind("\"Unknown\"")
+    .bind(&segment.text)
+    .bind(segment.start_time)
+    .bind(segment.end_time)
+    .bind(segment.confidence)
+    .bind(serde_json::to_string(&result)?)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Quotes `field` per RFC 4180 if it contains a comma, double quote, or newline,
+/// doubling any embedded double quotes. Used by [`TranscriptDatabase::speaker_stats_csv`].
+fn csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: f64, end: f64, speaker: &str) -> TranscriptionSegment {
+        TranscriptionSegment {
+            text: text.to_string(),
+            start_time: start,
+            end_time: end,
+            confidence: None,
+            words: None,
+            speaker_label: Some(speaker.to_string()),
+            audio_source: None,
+        }
+    }
+
+    fn result(segments: Vec<TranscriptionSegment>) -> TranscriptionResult {
+        TranscriptionResult {
+            text: segments.iter().map(|s| s.text.clone()).collect::<Vec<_>>().join(" "),
+            language: None,
+            segments,
+            processing_time_ms: 0,
+            model_used: "test".to_string(),
+            session_metadata: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_conversations_combines_segment_counts_and_participants() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("merge-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let target_id = db.create_conversation(Some("Target"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("hello", 0.0, 1.0, "Alice")]),
+            Some(target_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let source_id = db.create_conversation(Some("Source"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![
+                segment("hi", 0.0, 1.0, "Bob"),
+                segment("there", 1.0, 2.0, "Bob"),
+            ]),
+            Some(source_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        db.merge_conversations(&target_id, &[source_id.clone()])
+            .await
+            .unwrap();
+
+        let merged = db.get_conversation(&target_id).await.unwrap().unwrap();
+        assert_eq!(merged["segment_count"], 3);
+
+        let mut participants = merged["participants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p.as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        participants.sort();
+        assert_eq!(participants, vec!["Alice".to_string(), "Bob".to_string()]);
+
+        assert!(db.get_conversation(&source_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_segment_audio_offset_matches_stored_segment_times() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("offset-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("Replay"), None).await.unwrap();
+        db.set_conversation_audio_path(&conversation_id, "/recordings/replay.wav").await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("hello", 1.5, 3.25, "Alice")]),
+            Some(conversation_id.clone()),
+        ).await.unwrap();
+
+        let segment_row = sqlx::query("SELECT id FROM segments WHERE conversation_id = ?")
+            .bind(&conversation_id)
+            .fetch_one(&db.pool)
+            .await
+            .unwrap();
+        let segment_id: String = segment_row.get("id");
+
+        let (start, end) = db.segment_audio_offset(&segment_id).await.unwrap();
+        assert_eq!((start, end), (1.5, 3.25));
+
+        let manifest = db.export_player_manifest(&conversation_id).await.unwrap();
+        assert_eq!(manifest["audio_path"], "/recordings/replay.wav");
+        let segments = manifest["segments"].as_array().unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0]["start_offset_seconds"], 1.5);
+        assert_eq!(segments[0]["end_offset_seconds"], 3.25);
+    }
+
+    #[tokio::test]
+    async fn test_export_player_manifest_rejects_conversation_without_audio_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("no-audio-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("No Recording"), None).await.unwrap();
+
+        assert!(matches!(
+            db.export_player_manifest(&conversation_id).await,
+            Err(DbError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_store_segment_prefers_per_segment_audio_source_over_session() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("audio-source-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("Dual Capture"), None).await.unwrap();
+
+        let mut mic_segment = segment("from the mic", 0.0, 1.0, "Alice");
+        mic_segment.audio_source = Some(AudioSource::Microphone);
+
+        let mut system_segment = segment("from the speakers", 1.0, 2.0, "Bob");
+        system_segment.audio_source = Some(AudioSource::SystemAudio);
+
+        let mut unspecified_segment = segment("no per-segment source", 2.0, 3.0, "Alice");
+        unspecified_segment.audio_source = None;
+
+        let mut mixed_result = result(vec![mic_segment, system_segment, unspecified_segment]);
+        mixed_result.session_metadata = Some(SessionMetadata {
+            session_id: "session-1".to_string(),
+            timestamp: Utc::now(),
+            audio_source: AudioSource::BluetoothDevice("headset".to_string()),
+            speaker: None,
+            device_info: None,
+        });
+
+        db.store_transcription(&mixed_result, Some(conversation_id.clone()))
+            .await
+            .unwrap();
+
+        let stored = db.query_segments(&TranscriptQuery {
+            conversation_id: Some(conversation_id),
+            ..Default::default()
+        }).await.unwrap();
+
+        let by_text = |text: &str| stored.iter().find(|s| s.text == text).unwrap();
+
+        assert!(matches!(by_text("from the mic").audio_source, Some(AudioSource::Microphone)));
+        assert!(matches!(by_text("from the speakers").audio_source, Some(AudioSource::SystemAudio)));
+        // Unset on this segment - stays None rather than inheriting the session value.
+        assert!(by_text("no per-segment source").audio_source.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_conversation_speaker_breakdown_computes_percentage_split() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("breakdown-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("Standup"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("seven minutes of talking", 0.0, 7.0, "Alice")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+        db.store_transcription(
+            &result(vec![segment("three minutes of talking", 7.0, 10.0, "Bob")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let breakdown = db.conversation_speaker_breakdown(&conversation_id).await.unwrap();
+        assert_eq!(breakdown.len(), 2);
+
+        let alice = breakdown.iter().find(|s| s.speaker == "Alice").unwrap();
+        let bob = breakdown.iter().find(|s| s.speaker == "Bob").unwrap();
+        assert!((alice.percentage - 70.0).abs() < 0.001);
+        assert!((bob.percentage - 30.0).abs() < 0.001);
+        assert_eq!(alice.segment_count, 1);
+        assert_eq!(bob.segment_count, 1);
+    }
+
+    async fn insert_frame(pool: &SqlitePool, session_id: &str, timestamp: DateTime<Utc>, active_application: &str) {
+        sqlx::query(
+            r#"INSERT INTO video_frames
+               (id, session_id, timestamp, file_path, image_hash, active_application)
+               VALUES (?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(session_id)
+        .bind(timestamp)
+        .bind("/tmp/test_frame.png")
+        .bind(Uuid::new_v4().to_string())
+        .bind(active_application)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_query_segments_by_app_returns_only_segments_overlapping_matching_frame() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("segments-by-app-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let base = Utc::now();
+        let conversation_id = db.create_conversation(Some("Mixed"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![
+                segment("talking in zoom", 0.0, 10.0, "Alice"),
+                segment("talking in slack", 20.0, 30.0, "Alice"),
+            ]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        sqlx::query("INSERT INTO video_sessions (id, start_time) VALUES (?, ?)")
+            .bind("session-1")
+            .bind(base)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        // Frame within the first segment's [0, 10] second window, focused on Zoom.
+        insert_frame(&db.pool, "session-1", base + chrono::Duration::seconds(5), "Zoom").await;
+        // Frame within the second segment's [20, 30] second window, focused on Slack.
+        insert_frame(&db.pool, "session-1", base + chrono::Duration::seconds(25), "Slack").await;
+
+        let zoom_segments = db
+            .query_segments_by_app(&SegmentsByAppQuery {
+                active_application: Some("Zoom".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(zoom_segments.len(), 1);
+        assert_eq!(zoom_segments[0].text, "talking in zoom");
+    }
+
+    #[tokio::test]
+    async fn test_segment_sink_persists_sent_segments_and_flushes_on_close() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("segment-sink-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("Live"), None).await.unwrap();
+        let tx = db.segment_sink(conversation_id.clone());
+
+        tx.send(segment("one", 0.0, 1.0, "Alice")).await.unwrap();
+        tx.send(segment("two", 1.0, 2.0, "Alice")).await.unwrap();
+        tx.send(segment("three", 2.0, 3.0, "Alice")).await.unwrap();
+        drop(tx);
+
+        // Give the consumer task a moment to drain the channel after it closes.
+        for _ in 0..50 {
+            let stored = db
+                .query_segments(&TranscriptQuery {
+                    conversation_id: Some(conversation_id.clone()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            if stored.len() >= 3 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+
+        let stored = db
+            .query_segments(&TranscriptQuery {
+                conversation_id: Some(conversation_id),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut texts = stored.iter().map(|s| s.text.clone()).collect::<Vec<_>>();
+        texts.sort();
+        assert_eq!(texts, vec!["one".to_string(), "three".to_string(), "two".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_ensure_connected_restores_functionality_after_pool_closed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("health-test.db");
+        let mut db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        assert!(db.health_check().await.unwrap().healthy);
+
+        db.pool.close().await;
+        assert!(!db.health_check().await.unwrap().healthy);
+
+        db.ensure_connected().await.unwrap();
+        assert!(db.health_check().await.unwrap().healthy);
+        db.create_conversation(Some("after reconnect"), None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_export_all_then_import_all_round_trips_counts() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source_db = TranscriptDatabase::new(Some(temp_dir.path().join("source.db")))
+            .await
+            .unwrap();
+
+        let first = source_db.create_conversation(Some("Standup"), None).await.unwrap();
+        source_db
+            .store_transcription(
+                &result(vec![segment("hello", 0.0, 1.0, "Alice"), segment("hi", 1.0, 2.0, "Bob")]),
+                Some(first),
+            )
+            .await
+            .unwrap();
+        let second = source_db.create_conversation(Some("Retro"), None).await.unwrap();
+        source_db
+            .store_transcription(&result(vec![segment("agenda", 0.0, 1.0, "Alice")]), Some(second))
+            .await
+            .unwrap();
+
+        let mut exported = Vec::new();
+        let export_summary = source_db.export_all(&mut exported).await.unwrap();
+        assert_eq!(export_summary.conversations, 2);
+        assert_eq!(export_summary.segments, 3);
+
+        let target_db = TranscriptDatabase::new(Some(temp_dir.path().join("target.db")))
+            .await
+            .unwrap();
+        let import_summary = target_db.import_all(exported.as_slice()).await.unwrap();
+
+        assert_eq!(import_summary.conversations, export_summary.conversations);
+        assert_eq!(import_summary.segments, export_summary.segments);
+
+        let imported_conversations = target_db.list_conversations(None).await.unwrap();
+        assert_eq!(imported_conversations.len(), 2);
+        let total_segments: i64 = imported_conversations.iter().map(|c| c.segment_count).sum();
+        assert_eq!(total_segments, 3);
+    }
+
+    struct StubLLMClient {
+        response: std::result::Result<String, String>,
+    }
+
+    #[async_trait::async_trait]
+    impl LLMClient for StubLLMClient {
+        async fn complete(&self, _prompt: &str) -> anyhow::Result<String> {
+            self.response.clone().map_err(|e| anyhow::anyhow!(e))
+        }
+
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_title_stores_llm_generated_title() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("title-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment(
+                "we discussed the quarterly roadmap and agreed on next steps for the launch",
+                0.0,
+                5.0,
+                "Alice",
+            )]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let llm = StubLLMClient { response: Ok("Quarterly Roadmap Discussion".to_string()) };
+        let title = db.generate_title(&conversation_id, &llm).await.unwrap();
+        assert_eq!(title, "Quarterly Roadmap Discussion");
+
+        let stored = db.get_conversation(&conversation_id).await.unwrap().unwrap();
+        assert_eq!(stored["title"].as_str(), Some("Quarterly Roadmap Discussion"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_title_leaves_title_unchanged_on_llm_failure() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("title-failure-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(Some("Original Title"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment(
+                "we discussed the quarterly roadmap and agreed on next steps for the launch",
+                0.0,
+                5.0,
+                "Alice",
+            )]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let llm = StubLLMClient { response: Err("LLM unavailable".to_string()) };
+        let title = db.generate_title(&conversation_id, &llm).await.unwrap();
+        assert_eq!(title, "Original Title");
+
+        let stored = db.get_conversation(&conversation_id).await.unwrap().unwrap();
+        assert_eq!(stored["title"].as_str(), Some("Original Title"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_title_falls_back_to_content_for_very_short_conversations() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("title-short-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("hello there", 0.0, 1.0, "Alice")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let llm = StubLLMClient { response: Err("should not be called".to_string()) };
+        let title = db.generate_title(&conversation_id, &llm).await.unwrap();
+        assert_eq!(title, "hello there");
+    }
+
+    #[tokio::test]
+    async fn test_score_conversation_sentiment_persists_scores_and_updates_segments_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("sentiment-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![
+                segment("this is a wonderful and fantastic update", 0.0, 1.0, "Alice"),
+                segment("this is a terrible and awful problem", 1.0, 2.0, "Bob"),
+            ]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let segments_before = db.get_conversation_segments(&conversation_id).await.unwrap();
+        assert!(segments_before.iter().all(|s| s["sentiment"].is_null()));
+
+        let scored = db.score_conversation_sentiment(&conversation_id).await.unwrap();
+        assert_eq!(scored, 2);
+
+        let segments_after = db.get_conversation_segments(&conversation_id).await.unwrap();
+        let positive = segments_after.iter().find(|s| s["speaker"] == "Alice").unwrap();
+        let negative = segments_after.iter().find(|s| s["speaker"] == "Bob").unwrap();
+        assert!(positive["sentiment"].as_f64().unwrap() > 0.0);
+        assert!(negative["sentiment"].as_f64().unwrap() < 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_register_alert_then_matching_segment_emits_alert_event() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("alert-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        db.register_alert("deadline", false).await.unwrap();
+        let mut alerts = db.subscribe_alerts().await;
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("we need to hit the Deadline tomorrow", 0.0, 1.0, "Alice")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let event = alerts.recv().await.unwrap();
+        assert_eq!(event.conversation_id, conversation_id);
+        assert_eq!(event.pattern, "deadline");
+        assert!(event.segment_text.contains("Deadline"));
+    }
+
+    #[tokio::test]
+    async fn test_store_segment_without_matching_alert_sends_nothing() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("alert-no-match-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        db.register_alert("blocker", false).await.unwrap();
+        let mut alerts = db.subscribe_alerts().await;
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("everything is on track", 0.0, 1.0, "Alice")]),
+            Some(conversation_id),
+        )
+        .await
+        .unwrap();
+
+        assert!(alerts.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_conversation_cached_reuses_cache_until_segments_change() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("analysis-cache-test.db");
+        let mut db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+        db.init_semantic_search().await.unwrap();
+
+        let conversation_id = db.create_conversation(None, None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("we had a great meeting today", 0.0, 2.0, "Alice")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        db.analyze_conversation_cached(&conversation_id).await.unwrap();
+
+        // Tamper with the cached row directly; a real recompute would overwrite this,
+        // so seeing it survive a second call proves the cache was actually used.
+        let sentinel = serde_json::to_string(&ConversationAnalysis {
+            conversation_id: conversation_id.clone(),
+            summary: "SENTINEL".to_string(),
+            topics: vec![],
+            sentiment_score: 0.0,
+            key_phrases: vec![],
+            duration: 0.0,
+            participant_count: 0,
+            quality_score: 0.0,
+        })
+        .unwrap();
+        sqlx::query("UPDATE conversation_analysis_cache SET analysis_json = ? WHERE conversation_id = ?")
+            .bind(&sentinel)
+            .bind(&conversation_id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let cached = db.analyze_conversation_cached(&conversation_id).await.unwrap();
+        assert_eq!(cached.summary, "SENTINEL");
+
+        db.store_transcription(
+            &result(vec![segment("a quick follow up", 2.0, 3.0, "Alice")]),
+            Some(conversation_id.clone()),
+        )
+        .await
+        .unwrap();
+
+        let recomputed = db.analyze_conversation_cached(&conversation_id).await.unwrap();
+        assert_ne!(recomputed.summary, "SENTINEL");
+    }
+
+    #[tokio::test]
+    async fn test_merge_conversations_into_missing_target_returns_not_found() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("not-found-test.db");
+        let db = TranscriptDatabase::new(Some(db_path)).await.unwrap();
+
+        let source_id = db.create_conversation(Some("Source"), None).await.unwrap();
+
+        let err = db
+            .merge_conversations("does-not-exist", &[source_id])
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DbError::NotFound(_)), "expected NotFound, got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_import_all_duplicate_conversation_id_returns_constraint_violation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db = TranscriptDatabase::new(Some(temp_dir.path().join("constraint-test.db")))
+            .await
+            .unwrap();
+
+        let conversation_id = db.create_conversation(Some("Standup"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("hello", 0.0, 1.0, "Alice")]),
+            Some(conversation_id),
+        )
+        .await
+        .unwrap();
+
+        let mut exported = Vec::new();
+        db.export_all(&mut exported).await.unwrap();
+
+        // Re-importing the same export into the same database collides on the
+        // conversation's primary key.
+        let err = db.import_all(exported.as_slice()).await.unwrap_err();
+
+        assert!(matches!(err, DbError::Constraint(_)), "expected Constraint, got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_list_conversations_by_tags_filters_on_shared_tag() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db = TranscriptDatabase::new(Some(temp_dir.path().join("tags-test.db")))
+            .await
+            .unwrap();
+
+        let standup = db.create_conversation(Some("Standup"), None).await.unwrap();
+        db.add_tags(&standup, &["standup".to_string(), "work".to_string()])
+            .await
+            .unwrap();
+
+        let interview = db.create_conversation(Some("Interview"), None).await.unwrap();
+        db.add_tags(&interview, &["interview".to_string(), "work".to_string()])
+            .await
+            .unwrap();
+
+        let personal = db.create_conversation(Some("Catch up"), None).await.unwrap();
+        db.add_tags(&personal, &["personal".to_string()]).await.unwrap();
+
+        assert_eq!(db.list_tags(&standup).await.unwrap(), vec!["standup", "work"]);
+
+        // OR semantics: the shared "work" tag matches both work conversations.
+        let mut work = db
+            .list_conversations_by_tags(&["work".to_string()], false, None)
+            .await
+            .unwrap();
+        work.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut expected_ids = vec![standup.clone(), interview.clone()];
+        expected_ids.sort();
+        assert_eq!(
+            work.iter().map(|c| c.id.clone()).collect::<Vec<_>>(),
+            expected_ids
+        );
+
+        // AND semantics: only the standup carries both tags.
+        let both = db
+            .list_conversations_by_tags(&["work".to_string(), "standup".to_string()], true, None)
+            .await
+            .unwrap();
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].id, standup);
+
+        db.remove_tags(&standup, &["standup".to_string()]).await.unwrap();
+        assert_eq!(db.list_tags(&standup).await.unwrap(), vec!["work"]);
+    }
+
+    #[tokio::test]
+    async fn test_speaker_stats_csv_quotes_name_containing_comma() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db = TranscriptDatabase::new(Some(temp_dir.path().join("csv-test.db")))
+            .await
+            .unwrap();
+
+        let conversation_id = db.create_conversation(Some("Standup"), None).await.unwrap();
+        db.store_transcription(
+            &result(vec![segment("hello", 0.0, 1.0, "Smith, John")]),
+            Some(conversation_id),
+        )
+        .await
+        .unwrap();
+
+        let csv = db.speaker_stats_csv().await.unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "speaker,conversation_count,total_duration_seconds,total_segments,avg_confidence"
+        );
+        assert!(lines.next().unwrap().starts_with("\"Smith, John\","));
+    }
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::time::Duration;
 use crate::natural_query::LLMClientWrapper;
+use crate::retry::{retry_with_backoff, RetryConfig};
 
 /// LLM client trait for query processing
 #[async_trait]
@@ -49,6 +50,7 @@ impl Default for LLMConfig {
 pub struct OllamaClient {
     config: LLMConfig,
     client: reqwest::Client,
+    retry_config: RetryConfig,
 }
 
 /// OpenAI-compatible client implementation
@@ -56,6 +58,7 @@ pub struct OllamaClient {
 pub struct OpenAIClient {
     config: LLMConfig,
     client: reqwest::Client,
+    retry_config: RetryConfig,
 }
 
 /// Mock client for testing
@@ -70,8 +73,8 @@ impl OllamaClient {
             .timeout(Duration::from_secs(config.timeout_seconds))
             .build()
             .expect("Failed to create HTTP client");
-            
-        Self { config, client }
+
+        Self { config, client, retry_config: RetryConfig::default() }
     }
     
     pub async fn health_check(&self) -> Result<bool> {
@@ -99,13 +102,12 @@ impl LLMClient for OllamaClient {
             }
         });
         
-        let response = self.client
-            .post(&url)
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to send request to Ollama: {}", e))?;
-            
+        let response = retry_with_backoff(&self.retry_config, || {
+            self.client.post(&url).json(&payload).send()
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to send request to Ollama: {}", e))?;
+
         if !response.status().is_success() {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
@@ -141,8 +143,8 @@ impl OpenAIClient {
             .timeout(Duration::from_secs(config.timeout_seconds))
             .build()
             .map_err(|e| anyhow!("Failed to create HTTP client: {}", e))?;
-            
-        Ok(Self { config, client })
+
+        Ok(Self { config, client, retry_config: RetryConfig::default() })
     }
 }
 
@@ -163,15 +165,18 @@ impl LLMClient for OpenAIClient {
             "temperature": self.config.temperature.unwrap_or(0.1),
         });
         
-        let response = self.client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.config.api_key.as_ref().unwrap()))
-            .header("Content-Type", "application/json")
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to send request to OpenAI: {}", e))?;
-            
+        let api_key = self.config.api_key.as_ref().unwrap();
+        let response = retry_with_backoff(&self.retry_config, || {
+            self.client
+                .post(&url)
+                .header("Authorization", format!("Bearer {}", api_key))
+                .header("Content-Type", "application/json")
+                .json(&payload)
+                .send()
+        })
+        .await
+        .map_err(|e| anyhow!("Failed to send request to OpenAI: {}", e))?;
+
         if !response.status().is_success() {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
@@ -368,4 +373,36 @@ mod tests {
         assert!(prompt.contains("segments"));
         assert!(prompt.contains("JSON"));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_ollama_client_retries_then_succeeds() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/generate"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/generate"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"response": "ok"})),
+            )
+            .mount(&server)
+            .await;
+
+        let config = LLMConfig {
+            endpoint: server.uri(),
+            ..LLMConfig::default()
+        };
+        let client = OllamaClient::new(config);
+
+        let response = client.complete("hi").await.unwrap();
+        assert_eq!(response, "ok");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+}
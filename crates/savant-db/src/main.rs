@@ -5,9 +5,9 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand};
-use savant_db::{TranscriptDatabase, TranscriptQuery};
+use savant_db::{TranscriptDatabase, TranscriptQuery, LLMClientFactory, LLMConfig};
 use serde_json;
-use std::io::{self, BufRead, BufReader};
+use std::io::{self, Read};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -23,6 +23,11 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
     /// Store transcription from JSON input (stdin or file)
     Store {
         /// Input file (reads from stdin if not provided)
@@ -34,6 +39,9 @@ enum Commands {
         /// Title for new conversation
         #[arg(short, long)]
         title: Option<String>,
+        /// Abort on the first malformed line instead of skipping it
+        #[arg(long)]
+        strict: bool,
     },
     /// Query transcription segments
     Query {
@@ -72,6 +80,9 @@ enum Commands {
         /// Speaker filter
         #[arg(long)]
         speaker: Option<String>,
+        /// Characters of adjacent-segment context to show on each side of a match
+        #[arg(long, default_value_t = savant_db::DEFAULT_CONTEXT_CHARS)]
+        context: usize,
     },
     /// List conversations
     List {
@@ -80,7 +91,11 @@ enum Commands {
         limit: i64,
     },
     /// Show conversation statistics by speaker
-    Stats,
+    Stats {
+        /// Output format: table (default) or csv
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
     /// Export conversation to JSON
     Export {
         /// Conversation ID to export
@@ -89,6 +104,18 @@ enum Commands {
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
+    /// Export every conversation and its segments as NDJSON, one conversation per line
+    ExportAll {
+        /// Output file (prints to stdout if not provided)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Import conversations from an export-all NDJSON file (stdin or file)
+    ImportAll {
+        /// Input file (reads from stdin if not provided)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
     /// Create a new conversation
     Create {
         /// Conversation title
@@ -103,6 +130,26 @@ enum Commands {
         /// Conversation ID to analyze
         conversation_id: String,
     },
+    /// Merge one or more source conversations into a target conversation
+    Merge {
+        /// Conversation ID to merge into
+        target: String,
+        /// Conversation IDs to merge and delete
+        #[arg(required = true)]
+        sources: Vec<String>,
+    },
+    /// (Re)compute embeddings for segments that don't have one yet, or that were
+    /// indexed with a different embedding model
+    Reindex {
+        /// Number of segments to process per batch
+        #[arg(long, default_value = "50")]
+        batch_size: usize,
+        /// Embedding model to index with (default: local-minilm). Switching models
+        /// re-embeds every segment indexed under a different model, since embeddings
+        /// from different models can't be compared.
+        #[arg(long)]
+        model: Option<String>,
+    },
     /// Speaker management commands
     Speaker {
         #[command(subcommand)]
@@ -113,6 +160,78 @@ enum Commands {
         #[command(subcommand)]
         command: TopicCommands,
     },
+    /// Conversation title commands
+    Title {
+        #[command(subcommand)]
+        command: TitleCommands,
+    },
+    /// Conversation tag commands
+    Tag {
+        #[command(subcommand)]
+        command: TagCommands,
+    },
+    /// Score and store sentiment for every segment in a conversation
+    Sentiment {
+        /// Conversation ID to score
+        conversation_id: String,
+    },
+    /// Watch for a keyword/phrase in newly stored segments until interrupted (Ctrl-C)
+    Alert {
+        /// Keyword or regex pattern to watch for
+        pattern: String,
+        /// Match case-sensitively
+        #[arg(long)]
+        case_sensitive: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TitleCommands {
+    /// Generate and store a title for a conversation from its content via an LLM
+    Generate {
+        /// Conversation ID to title
+        conversation_id: String,
+        /// LLM provider: ollama, openai, or mock
+        #[arg(long, default_value = "ollama")]
+        llm_provider: String,
+        /// LLM endpoint URL
+        #[arg(long, default_value = "http://localhost:11434")]
+        llm_endpoint: String,
+        /// LLM model name
+        #[arg(long, default_value = "llama3.2")]
+        llm_model: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TagCommands {
+    /// Add one or more tags to a conversation
+    Add {
+        /// Conversation ID
+        conversation_id: String,
+        /// Tags to add
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Remove one or more tags from a conversation
+    Remove {
+        /// Conversation ID
+        conversation_id: String,
+        /// Tags to remove
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// List conversations, optionally filtered by tag
+    List {
+        /// Only show conversations carrying these tags (all of them unless --any is set)
+        tags: Vec<String>,
+        /// Match any listed tag instead of requiring all of them
+        #[arg(long)]
+        any: bool,
+        /// Limit number of conversations
+        #[arg(long, default_value = "20")]
+        limit: i64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -139,6 +258,21 @@ enum SpeakerCommands {
         /// Secondary speaker ID (merge into primary)
         secondary: String,
     },
+    /// Export a speaker's profile to JSON for transfer to another database
+    Export {
+        /// Speaker ID to export
+        speaker_id: String,
+        /// Output file (prints to stdout if not provided)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Import a speaker profile exported from another database
+    Import {
+        /// Input file containing an exported speaker profile
+        input: PathBuf,
+    },
+    /// Re-tune confidence thresholds from recorded voice samples
+    TuneThresholds,
 }
 
 #[derive(Subcommand)]
@@ -158,6 +292,12 @@ enum TopicCommands {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+
+    if let Commands::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return Ok(());
+    }
+
     let mut db = TranscriptDatabase::new(cli.db_path).await?;
     
     // Initialize enhanced features
@@ -165,30 +305,35 @@ async fn main() -> Result<()> {
     db.init_semantic_search().await?;
 
     match cli.command {
-        Commands::Store { input, conversation, title } => {
-            let input_reader: Box<dyn BufRead> = if let Some(path) = input {
-                Box::new(BufReader::new(std::fs::File::open(path)?))
+        Commands::Completions { .. } => unreachable!("handled above, before the database is opened"),
+        Commands::Store { input, conversation, title, strict } => {
+            let content = if let Some(path) = input {
+                std::fs::read_to_string(path)?
             } else {
-                Box::new(io::stdin().lock())
+                let mut buf = String::new();
+                io::stdin().lock().read_to_string(&mut buf)?;
+                buf
             };
 
-            for line in input_reader.lines() {
-                let line = line?;
-                if line.trim().is_empty() {
-                    continue;
-                }
+            let outcome = parse_store_input(&content, strict, |line_number, message| {
+                eprintln!("Skipping invalid line {}: {}", line_number, message);
+            })
+            .map_err(|e| anyhow::anyhow!(e))?;
 
-                let result: savant_stt::TranscriptionResult = serde_json::from_str(&line)?;
-                
+            let mut imported = 0usize;
+            for result in &outcome.valid {
                 let conv_id = if let Some(id) = &conversation {
                     id.clone()
                 } else {
                     db.create_conversation(title.as_deref(), None).await?
                 };
 
-                let stored_id = db.store_transcription(&result, Some(conv_id)).await?;
+                let stored_id = db.store_transcription(result, Some(conv_id)).await?;
                 println!("Stored transcription in conversation: {}", stored_id);
+                imported += 1;
             }
+
+            eprintln!("Import summary: {} imported, {} skipped", imported, outcome.skipped);
         }
 
         Commands::Query { conversation, speaker, text, start, end, limit, offset } => {
@@ -246,20 +391,24 @@ async fn main() -> Result<()> {
             }
         }
 
-        Commands::Stats => {
-            let stats = db.get_speaker_stats().await?;
-            
-            println!("{:<20} {:<15} {:<15} {:<10} {:<10}", 
-                     "Speaker", "Conversations", "Total Time", "Segments", "Avg Conf");
-            println!("{}", "─".repeat(80));
-            
-            for stat in stats {
-                println!("{:<20} {:<15} {:<15.1}s {:<10} {:<10.2}", 
-                         stat.speaker,
-                         stat.conversation_count,
-                         stat.total_duration_seconds,
-                         stat.total_segments,
-                         stat.avg_confidence);
+        Commands::Stats { format } => {
+            if format == "csv" {
+                print!("{}", db.speaker_stats_csv().await?);
+            } else {
+                let stats = db.get_speaker_stats().await?;
+
+                println!("{:<20} {:<15} {:<15} {:<10} {:<10}",
+                         "Speaker", "Conversations", "Total Time", "Segments", "Avg Conf");
+                println!("{}", "─".repeat(80));
+
+                for stat in stats {
+                    println!("{:<20} {:<15} {:<15.1}s {:<10} {:<10.2}",
+                             stat.speaker,
+                             stat.conversation_count,
+                             stat.total_duration_seconds,
+                             stat.total_segments,
+                             stat.avg_confidence);
+                }
             }
         }
 
@@ -275,13 +424,52 @@ async fn main() -> Result<()> {
             }
         }
 
+        Commands::ExportAll { output } => {
+            let summary = match &output {
+                Some(path) => db.export_all(std::fs::File::create(path)?).await?,
+                None => db.export_all(io::stdout()).await?,
+            };
+            eprintln!(
+                "Exported {} conversation(s), {} segment(s)",
+                summary.conversations, summary.segments
+            );
+        }
+
+        Commands::ImportAll { input } => {
+            let summary = match &input {
+                Some(path) => db.import_all(io::BufReader::new(std::fs::File::open(path)?)).await?,
+                None => db.import_all(io::stdin().lock()).await?,
+            };
+            eprintln!(
+                "Imported {} conversation(s), {} segment(s)",
+                summary.conversations, summary.segments
+            );
+        }
+
+        Commands::Reindex { batch_size, model } => {
+            if let Some(model_name) = model {
+                db.set_embedding_model(savant_db::EmbeddingModel::by_name(&model_name)?);
+            }
+            let indexed = db
+                .reindex(batch_size, |done, total| {
+                    eprintln!("Reindexed {}/{} segments", done, total);
+                })
+                .await?;
+            println!("Reindexed {} segment(s)", indexed);
+        }
+
         Commands::Create { title, context } => {
             let conversation_id = db.create_conversation(title.as_deref(), context.as_deref()).await?;
             println!("Created conversation: {}", conversation_id);
         }
 
-        Commands::Search { query, limit, threshold, speaker } => {
-            let results = db.semantic_search(&query, limit, threshold).await?;
+        Commands::Merge { target, sources } => {
+            db.merge_conversations(&target, &sources).await?;
+            println!("Merged {} conversation(s) into {}", sources.len(), target);
+        }
+
+        Commands::Search { query, limit, threshold, speaker, context } => {
+            let results = db.semantic_search(&query, limit, threshold, context).await?;
             
             if results.is_empty() {
                 println!("No results found for query: \"{}\"", query);
@@ -315,7 +503,7 @@ async fn main() -> Result<()> {
         }
 
         Commands::Analyze { conversation_id } => {
-            let analysis = db.analyze_conversation(&conversation_id).await?;
+            let analysis = db.analyze_conversation_cached(&conversation_id).await?;
             
             println!("Conversation Analysis: {}", conversation_id);
             println!("{}", "═".repeat(60));
@@ -412,6 +600,37 @@ async fn main() -> Result<()> {
                     db.merge_speakers(&primary, &secondary).await?;
                     println!("Merged speaker {} into {}", secondary, primary);
                 }
+
+                SpeakerCommands::Export { speaker_id, output } => {
+                    let export = db.export_speaker(&speaker_id).await?;
+                    let json_output = serde_json::to_string_pretty(&export)?;
+
+                    if let Some(path) = output {
+                        std::fs::write(path, json_output)?;
+                        println!("Exported speaker {}", speaker_id);
+                    } else {
+                        println!("{}", json_output);
+                    }
+                }
+
+                SpeakerCommands::Import { input } => {
+                    let json = std::fs::read_to_string(input)?;
+                    let export = serde_json::from_str(&json)?;
+                    let speaker_id = db.import_speaker(export).await?;
+                    println!("Imported speaker: {}", speaker_id);
+                }
+
+                SpeakerCommands::TuneThresholds => {
+                    let tuned = db.tune_speaker_thresholds().await?;
+
+                    if tuned.is_empty() {
+                        println!("No speakers had enough voice samples to tune");
+                    } else {
+                        for (speaker_id, threshold) in tuned {
+                            println!("{}: confidence_threshold = {:.3}", speaker_id, threshold);
+                        }
+                    }
+                }
             }
         }
 
@@ -455,7 +674,168 @@ async fn main() -> Result<()> {
                 }
             }
         }
+
+        Commands::Title { command } => match command {
+            TitleCommands::Generate { conversation_id, llm_provider, llm_endpoint, llm_model } => {
+                let llm_config = LLMConfig {
+                    provider: llm_provider,
+                    endpoint: llm_endpoint,
+                    model: llm_model,
+                    api_key: std::env::var("OPENAI_API_KEY").ok(),
+                    ..LLMConfig::default()
+                };
+                let llm = LLMClientFactory::create_client(&llm_config)?;
+                let title = db.generate_title(&conversation_id, &llm).await?;
+                println!("Title for conversation {}: {}", conversation_id, title);
+            }
+        },
+
+        Commands::Tag { command } => match command {
+            TagCommands::Add { conversation_id, tags } => {
+                db.add_tags(&conversation_id, &tags).await?;
+                println!("Added tag(s) {} to conversation {}", tags.join(", "), conversation_id);
+            }
+
+            TagCommands::Remove { conversation_id, tags } => {
+                db.remove_tags(&conversation_id, &tags).await?;
+                println!("Removed tag(s) {} from conversation {}", tags.join(", "), conversation_id);
+            }
+
+            TagCommands::List { tags, any, limit } => {
+                let conversations = if tags.is_empty() {
+                    db.list_conversations(Some(limit)).await?
+                } else {
+                    db.list_conversations_by_tags(&tags, !any, Some(limit)).await?
+                };
+
+                println!("{:<36} {:<20} {:<20} {:<10} {:<10}",
+                         "ID", "Title", "Start Time", "Segments", "Duration");
+                println!("{}", "─".repeat(100));
+
+                for conv in conversations {
+                    let title = conv.title.unwrap_or_else(|| "Untitled".to_string());
+                    let title = if title.len() > 18 {
+                        format!("{}...", &title[..15])
+                    } else {
+                        title
+                    };
+
+                    println!("{:<36} {:<20} {:<20} {:<10} {:<10.1}s",
+                             conv.id,
+                             title,
+                             conv.start_time.format("%Y-%m-%d %H:%M"),
+                             conv.segment_count,
+                             conv.total_duration);
+                }
+            }
+        },
+
+        Commands::Sentiment { conversation_id } => {
+            let scored = db.score_conversation_sentiment(&conversation_id).await?;
+            println!("Scored sentiment for {} segment(s) in conversation {}", scored, conversation_id);
+        }
+
+        Commands::Alert { pattern, case_sensitive } => {
+            db.register_alert(&pattern, case_sensitive).await?;
+            let mut alerts = db.subscribe_alerts().await;
+            println!("Watching for \"{}\" (Ctrl-C to stop)...", pattern);
+
+            while let Some(event) = alerts.recv().await {
+                println!(
+                    "[{}] matched \"{}\": {}",
+                    event.conversation_id, event.pattern, event.segment_text
+                );
+            }
+        }
     }
 
     Ok(())
+}
+
+/// Result of parsing a `store` input: the successfully parsed transcriptions, in order,
+/// and a count of lines that failed to parse.
+struct StoreOutcome {
+    valid: Vec<savant_stt::TranscriptionResult>,
+    skipped: usize,
+}
+
+/// Parse every non-blank line of `content` as a `TranscriptionResult`.
+///
+/// In non-strict mode, an invalid line is reported through `on_invalid` (1-indexed line
+/// number and the parse error) and counted in `skipped` rather than aborting the whole
+/// import. In strict mode, the first invalid line returns `Err` immediately.
+fn parse_store_input(
+    content: &str,
+    strict: bool,
+    mut on_invalid: impl FnMut(usize, &str),
+) -> Result<StoreOutcome, String> {
+    let mut valid = Vec::new();
+    let mut skipped = 0usize;
+
+    for (line_number, line) in content.lines().enumerate().map(|(i, l)| (i + 1, l)) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<savant_stt::TranscriptionResult>(line) {
+            Ok(result) => valid.push(result),
+            Err(e) => {
+                if strict {
+                    return Err(format!("line {}: {}", line_number, e));
+                }
+                on_invalid(line_number, &e.to_string());
+                skipped += 1;
+            }
+        }
+    }
+
+    Ok(StoreOutcome { valid, skipped })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_line(text: &str) -> String {
+        serde_json::json!({
+            "text": text,
+            "language": null,
+            "segments": [],
+            "processing_time_ms": 0,
+            "model_used": "test",
+            "session_metadata": null
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_parse_store_input_skips_invalid_lines_and_reports_summary() {
+        let content = format!(
+            "{}\nnot valid json\n{}\n\n",
+            valid_line("hello"),
+            valid_line("world")
+        );
+
+        let mut invalid_lines = Vec::new();
+        let outcome = parse_store_input(&content, false, |line_number, _message| {
+            invalid_lines.push(line_number);
+        })
+        .unwrap();
+
+        assert_eq!(outcome.valid.len(), 2);
+        assert_eq!(outcome.valid[0].text, "hello");
+        assert_eq!(outcome.valid[1].text, "world");
+        assert_eq!(outcome.skipped, 1);
+        assert_eq!(invalid_lines, vec![2]);
+    }
+
+    #[test]
+    fn test_parse_store_input_strict_mode_fails_fast_on_first_invalid_line() {
+        let content = format!("{}\nnot valid json\n{}\n", valid_line("hello"), valid_line("world"));
+
+        let result = parse_store_input(&content, true, |_, _| {});
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("line 2"));
+    }
 }
\ No newline at end of file
@@ -12,6 +12,7 @@ use tokio::sync::RwLock;
 use sqlparser::ast::{Statement, Query, SetExpr, TableFactor, Expr};
 use sqlparser::dialect::SQLiteDialect;
 use sqlparser::parser::Parser;
+use sqlx::{Row, SqlitePool};
 
 /// Query complexity levels for rate limiting
 #[derive(Debug, Clone, PartialEq)]
@@ -86,6 +87,9 @@ pub enum SecurityError {
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
 
+    #[error("Query complexity too high: expensive joins/aggregations are not permitted")]
+    ComplexityTooHigh,
+
     #[error("String concatenation detected - requires parameterization")]
     RequiresParameterization,
 
@@ -245,12 +249,19 @@ impl QuerySecurityManager {
             return Err(SecurityError::TimingAttack);
         }
 
-        // 7. Rate limiting per query complexity
+        // 7. Reject queries whose estimated cost is too high outright, rather than
+        // merely rate-limiting them - an expensive cross join shouldn't be allowed
+        // just because it's the first one this minute.
+        if complexity == QueryComplexity::High {
+            return Err(SecurityError::ComplexityTooHigh);
+        }
+
+        // 8. Rate limiting per query complexity
         if !self.rate_limiter.check_complexity(complexity).await {
             return Err(SecurityError::RateLimitExceeded);
         }
 
-        // 8. Enforce parameterized queries
+        // 9. Enforce parameterized queries
         if self.contains_string_concatenation(query) {
             return Err(SecurityError::RequiresParameterization);
         }
@@ -332,6 +343,17 @@ impl QuerySecurityManager {
         self.concatenation_patterns.iter().any(|pattern| pattern.is_match(query))
     }
 
+    /// Enforce a per-session request rate limit, independent of query complexity.
+    ///
+    /// Callers (e.g. the MCP server) should key this on their own session
+    /// identifier so one noisy client can't starve others.
+    pub async fn check_session_rate_limit(&self, session_id: &str) -> Result<(), SecurityError> {
+        if !self.rate_limiter.check_rate_limit(session_id).await {
+            return Err(SecurityError::RateLimitExceeded);
+        }
+        Ok(())
+    }
+
     /// Estimate query complexity for rate limiting
     pub fn estimate_query_cost(&self, query: &str) -> QueryComplexity {
         let complexity_score = query.matches("JOIN").count() * 2 +
@@ -347,6 +369,30 @@ impl QuerySecurityManager {
         }
     }
 
+    /// Run `EXPLAIN QUERY PLAN` for `query` against `pool` and reject plans containing
+    /// an expensive operation - a full table `SCAN` without a supporting index, or a
+    /// temporary B-tree for sorting/grouping. This catches queries the static heuristic
+    /// in [`Self::estimate_query_cost`] underestimates, e.g. an unindexed `WHERE` clause
+    /// on an otherwise simple single-table SELECT.
+    pub async fn validate_query_plan(&self, pool: &SqlitePool, query: &str) -> Result<(), SecurityError> {
+        let plan_rows = sqlx::query(&format!("EXPLAIN QUERY PLAN {query}"))
+            .fetch_all(pool)
+            .await
+            .map_err(|e| SecurityError::ParseError { reason: e.to_string() })?;
+
+        for row in &plan_rows {
+            let detail: String = row.try_get("detail").unwrap_or_default();
+            if detail.contains("SCAN") && !detail.contains("USING INDEX") {
+                return Err(SecurityError::ComplexityTooHigh);
+            }
+            if detail.contains("USE TEMP B-TREE") {
+                return Err(SecurityError::ComplexityTooHigh);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Legacy SQL validation method for backward compatibility
     pub fn validate_sql_query(&self, sql: &str) -> Result<(), SecurityError> {
         let complexity = self.estimate_query_cost(sql);
@@ -546,6 +592,65 @@ mod tests {
         assert!(security.validate_query("SELECT * FROM unauthorized_table", QueryComplexity::Low).await.is_err());
     }
 
+    #[tokio::test]
+    async fn test_high_complexity_query_rejected() {
+        let security = QuerySecurityManager::new();
+
+        let result = security.validate_query(
+            "SELECT * FROM conversations c JOIN segments s ON s.conversation_id = c.id \
+             JOIN speakers sp ON sp.id = s.speaker GROUP BY c.id ORDER BY c.id LIMIT 10",
+            QueryComplexity::High,
+        ).await;
+
+        assert!(matches!(result, Err(SecurityError::ComplexityTooHigh)));
+    }
+
+    #[tokio::test]
+    async fn test_session_rate_limit_exceeded() {
+        let security = QuerySecurityManager::new();
+
+        for _ in 0..60 {
+            assert!(security.check_session_rate_limit("session-a").await.is_ok());
+        }
+        assert!(matches!(
+            security.check_session_rate_limit("session-a").await,
+            Err(SecurityError::RateLimitExceeded)
+        ));
+
+        // A different session has its own independent budget.
+        assert!(security.check_session_rate_limit("session-b").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_query_plan_rejects_unindexed_scan() {
+        use sqlx::sqlite::SqlitePoolOptions;
+
+        let pool = SqlitePoolOptions::new()
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE conversations (id INTEGER PRIMARY KEY, speaker TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let security = QuerySecurityManager::new();
+
+        // No index on `speaker` - the plan is a full table scan.
+        assert!(matches!(
+            security
+                .validate_query_plan(&pool, "SELECT * FROM conversations WHERE speaker = 'john'")
+                .await,
+            Err(SecurityError::ComplexityTooHigh)
+        ));
+
+        // A scan by rowid (the primary key) is cheap and should be allowed.
+        assert!(security
+            .validate_query_plan(&pool, "SELECT * FROM conversations WHERE id = 1")
+            .await
+            .is_ok());
+    }
+
     #[test]
     fn test_input_sanitization() {
         assert_eq!(sanitize_input("normal text").unwrap(), "normal text");
@@ -9,8 +9,14 @@ use ndarray::{Array1};
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
+/// Default capacity of [`SemanticSearchEngine`]'s query embedding cache.
+pub const DEFAULT_QUERY_EMBEDDING_CACHE_CAPACITY: usize = 256;
+
 /// Semantic embedding for text segments
 #[derive(Debug, Clone)]
 pub struct SemanticEmbedding {
@@ -40,6 +46,10 @@ pub struct SearchResult {
     pub speaker_id: Option<String>,
     pub text: String,
     pub similarity_score: f32,
+    /// Which [`DistanceMetric`] `similarity_score` was computed with, so scores from
+    /// different engines/searches aren't compared against each other as if they were
+    /// on the same scale.
+    pub metric: DistanceMetric,
     pub timestamp: DateTime<Utc>,
     pub context_before: Option<String>,
     pub context_after: Option<String>,
@@ -58,35 +68,279 @@ pub struct ConversationAnalysis {
     pub quality_score: f32,
 }
 
+/// Default number of characters of adjacent-segment context returned on each side of a
+/// search match when the caller doesn't specify `context_chars`.
+pub const DEFAULT_CONTEXT_CHARS: usize = 100;
+
+/// Where an [`EmbeddingModel`] computes its vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmbeddingProvider {
+    /// Computed in-process, no network call (the feature-hashing placeholder today).
+    Local,
+    /// Computed via a local Ollama server.
+    Ollama,
+    /// Computed via the OpenAI embeddings API.
+    OpenAi,
+}
+
+/// An embedding model's identity and the shape of vectors it produces. Segments are
+/// stamped with the name of the model that embedded them (see the `embedding_model`
+/// column added in migration 012) so [`SemanticSearchEngine`] never mixes embeddings
+/// from two models with different dimensions in the same similarity comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingModel {
+    pub name: String,
+    pub provider: EmbeddingProvider,
+    pub dimension: usize,
+}
+
+impl EmbeddingModel {
+    /// Fast, in-process default - no external dependency, good enough to search on
+    /// out of the box.
+    pub fn local_minilm() -> Self {
+        Self {
+            name: "local-minilm".to_string(),
+            provider: EmbeddingProvider::Local,
+            dimension: 384,
+        }
+    }
+
+    /// Higher-quality model served by a local Ollama instance.
+    pub fn ollama_nomic_embed_text() -> Self {
+        Self {
+            name: "ollama-nomic-embed-text".to_string(),
+            provider: EmbeddingProvider::Ollama,
+            dimension: 768,
+        }
+    }
+
+    /// Higher-quality hosted model via the OpenAI API.
+    pub fn openai_text_embedding_3_small() -> Self {
+        Self {
+            name: "openai-text-embedding-3-small".to_string(),
+            provider: EmbeddingProvider::OpenAi,
+            dimension: 1536,
+        }
+    }
+
+    /// Resolve a model by the name users pass to `--model`, e.g. on `reindex --model
+    /// <name>`. Returns a descriptive error for an unknown name rather than panicking.
+    pub fn by_name(name: &str) -> Result<Self> {
+        match name {
+            "local-minilm" => Ok(Self::local_minilm()),
+            "ollama-nomic-embed-text" => Ok(Self::ollama_nomic_embed_text()),
+            "openai-text-embedding-3-small" => Ok(Self::openai_text_embedding_3_small()),
+            other => Err(anyhow!(
+                "Unknown embedding model '{}': expected one of local-minilm, \
+                 ollama-nomic-embed-text, openai-text-embedding-3-small",
+                other
+            )),
+        }
+    }
+}
+
+impl Default for EmbeddingModel {
+    fn default() -> Self {
+        Self::local_minilm()
+    }
+}
+
+/// How [`SemanticSearchEngine`] ranks two embeddings against each other. Different
+/// embedding models are tuned for different metrics (e.g. models whose vectors are
+/// already unit-length are often tuned for dot product, so normalizing them again
+/// would throw away magnitude information the model encoded on purpose), so this is
+/// configurable independently of the [`EmbeddingModel`] itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Angle between vectors, ignoring magnitude. Normalizes both vectors by their own
+    /// L2 norm before comparing, so it's insensitive to embeddings with inconsistent
+    /// scale.
+    Cosine,
+    /// Raw dot product, magnitude included. Appropriate when the embedding model
+    /// already calibrates vector magnitude to carry meaning (e.g. pre-normalized
+    /// embeddings tuned for this metric) -- normalizing here would discard that.
+    DotProduct,
+    /// Straight-line distance. Smaller distance means more similar; converted to a
+    /// `(0, 1]` similarity score (`1 / (1 + distance)`) so it sorts and thresholds the
+    /// same direction as the other two metrics.
+    Euclidean,
+}
+
+impl Default for DistanceMetric {
+    fn default() -> Self {
+        DistanceMetric::Cosine
+    }
+}
+
+impl DistanceMetric {
+    /// Score `a` against `b` under this metric. Always higher-is-more-similar,
+    /// regardless of metric, so callers can sort descending and threshold against
+    /// `min_similarity` without needing to know which metric produced the score.
+    fn score(&self, a: &Array1<f32>, b: &Array1<f32>) -> f32 {
+        match self {
+            DistanceMetric::Cosine => cosine_similarity(a, b),
+            DistanceMetric::DotProduct => a.dot(b),
+            DistanceMetric::Euclidean => {
+                let distance = (a - b).mapv(|x| x * x).sum().sqrt();
+                1.0 / (1.0 + distance)
+            }
+        }
+    }
+}
+
+/// Keep at most the last `max_chars` characters of `text` (the portion closest to the
+/// match), trimmed forward to the next word boundary so the result never starts mid-word.
+fn truncate_context_before(text: &str, max_chars: usize) -> String {
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text.to_string();
+    }
+
+    let skip = char_count - max_chars;
+    let tail: String = text.chars().skip(skip).collect();
+    match tail.find(char::is_whitespace) {
+        Some(idx) => tail[idx..].trim_start().to_string(),
+        None => tail,
+    }
+}
+
+/// Keep at most the first `max_chars` characters of `text` (the portion closest to the
+/// match), trimmed back to the previous word boundary so the result never ends mid-word.
+fn truncate_context_after(text: &str, max_chars: usize) -> String {
+    let char_count = text.chars().count();
+    if char_count <= max_chars {
+        return text.to_string();
+    }
+
+    let head: String = text.chars().take(max_chars).collect();
+    match head.rfind(char::is_whitespace) {
+        Some(idx) => head[..idx].trim_end().to_string(),
+        None => head,
+    }
+}
+
 /// Semantic search engine for conversations
 pub struct SemanticSearchEngine {
     pool: SqlitePool,
     embedding_cache: HashMap<String, Array1<f32>>,
-    embedding_dimension: usize,
+    embedding_model: EmbeddingModel,
+    metric: DistanceMetric,
+    /// Keyed by (embedding model id, normalized query text) so the same query text
+    /// against two different models never collides. `Mutex`-guarded so it stays safe
+    /// to populate from the `&self` API.
+    query_embedding_cache: Arc<Mutex<lru::LruCache<(String, String), Array1<f32>>>>,
+    query_embedding_computations: Arc<AtomicUsize>,
 }
 
 impl SemanticSearchEngine {
-    /// Create new semantic search engine
+    /// Create new semantic search engine, using the default [`EmbeddingModel`] and
+    /// [`DistanceMetric`].
     pub fn new(pool: SqlitePool) -> Self {
+        Self::with_model(pool, EmbeddingModel::default())
+    }
+
+    /// Create a new semantic search engine configured for a specific embedding model,
+    /// using the default [`DistanceMetric`].
+    pub fn with_model(pool: SqlitePool, embedding_model: EmbeddingModel) -> Self {
         Self {
             pool,
             embedding_cache: HashMap::new(),
-            embedding_dimension: 384, // MiniLM default
+            embedding_model,
+            metric: DistanceMetric::default(),
+            query_embedding_cache: Arc::new(Mutex::new(lru::LruCache::new(
+                NonZeroUsize::new(DEFAULT_QUERY_EMBEDDING_CACHE_CAPACITY).unwrap(),
+            ))),
+            query_embedding_computations: Arc::new(AtomicUsize::new(0)),
         }
     }
 
-    /// Load embeddings into memory for fast similarity search
+    /// Resize the query embedding cache, discarding whatever is currently cached.
+    pub fn set_query_embedding_cache_capacity(&mut self, capacity: usize) {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::new(1).unwrap());
+        self.query_embedding_cache = Arc::new(Mutex::new(lru::LruCache::new(capacity)));
+    }
+
+    /// Compute (or reuse a cached) embedding for `query_text` under the engine's
+    /// currently configured [`EmbeddingModel`]. Repeated calls with the same query text
+    /// and model reuse the cached vector instead of recomputing it -- see
+    /// [`Self::query_embedding_computations`] to observe cache misses.
+    pub fn embed_query(&self, query_text: &str) -> Array1<f32> {
+        let key = (self.embedding_model.name.clone(), query_text.trim().to_lowercase());
+
+        if let Some(cached) = self.query_embedding_cache.lock().unwrap().get(&key) {
+            return cached.clone();
+        }
+
+        let embedding = compute_placeholder_embedding(&key.1, self.embedding_model.dimension);
+        self.query_embedding_computations.fetch_add(1, Ordering::Relaxed);
+        self.query_embedding_cache.lock().unwrap().put(key, embedding.clone());
+
+        embedding
+    }
+
+    /// How many times [`Self::embed_query`] has actually computed an embedding (i.e.
+    /// cache misses), rather than reusing a cached one.
+    pub fn query_embedding_computations(&self) -> usize {
+        self.query_embedding_computations.load(Ordering::Relaxed)
+    }
+
+    /// Convenience wrapper over [`Self::semantic_search`] that computes the query
+    /// embedding from `query_text` via [`Self::embed_query`] first, so repeated
+    /// identical queries (common in interactive UIs) don't recompute it.
+    pub async fn semantic_search_text(
+        &self,
+        query_text: &str,
+        limit: usize,
+        min_similarity: f32,
+        context_chars: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let embedding = self.embed_query(query_text);
+        self.semantic_search(&embedding, limit, min_similarity, context_chars).await
+    }
+
+    /// The embedding model this engine currently indexes and queries against.
+    pub fn embedding_model(&self) -> &EmbeddingModel {
+        &self.embedding_model
+    }
+
+    /// The distance metric this engine currently ranks results with.
+    pub fn metric(&self) -> DistanceMetric {
+        self.metric
+    }
+
+    /// Change the distance metric used to rank results.
+    pub fn set_metric(&mut self, metric: DistanceMetric) {
+        self.metric = metric;
+    }
+
+    /// Switch to a different embedding model and drop the in-memory cache, since
+    /// cached embeddings from the old model don't share the new model's dimension.
+    /// Call [`Self::load_embeddings`] afterwards to load anything already indexed
+    /// under the new model, and [`Self::reindex`] to (re)compute the rest.
+    pub fn set_embedding_model(&mut self, embedding_model: EmbeddingModel) {
+        self.embedding_model = embedding_model;
+        self.embedding_cache.clear();
+    }
+
+    /// Load embeddings into memory for fast similarity search. Only loads segments
+    /// indexed with the currently configured [`EmbeddingModel`] -- segments indexed
+    /// under a different model are skipped so the cache never mixes embeddings of
+    /// different dimensions.
     pub async fn load_embeddings(&mut self) -> Result<()> {
         let rows = sqlx::query(
-            "SELECT id, semantic_embedding FROM segments WHERE semantic_embedding IS NOT NULL"
+            r#"SELECT id, semantic_embedding FROM segments
+               WHERE semantic_embedding IS NOT NULL
+                 AND COALESCE(embedding_model, ?) = ?"#
         )
+        .bind(&self.embedding_model.name)
+        .bind(&self.embedding_model.name)
         .fetch_all(&self.pool)
         .await?;
 
         for row in rows {
             let segment_id: String = row.get("id");
             if let Some(embedding_blob) = row.get::<Option<Vec<u8>>, _>("semantic_embedding") {
-                if let Ok(embedding) = deserialize_embedding(&embedding_blob, self.embedding_dimension) {
+                if let Ok(embedding) = deserialize_embedding(&embedding_blob, self.embedding_model.dimension) {
                     self.embedding_cache.insert(segment_id, embedding);
                 }
             }
@@ -95,7 +349,8 @@ impl SemanticSearchEngine {
         Ok(())
     }
 
-    /// Store semantic embedding for a text segment
+    /// Store semantic embedding for a text segment, stamped with the currently
+    /// configured [`EmbeddingModel`]'s name.
     pub async fn store_embedding(
         &mut self,
         segment_id: &str,
@@ -105,10 +360,11 @@ impl SemanticSearchEngine {
         let embedding_blob = serialize_embedding(embedding);
 
         sqlx::query(
-            "UPDATE segments SET semantic_embedding = ?, processed_text = ? WHERE id = ?"
+            "UPDATE segments SET semantic_embedding = ?, processed_text = ?, embedding_model = ? WHERE id = ?"
         )
         .bind(&embedding_blob)
         .bind(text)
+        .bind(&self.embedding_model.name)
         .bind(segment_id)
         .execute(&self.pool)
         .await?;
@@ -119,11 +375,60 @@ impl SemanticSearchEngine {
         Ok(())
     }
 
+    /// Find segments with no embedding, or an embedding from a different model than
+    /// the one currently configured, and (re)compute one for each under the current
+    /// model, in batches of `batch_size` so a large backlog (e.g. after importing old
+    /// data, or switching models) doesn't load every row into memory at once.
+    /// `progress` is called after each batch with `(indexed_so_far, total_to_index)`.
+    /// Returns how many segments were indexed.
+    ///
+    /// Embeddings are a deterministic feature-hashing bag-of-words vector (see
+    /// [`compute_placeholder_embedding`]), not a real sentence-transformer model, which
+    /// isn't available in this environment -- same placeholder caveat as
+    /// [`semantic_search`](Self::semantic_search).
+    pub async fn reindex(&self, batch_size: usize, progress: impl Fn(usize, usize)) -> Result<usize> {
+        let rows = sqlx::query(
+            r#"SELECT id, text FROM segments
+               WHERE semantic_embedding IS NULL
+                  OR COALESCE(embedding_model, ?) != ?"#
+        )
+        .bind(&self.embedding_model.name)
+        .bind(&self.embedding_model.name)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let total = rows.len();
+        let mut indexed = 0;
+
+        for batch in rows.chunks(batch_size.max(1)) {
+            for row in batch {
+                let segment_id: String = row.get("id");
+                let text: String = row.get("text");
+                let embedding = compute_placeholder_embedding(&text, self.embedding_model.dimension);
+                let embedding_blob = serialize_embedding(&embedding);
+
+                sqlx::query("UPDATE segments SET semantic_embedding = ?, embedding_model = ? WHERE id = ?")
+                    .bind(&embedding_blob)
+                    .bind(&self.embedding_model.name)
+                    .bind(&segment_id)
+                    .execute(&self.pool)
+                    .await?;
+
+                indexed += 1;
+            }
+
+            progress(indexed, total);
+        }
+
+        Ok(indexed)
+    }
+
     /// Text-based search for conversation segments (simplified implementation)
     pub async fn text_search(
         &self,
         query: &str,
         limit: usize,
+        context_chars: usize,
     ) -> Result<Vec<SearchResult>> {
         // Simple text search using SQL LIKE and full-text search
         let rows = sqlx::query(
@@ -146,7 +451,9 @@ impl SemanticSearchEngine {
             let end_time: f32 = row.get("end_time");
 
             // Get context around the match
-            let (context_before, context_after) = self.get_context(&conversation_id, start_time, end_time).await?;
+            let (context_before, context_after) = self
+                .get_context(&conversation_id, start_time, end_time, context_chars)
+                .await?;
 
             results.push(SearchResult {
                 segment_id: row.get("id"),
@@ -154,6 +461,7 @@ impl SemanticSearchEngine {
                 speaker_id: row.get("speaker"),
                 text: row.get("text"),
                 similarity_score: 1.0, // Placeholder for text match
+                metric: self.metric,
                 timestamp: row.get("timestamp"),
                 context_before,
                 context_after,
@@ -163,24 +471,58 @@ impl SemanticSearchEngine {
         Ok(results)
     }
 
-    /// Enhanced semantic search (placeholder for future ML implementation)
+    /// Rank cached embeddings against `query_embedding` under the engine's configured
+    /// [`DistanceMetric`] (see [`Self::metric`]) and return the
+    /// top `limit` segments scoring at least `min_similarity`. Refuses to compare
+    /// embeddings of different dimensions rather than panicking: if `query_embedding`
+    /// wasn't produced by the currently configured [`EmbeddingModel`] (see
+    /// [`Self::embedding_model`]), this returns a descriptive error asking the caller
+    /// to reindex with a matching model.
     pub async fn semantic_search(
         &self,
-        _query_embedding: &Array1<f32>,
-        _limit: usize,
-        _min_similarity: f32,
+        query_embedding: &Array1<f32>,
+        limit: usize,
+        min_similarity: f32,
+        context_chars: usize,
     ) -> Result<Vec<SearchResult>> {
-        // For now, this returns empty results as a placeholder
-        // In a full implementation, this would use sentence transformers
-        // to generate embeddings and perform cosine similarity search
-        Ok(Vec::new())
+        if query_embedding.len() != self.embedding_model.dimension {
+            return Err(anyhow!(
+                "Embedding dimension mismatch: query embedding has {} dimension(s), but \
+                 the index was built with model '{}' ({} dimension(s)). Run `reindex \
+                 --model {}` to rebuild the index with a matching model.",
+                query_embedding.len(),
+                self.embedding_model.name,
+                self.embedding_model.dimension,
+                self.embedding_model.name
+            ));
+        }
+
+        let mut scored: Vec<(String, f32)> = self
+            .embedding_cache
+            .iter()
+            .map(|(segment_id, embedding)| (segment_id.clone(), self.metric.score(query_embedding, embedding)))
+            .filter(|(_, score)| *score >= min_similarity)
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(limit);
+
+        let mut results = Vec::new();
+        for (segment_id, score) in scored {
+            if let Some(result) = self.get_search_result(&segment_id, score, context_chars).await? {
+                results.push(result);
+            }
+        }
+
+        Ok(results)
     }
 
     /// Get full search result with context
-    async fn _get_search_result(
+    async fn get_search_result(
         &self,
         segment_id: &str,
         similarity_score: f32,
+        context_chars: usize,
     ) -> Result<Option<SearchResult>> {
         let row = sqlx::query(
             r#"SELECT s.id, s.conversation_id, s.speaker, s.text, s.timestamp,
@@ -198,7 +540,9 @@ impl SemanticSearchEngine {
             let end_time: f32 = row.get("end_time");
 
             // Get context (previous and next segments)
-            let (context_before, context_after) = self.get_context(&conversation_id, start_time, end_time).await?;
+            let (context_before, context_after) = self
+                .get_context(&conversation_id, start_time, end_time, context_chars)
+                .await?;
 
             Ok(Some(SearchResult {
                 segment_id: row.get("id"),
@@ -206,6 +550,7 @@ impl SemanticSearchEngine {
                 speaker_id: row.get("speaker"),
                 text: row.get("text"),
                 similarity_score,
+                metric: self.metric,
                 timestamp: row.get("timestamp"),
                 context_before,
                 context_after,
@@ -215,36 +560,38 @@ impl SemanticSearchEngine {
         }
     }
 
-    /// Get conversation context around a segment
+    /// Get conversation context around a segment, truncated to at most `context_chars`
+    /// characters on each side at a word boundary (never a mid-word cut).
     async fn get_context(
         &self,
         conversation_id: &str,
         start_time: f32,
         end_time: f32,
+        context_chars: usize,
     ) -> Result<(Option<String>, Option<String>)> {
         // Get previous segment
         let context_before = sqlx::query(
-            r#"SELECT text FROM segments 
-               WHERE conversation_id = ? AND end_time <= ? 
+            r#"SELECT text FROM segments
+               WHERE conversation_id = ? AND end_time <= ?
                ORDER BY end_time DESC LIMIT 1"#
         )
         .bind(conversation_id)
         .bind(start_time)
         .fetch_optional(&self.pool)
         .await?
-        .map(|row| row.get::<String, _>("text"));
+        .map(|row| truncate_context_before(&row.get::<String, _>("text"), context_chars));
 
         // Get next segment
         let context_after = sqlx::query(
-            r#"SELECT text FROM segments 
-               WHERE conversation_id = ? AND start_time >= ? 
+            r#"SELECT text FROM segments
+               WHERE conversation_id = ? AND start_time >= ?
                ORDER BY start_time ASC LIMIT 1"#
         )
         .bind(conversation_id)
         .bind(end_time)
         .fetch_optional(&self.pool)
         .await?
-        .map(|row| row.get::<String, _>("text"));
+        .map(|row| truncate_context_after(&row.get::<String, _>("text"), context_chars));
 
         Ok((context_before, context_after))
     }
@@ -496,7 +843,7 @@ impl SemanticSearchEngine {
 }
 
 /// Calculate cosine similarity between two vectors
-fn _cosine_similarity(a: &Array1<f32>, b: &Array1<f32>) -> f32 {
+fn cosine_similarity(a: &Array1<f32>, b: &Array1<f32>) -> f32 {
     let dot_product = a.dot(b);
     let norm_a = a.mapv(|x| x * x).sum().sqrt();
     let norm_b = b.mapv(|x| x * x).sum().sqrt();
@@ -516,6 +863,28 @@ fn serialize_embedding(embedding: &Array1<f32>) -> Vec<u8> {
         .collect()
 }
 
+/// Deterministic feature-hashing bag-of-words embedding: each word hashes into one of
+/// `dim` buckets, which is then L2-normalized. A stand-in for a real sentence-transformer
+/// model until one is available; same spirit as the rest of this module's placeholders.
+fn compute_placeholder_embedding(text: &str, dim: usize) -> Array1<f32> {
+    let mut buckets = vec![0f32; dim.max(1)];
+
+    for word in text.split_whitespace() {
+        let hash = word.bytes().fold(0u64, |acc, b| acc.wrapping_mul(31).wrapping_add(b as u64));
+        let bucket = (hash % buckets.len() as u64) as usize;
+        buckets[bucket] += 1.0;
+    }
+
+    let norm = buckets.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in buckets.iter_mut() {
+            *value /= norm;
+        }
+    }
+
+    Array1::from_vec(buckets)
+}
+
 /// Deserialize embedding vector from binary format
 fn deserialize_embedding(blob: &[u8], expected_dim: usize) -> Result<Array1<f32>> {
     if blob.len() != expected_dim * 4 {
@@ -529,3 +898,248 @@ fn deserialize_embedding(blob: &[u8], expected_dim: usize) -> Result<Array1<f32>
 
     Ok(Array1::from_vec(embedding_data))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_context_before_respects_char_limit_without_splitting_words() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        let truncated = truncate_context_before(text, 15);
+
+        assert!(truncated.chars().count() <= 15);
+        assert!(text.ends_with(&truncated));
+        for word in truncated.split_whitespace() {
+            assert!(text.split_whitespace().any(|w| w == word));
+        }
+    }
+
+    #[test]
+    fn test_truncate_context_after_respects_char_limit_without_splitting_words() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        let truncated = truncate_context_after(text, 15);
+
+        assert!(truncated.chars().count() <= 15);
+        assert!(text.starts_with(&truncated));
+        for word in truncated.split_whitespace() {
+            assert!(text.split_whitespace().any(|w| w == word));
+        }
+    }
+
+    #[test]
+    fn test_truncate_context_leaves_short_text_untouched() {
+        let text = "short text";
+        assert_eq!(truncate_context_before(text, 100), text);
+        assert_eq!(truncate_context_after(text, 100), text);
+    }
+
+    #[test]
+    fn test_compute_placeholder_embedding_is_deterministic_and_normalized() {
+        let a = compute_placeholder_embedding("hello world", 16);
+        let b = compute_placeholder_embedding("hello world", 16);
+        assert_eq!(a, b);
+
+        let norm: f32 = a.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 0.001);
+    }
+
+    async fn pool_with_embedding_backlog(texts: &[&str]) -> (SqlitePool, tempfile::TempDir) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("reindex-test.db");
+        let database_url = format!("sqlite://{}", db_path.display());
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .connect(&database_url)
+            .await
+            .unwrap();
+
+        sqlx::query(
+            r#"CREATE TABLE segments (
+                id TEXT PRIMARY KEY,
+                conversation_id TEXT NOT NULL,
+                timestamp DATETIME NOT NULL,
+                speaker TEXT NOT NULL,
+                audio_source TEXT NOT NULL,
+                text TEXT NOT NULL,
+                start_time REAL NOT NULL,
+                end_time REAL NOT NULL,
+                confidence REAL,
+                metadata TEXT,
+                semantic_embedding BLOB,
+                processed_text TEXT,
+                embedding_model TEXT
+            )"#
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        for (i, text) in texts.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO segments (id, conversation_id, timestamp, speaker, audio_source, text, start_time, end_time)
+                 VALUES (?, 'conv-1', datetime('now'), 'Alice', '\"Microphone\"', ?, ?, ?)"
+            )
+            .bind(format!("segment-{}", i))
+            .bind(*text)
+            .bind(i as f64)
+            .bind(i as f64 + 1.0)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        (pool, temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_reindex_computes_embeddings_for_segments_missing_one() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&["hello world", "goodbye world"]).await;
+        let engine = SemanticSearchEngine::new(pool.clone());
+
+        let mut progress_calls = Vec::new();
+        let indexed = engine
+            .reindex(1, |done, total| progress_calls.push((done, total)))
+            .await
+            .unwrap();
+
+        assert_eq!(indexed, 2);
+        assert_eq!(progress_calls, vec![(1, 2), (2, 2)]);
+
+        let remaining = sqlx::query("SELECT COUNT(*) as count FROM segments WHERE semantic_embedding IS NULL")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let remaining_count: i64 = remaining.get("count");
+        assert_eq!(remaining_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_reindex_with_different_model_reembeds_segments_indexed_under_old_model() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&["hello world"]).await;
+        let engine = SemanticSearchEngine::new(pool.clone());
+        engine.reindex(10, |_, _| {}).await.unwrap();
+
+        let mut engine = engine;
+        engine.set_embedding_model(EmbeddingModel::ollama_nomic_embed_text());
+        let indexed = engine.reindex(10, |_, _| {}).await.unwrap();
+        assert_eq!(indexed, 1);
+
+        let row = sqlx::query("SELECT embedding_model FROM segments WHERE id = 'segment-0'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let stored_model: String = row.get("embedding_model");
+        assert_eq!(stored_model, "ollama-nomic-embed-text");
+    }
+
+    #[tokio::test]
+    async fn test_semantic_search_rejects_query_embedding_with_mismatched_dimension() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&["hello world"]).await;
+        let engine = SemanticSearchEngine::new(pool);
+
+        let wrong_dimension_query = Array1::from_vec(vec![0.1_f32; 16]);
+        let result = engine.semantic_search(&wrong_dimension_query, 5, 0.0, 50).await;
+
+        let error = result.expect_err("expected a dimension mismatch error, not a panic");
+        let message = error.to_string();
+        assert!(message.contains("dimension mismatch"), "unexpected error message: {}", message);
+        assert!(message.contains("local-minilm"), "unexpected error message: {}", message);
+    }
+
+    #[tokio::test]
+    async fn test_embed_query_caches_repeated_queries() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&[]).await;
+        let engine = SemanticSearchEngine::new(pool);
+
+        let first = engine.embed_query("How do I reset my password?");
+        let second = engine.embed_query("  HOW DO I RESET MY PASSWORD?  ");
+
+        assert_eq!(first, second);
+        assert_eq!(engine.query_embedding_computations(), 1);
+
+        engine.embed_query("a different query");
+        assert_eq!(engine.query_embedding_computations(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_semantic_search_text_reuses_cached_query_embedding() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&["hello world"]).await;
+        let engine = SemanticSearchEngine::new(pool.clone());
+        engine.reindex(10, |_, _| {}).await.unwrap();
+
+        let mut engine = engine;
+        engine.load_embeddings().await.unwrap();
+
+        engine.semantic_search_text("hello", 5, 0.0, 50).await.unwrap();
+        engine.semantic_search_text("hello", 5, 0.0, 50).await.unwrap();
+
+        assert_eq!(engine.query_embedding_computations(), 1);
+    }
+
+    #[test]
+    fn test_embedding_model_by_name_rejects_unknown_model() {
+        let error = EmbeddingModel::by_name("gpt-nonexistent").unwrap_err();
+        assert!(error.to_string().contains("Unknown embedding model"));
+    }
+
+    #[test]
+    fn test_distance_metrics_rank_aligned_vs_close_candidate_differently() {
+        // `aligned` points the same direction as `query` but with much larger magnitude.
+        // `close` points a different direction but lands very near `query` in space.
+        let query = Array1::from_vec(vec![1.0_f32, 0.0]);
+        let aligned = Array1::from_vec(vec![10.0_f32, 0.0]);
+        let close = Array1::from_vec(vec![1.0_f32, 0.9]);
+
+        // Cosine only cares about angle, so the perfectly-aligned vector wins outright.
+        let cosine_aligned = DistanceMetric::Cosine.score(&query, &aligned);
+        let cosine_close = DistanceMetric::Cosine.score(&query, &close);
+        assert!((cosine_aligned - 1.0).abs() < 1e-6);
+        assert!(cosine_aligned > cosine_close);
+
+        // Dot product rewards magnitude too, so the aligned vector wins even more clearly.
+        let dot_aligned = DistanceMetric::DotProduct.score(&query, &aligned);
+        let dot_close = DistanceMetric::DotProduct.score(&query, &close);
+        assert_eq!(dot_aligned, 10.0);
+        assert_eq!(dot_close, 1.0);
+        assert!(dot_aligned > dot_close);
+
+        // Euclidean cares about raw distance, so the nearby-but-misaligned vector wins instead --
+        // the ranking flips relative to cosine and dot product.
+        let euclidean_aligned = DistanceMetric::Euclidean.score(&query, &aligned);
+        let euclidean_close = DistanceMetric::Euclidean.score(&query, &close);
+        assert!(euclidean_close > euclidean_aligned);
+    }
+
+    #[tokio::test]
+    async fn test_semantic_search_ranking_changes_with_metric() {
+        let (pool, _temp_dir) = pool_with_embedding_backlog(&["aligned", "close"]).await;
+        let mut engine = SemanticSearchEngine::with_model(pool.clone(), EmbeddingModel {
+            name: "test-2d".to_string(),
+            provider: EmbeddingProvider::Local,
+            dimension: 2,
+        });
+
+        // Bypass the placeholder text embedder and store known vectors directly so the
+        // test is about metric behavior, not the hashing scheme.
+        engine
+            .store_embedding("segment-0", "aligned", &Array1::from_vec(vec![10.0_f32, 0.0]))
+            .await
+            .unwrap();
+        engine
+            .store_embedding("segment-1", "close", &Array1::from_vec(vec![1.0_f32, 0.9]))
+            .await
+            .unwrap();
+
+        let query = Array1::from_vec(vec![1.0_f32, 0.0]);
+
+        engine.set_metric(DistanceMetric::Cosine);
+        let cosine_results = engine.semantic_search(&query, 2, 0.0, 50).await.unwrap();
+        assert_eq!(cosine_results[0].segment_id, "segment-0");
+        assert_eq!(cosine_results[0].metric, DistanceMetric::Cosine);
+
+        engine.set_metric(DistanceMetric::Euclidean);
+        let euclidean_results = engine.semantic_search(&query, 2, 0.0, 50).await.unwrap();
+        assert_eq!(euclidean_results[0].segment_id, "segment-1");
+        assert_eq!(euclidean_results[0].metric, DistanceMetric::Euclidean);
+    }
+}
@@ -0,0 +1,57 @@
+//! Lightweight lexicon-based sentiment scoring
+//!
+//! Placeholder until a full ML sentiment pipeline is available: counts hits against a
+//! small positive/negative word lexicon and normalizes by word count. Good enough to
+//! flag obviously tense or positive moments without the cost of a real model.
+
+const POSITIVE_WORDS: &[&str] = &[
+    "great", "good", "happy", "excellent", "love", "wonderful", "fantastic", "amazing",
+    "pleased", "glad", "thank", "thanks", "awesome", "perfect", "nice", "excited",
+];
+
+const NEGATIVE_WORDS: &[&str] = &[
+    "bad", "terrible", "angry", "hate", "awful", "worst", "frustrated", "upset",
+    "disappointed", "sad", "annoyed", "horrible", "problem", "wrong", "fail", "failed",
+];
+
+/// Score `text`'s sentiment in `[-1.0, 1.0]`; positive means more positive language.
+/// Empty or purely punctuation text scores neutral (`0.0`).
+pub fn score_sentiment(text: &str) -> f32 {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let positive_hits = words.iter().filter(|w| POSITIVE_WORDS.contains(&w.as_str())).count();
+    let negative_hits = words.iter().filter(|w| NEGATIVE_WORDS.contains(&w.as_str())).count();
+
+    ((positive_hits as f32 - negative_hits as f32) / words.len() as f32).clamp(-1.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_sentiment_is_positive_for_clearly_positive_sentence() {
+        let score = score_sentiment("This is a wonderful, great, fantastic day!");
+        assert!(score > 0.0, "expected positive score, got {score}");
+    }
+
+    #[test]
+    fn test_score_sentiment_is_negative_for_clearly_negative_sentence() {
+        let score = score_sentiment("This is a terrible, awful, horrible problem.");
+        assert!(score < 0.0, "expected negative score, got {score}");
+    }
+
+    #[test]
+    fn test_score_sentiment_is_neutral_for_factual_sentence() {
+        let score = score_sentiment("The meeting starts at three o'clock.");
+        assert_eq!(score, 0.0);
+    }
+}
@@ -4,6 +4,7 @@
 //! existing transcription data and can be enhanced with ML models later.
 
 use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use chrono::{DateTime, Utc};
 use ndarray::{Array1};
 use serde::{Deserialize, Serialize};
@@ -51,6 +52,19 @@ pub enum MatchMethod {
     Unknown,
 }
 
+/// Portable snapshot of a speaker's identity, suitable for transferring to another
+/// machine's database. Embeds the voice embedding as a base64 string rather than the
+/// raw binary blob so the export round-trips cleanly through JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerExport {
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+    pub confidence_threshold: f32,
+    pub total_conversation_time: f32,
+    pub total_conversations: i64,
+    pub voice_embedding: Option<String>,
+}
+
 /// Speaker identification system
 pub struct SpeakerIdentifier {
     pool: SqlitePool,
@@ -183,6 +197,38 @@ impl SpeakerIdentifier {
         Ok(speaker_id)
     }
 
+    /// Get the speaker with this name, creating it if it doesn't exist yet.
+    ///
+    /// Unlike [`Self::create_speaker`], this is safe to call concurrently with the
+    /// same `name` (e.g. from two transcription daemons): the insert relies on the
+    /// unique index on `speakers.name` (migration 010) and an `ON CONFLICT` clause
+    /// to converge on a single row rather than racing to create duplicates.
+    pub async fn get_or_create_speaker(&mut self, name: &str) -> Result<String> {
+        let now = Utc::now();
+        let candidate_id = Uuid::new_v4().to_string();
+
+        let row = sqlx::query(
+            r#"INSERT INTO speakers
+               (id, name, display_name, confidence_threshold,
+                total_conversation_time, total_conversations, created_at, updated_at)
+               VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+               ON CONFLICT(name) WHERE name IS NOT NULL DO UPDATE SET name = excluded.name
+               RETURNING id"#
+        )
+        .bind(&candidate_id)
+        .bind(name)
+        .bind(name) // Use name as display_name initially
+        .bind(self.confidence_threshold)
+        .bind(0.0) // initial conversation time
+        .bind(0i64) // initial conversation count
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.get("id"))
+    }
+
     /// Update speaker embedding with new sample
     pub async fn update_speaker_embedding(
         &mut self,
@@ -347,8 +393,153 @@ impl SpeakerIdentifier {
 
         Ok(duplicates)
     }
+
+    /// Export a speaker's full profile (metadata + voice embedding) for transfer to
+    /// another machine's database.
+    pub async fn export_speaker(&self, id: &str) -> Result<SpeakerExport> {
+        let row = sqlx::query(
+            r#"SELECT name, display_name, confidence_threshold,
+                      total_conversation_time, total_conversations, voice_embedding
+               FROM speakers WHERE id = ?"#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| anyhow!("Speaker not found: {}", id))?;
+
+        let voice_embedding: Option<Vec<u8>> = row.get("voice_embedding");
+
+        Ok(SpeakerExport {
+            name: row.get("name"),
+            display_name: row.get("display_name"),
+            confidence_threshold: row.get("confidence_threshold"),
+            total_conversation_time: row.get("total_conversation_time"),
+            total_conversations: row.get("total_conversations"),
+            voice_embedding: voice_embedding.map(|blob| STANDARD.encode(blob)),
+        })
+    }
+
+    /// Import a speaker profile exported from another database via [`export_speaker`].
+    /// If a speaker with the same name already exists, the imported profile is merged
+    /// into it (via [`merge_speakers`]); otherwise a new speaker is created. Returns the
+    /// id of the resulting speaker.
+    pub async fn import_speaker(&mut self, export: SpeakerExport) -> Result<String> {
+        let embedding = export
+            .voice_embedding
+            .as_deref()
+            .map(|encoded| -> Result<Array1<f32>> {
+                let blob = STANDARD.decode(encoded)?;
+                deserialize_embedding(&blob)
+            })
+            .transpose()?;
+
+        // Created unnamed so the insert can't collide with an existing speaker under the
+        // unique index on `speakers.name`; the name is set below once no match is found.
+        let imported_id = self.create_speaker(None, embedding).await?;
+
+        sqlx::query(
+            r#"UPDATE speakers SET display_name = ?, confidence_threshold = ?,
+                      total_conversation_time = ?, total_conversations = ?, updated_at = ?
+               WHERE id = ?"#,
+        )
+        .bind(export.display_name.or_else(|| export.name.clone()))
+        .bind(export.confidence_threshold)
+        .bind(export.total_conversation_time)
+        .bind(export.total_conversations)
+        .bind(Utc::now())
+        .bind(&imported_id)
+        .execute(&self.pool)
+        .await?;
+
+        if let Some(name) = &export.name {
+            let existing = sqlx::query("SELECT id FROM speakers WHERE name = ? AND id != ?")
+                .bind(name)
+                .bind(&imported_id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+            if let Some(row) = existing {
+                let primary_id: String = row.get("id");
+                self.merge_speakers(&primary_id, &imported_id).await?;
+                return Ok(primary_id);
+            }
+
+            sqlx::query("UPDATE speakers SET name = ? WHERE id = ?")
+                .bind(name)
+                .bind(&imported_id)
+                .execute(&self.pool)
+                .await?;
+        }
+
+        Ok(imported_id)
+    }
+
+    /// Re-tune each speaker's confidence threshold from the distribution of intra- vs
+    /// inter-speaker similarities among their recorded `voice_samples`. Speakers with
+    /// fewer than [`MIN_SAMPLES_FOR_TUNING`] samples are left unchanged since there isn't
+    /// enough data to estimate a reliable separation point. Returns the speakers whose
+    /// threshold changed, with their new value.
+    pub async fn tune_thresholds(&mut self) -> Result<Vec<(String, f32)>> {
+        let rows = sqlx::query("SELECT speaker_id, embedding FROM voice_samples")
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut samples_by_speaker: HashMap<String, Vec<Array1<f32>>> = HashMap::new();
+        for row in rows {
+            let speaker_id: String = row.get("speaker_id");
+            let embedding_blob: Vec<u8> = row.get("embedding");
+            let embedding = deserialize_embedding(&embedding_blob)?;
+            samples_by_speaker.entry(speaker_id).or_default().push(embedding);
+        }
+
+        let mut updated = Vec::new();
+
+        for (speaker_id, samples) in &samples_by_speaker {
+            if samples.len() < MIN_SAMPLES_FOR_TUNING {
+                continue;
+            }
+
+            let intra = pairwise_similarities(samples);
+
+            let mut inter: Vec<f32> = Vec::new();
+            for (other_id, other_samples) in &samples_by_speaker {
+                if other_id == speaker_id {
+                    continue;
+                }
+                for a in samples {
+                    for b in other_samples {
+                        inter.push(cosine_similarity(a, b));
+                    }
+                }
+            }
+
+            if intra.is_empty() || inter.is_empty() {
+                continue;
+            }
+
+            let threshold = optimal_separation_threshold(&intra, &inter);
+
+            sqlx::query("UPDATE speakers SET confidence_threshold = ?, updated_at = ? WHERE id = ?")
+                .bind(threshold)
+                .bind(Utc::now())
+                .bind(speaker_id)
+                .execute(&self.pool)
+                .await?;
+
+            if let Some(cached) = self.embedding_cache.get_mut(speaker_id) {
+                cached.confidence = threshold;
+            }
+
+            updated.push((speaker_id.clone(), threshold));
+        }
+
+        Ok(updated)
+    }
 }
 
+/// Minimum voice samples a speaker needs before `tune_thresholds` will adjust them.
+const MIN_SAMPLES_FOR_TUNING: usize = 3;
+
 /// Calculate cosine similarity between two vectors
 fn cosine_similarity(a: &Array1<f32>, b: &Array1<f32>) -> f32 {
     let dot_product = a.dot(b);
@@ -362,6 +553,37 @@ fn cosine_similarity(a: &Array1<f32>, b: &Array1<f32>) -> f32 {
     }
 }
 
+/// All pairwise cosine similarities among a single speaker's voice samples
+fn pairwise_similarities(samples: &[Array1<f32>]) -> Vec<f32> {
+    let mut similarities = Vec::new();
+    for i in 0..samples.len() {
+        for j in (i + 1)..samples.len() {
+            similarities.push(cosine_similarity(&samples[i], &samples[j]));
+        }
+    }
+    similarities
+}
+
+/// Pick the confidence threshold that best separates a speaker's intra-speaker
+/// similarities (expected to be high) from their inter-speaker similarities (expected to
+/// be low). When the two distributions don't overlap, the midpoint between the weakest
+/// intra-speaker match and the strongest inter-speaker match cleanly separates them;
+/// otherwise falls back to the midpoint of the two distributions' means.
+fn optimal_separation_threshold(intra: &[f32], inter: &[f32]) -> f32 {
+    let min_intra = intra.iter().cloned().fold(f32::INFINITY, f32::min);
+    let max_inter = inter.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+
+    let threshold = if min_intra > max_inter {
+        (min_intra + max_inter) / 2.0
+    } else {
+        let mean_intra = intra.iter().sum::<f32>() / intra.len() as f32;
+        let mean_inter = inter.iter().sum::<f32>() / inter.len() as f32;
+        (mean_intra + mean_inter) / 2.0
+    };
+
+    threshold.clamp(0.0, 1.0)
+}
+
 /// Serialize embedding vector to binary format
 fn serialize_embedding(embedding: &Array1<f32>) -> Vec<u8> {
     embedding
@@ -387,6 +609,7 @@ fn deserialize_embedding(blob: &[u8]) -> Result<Array1<f32>> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn test_cosine_similarity() {
@@ -408,4 +631,228 @@ mod tests {
             assert!((orig - deser).abs() < 1e-6);
         }
     }
+
+    async fn in_memory_identifier() -> SpeakerIdentifier {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query(
+            r#"CREATE TABLE speakers (
+                id TEXT PRIMARY KEY,
+                name TEXT,
+                display_name TEXT,
+                voice_embedding BLOB,
+                confidence_threshold REAL NOT NULL,
+                total_conversation_time REAL NOT NULL,
+                total_conversations INTEGER NOT NULL,
+                last_interaction DATETIME,
+                created_at DATETIME NOT NULL,
+                updated_at DATETIME NOT NULL
+            )"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            r#"CREATE TABLE speaker_aliases (
+                id TEXT PRIMARY KEY,
+                primary_speaker_id TEXT NOT NULL,
+                alias_name TEXT NOT NULL,
+                merge_confidence REAL NOT NULL,
+                source TEXT NOT NULL
+            )"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("CREATE TABLE segments (id TEXT PRIMARY KEY, speaker TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            r#"CREATE TABLE voice_samples (
+                id TEXT PRIMARY KEY,
+                speaker_id TEXT NOT NULL,
+                audio_path TEXT NOT NULL,
+                embedding BLOB NOT NULL,
+                duration REAL NOT NULL,
+                quality_score REAL,
+                transcription TEXT,
+                created_at DATETIME NOT NULL
+            )"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        SpeakerIdentifier::new(pool)
+    }
+
+    async fn insert_voice_sample(db: &SpeakerIdentifier, speaker_id: &str, embedding: &Array1<f32>) {
+        sqlx::query(
+            r#"INSERT INTO voice_samples (id, speaker_id, audio_path, embedding, duration, created_at)
+               VALUES (?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(speaker_id)
+        .bind("/tmp/sample.wav")
+        .bind(serialize_embedding(embedding))
+        .bind(1.0_f32)
+        .bind(Utc::now())
+        .execute(&db.pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_export_import_speaker_round_trips_across_databases() {
+        let mut source = in_memory_identifier().await;
+        let embedding = Array1::from_vec(vec![0.1_f32; 512]);
+        let speaker_id = source
+            .create_speaker(Some("Alice".to_string()), Some(embedding.clone()))
+            .await
+            .unwrap();
+
+        let export = source.export_speaker(&speaker_id).await.unwrap();
+        assert!(export.voice_embedding.is_some());
+        assert_eq!(export.name.as_deref(), Some("Alice"));
+
+        let mut target = in_memory_identifier().await;
+        let imported_id = target.import_speaker(export).await.unwrap();
+
+        let speakers = target.list_speakers().await.unwrap();
+        let imported = speakers.iter().find(|s| s.id == imported_id).unwrap();
+        assert_eq!(imported.name.as_deref(), Some("Alice"));
+
+        let imported_embedding = target.get_speaker_embedding(&imported_id).await.unwrap().unwrap();
+        for (orig, roundtripped) in embedding.iter().zip(imported_embedding.iter()) {
+            assert!((orig - roundtripped).abs() < 1e-6);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_speaker_with_existing_name_merges_instead_of_duplicating() {
+        let mut db = in_memory_identifier().await;
+        let existing_id = db.create_speaker(Some("Bob".to_string()), None).await.unwrap();
+
+        let export = SpeakerExport {
+            name: Some("Bob".to_string()),
+            display_name: Some("Bob".to_string()),
+            confidence_threshold: 0.75,
+            total_conversation_time: 42.0,
+            total_conversations: 3,
+            voice_embedding: None,
+        };
+
+        let result_id = db.import_speaker(export).await.unwrap();
+        assert_eq!(result_id, existing_id);
+
+        let speakers = db.list_speakers().await.unwrap();
+        assert_eq!(speakers.len(), 1);
+        assert_eq!(speakers[0].total_conversations, 3);
+    }
+
+    #[tokio::test]
+    async fn test_tune_thresholds_separates_intra_and_inter_speaker_clusters() {
+        let mut db = in_memory_identifier().await;
+        let alice = db.create_speaker(Some("Alice".to_string()), None).await.unwrap();
+        let bob = db.create_speaker(Some("Bob".to_string()), None).await.unwrap();
+
+        // Alice's samples cluster tightly around [1, 0, 0, 0]
+        for v in [
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.95, 0.05, 0.0, 0.0],
+            vec![0.97, 0.0, 0.03, 0.0],
+        ] {
+            insert_voice_sample(&db, &alice, &Array1::from_vec(v)).await;
+        }
+
+        // Bob's samples cluster tightly around [0, 1, 0, 0], far from Alice's
+        for v in [
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![0.05, 0.95, 0.0, 0.0],
+            vec![0.0, 0.97, 0.03, 0.0],
+        ] {
+            insert_voice_sample(&db, &bob, &Array1::from_vec(v)).await;
+        }
+
+        let tuned = db.tune_thresholds().await.unwrap();
+        assert_eq!(tuned.len(), 2);
+
+        let alice_threshold = tuned.iter().find(|(id, _)| id == &alice).unwrap().1;
+        assert!(
+            (0.3..0.99).contains(&alice_threshold),
+            "expected a threshold separating the two clusters, got {alice_threshold}"
+        );
+
+        let speakers = db.list_speakers().await.unwrap();
+        let stored = speakers.iter().find(|s| s.id == alice).unwrap();
+        assert!((stored.confidence_threshold - alice_threshold).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_speaker_converges_on_one_row_under_concurrency() {
+        // Concurrent inserts need genuinely concurrent connections, so unlike
+        // `in_memory_identifier` (a single `:memory:` connection reused sequentially)
+        // this uses a file-backed pool, matching how `natural_query`'s tests do it.
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("speakers.db");
+        let pool = SqlitePool::connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+            .await
+            .unwrap();
+        sqlx::query(
+            r#"CREATE TABLE speakers (
+                id TEXT PRIMARY KEY,
+                name TEXT,
+                display_name TEXT,
+                voice_embedding BLOB,
+                confidence_threshold REAL NOT NULL,
+                total_conversation_time REAL NOT NULL,
+                total_conversations INTEGER NOT NULL,
+                last_interaction DATETIME,
+                created_at DATETIME NOT NULL,
+                updated_at DATETIME NOT NULL
+            )"#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("CREATE UNIQUE INDEX idx_speakers_name_unique ON speakers (name) WHERE name IS NOT NULL")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut tasks = Vec::new();
+        for _ in 0..8 {
+            let pool = pool.clone();
+            tasks.push(tokio::spawn(async move {
+                let mut identifier = SpeakerIdentifier::new(pool);
+                identifier.get_or_create_speaker("Concurrent Caller").await.unwrap()
+            }));
+        }
+
+        let mut speaker_ids = HashSet::new();
+        for task in tasks {
+            speaker_ids.insert(task.await.unwrap());
+        }
+
+        assert_eq!(speaker_ids.len(), 1, "all callers should converge on one speaker row");
+
+        let count: i64 = sqlx::query("SELECT COUNT(*) as c FROM speakers WHERE name = 'Concurrent Caller'")
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+            .get("c");
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_tune_thresholds_leaves_speakers_with_too_few_samples_unchanged() {
+        let mut db = in_memory_identifier().await;
+        let speaker_id = db.create_speaker(Some("Solo".to_string()), None).await.unwrap();
+        insert_voice_sample(&db, &speaker_id, &Array1::from_vec(vec![1.0, 0.0])).await;
+
+        let tuned = db.tune_thresholds().await.unwrap();
+        assert!(tuned.is_empty());
+
+        let speakers = db.list_speakers().await.unwrap();
+        assert_eq!(speakers[0].confidence_threshold, 0.75);
+    }
 }
\ No newline at end of file
@@ -111,6 +111,12 @@ pub struct ActivitySummary {
     pub primary_applications: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CleanupStats {
+    pub frames_deleted: i64,
+    pub bytes_reclaimed: i64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CodeAnalysis {
     pub programming_language: String,
@@ -293,6 +299,17 @@ impl VisualDataManager {
         Ok(())
     }
 
+    /// Look up a stored frame by its SHA256 `frame_hash`, returning its session id
+    /// if a byte-identical frame has already been stored.
+    pub async fn find_frame_by_hash(&self, hash: &str) -> Result<Option<String>> {
+        let row = sqlx::query("SELECT session_id FROM hf_video_frames WHERE frame_hash = ?")
+            .bind(hash)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|row| row.get("session_id")))
+    }
+
     /// Store high-frequency frame data
     pub async fn store_hf_frame(&self, frame: &HighFrequencyFrame) -> Result<()> {
         sqlx::query(
@@ -963,6 +980,129 @@ impl VisualDataManager {
         Ok(frames)
     }
 
+    /// Get all frames recorded for a session, oldest first.
+    pub async fn get_frames_by_session(&self, session_id: &str) -> Result<Vec<serde_json::Value>> {
+        let rows = sqlx::query(
+            r#"SELECT * FROM hf_video_frames
+               WHERE session_id = ?
+               ORDER BY timestamp_ms ASC"#
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut frames = Vec::new();
+        for row in rows {
+            frames.push(serde_json::json!({
+                "frame_hash": row.get::<String, _>("frame_hash"),
+                "session_id": row.get::<String, _>("session_id"),
+                "timestamp_ms": row.get::<i64, _>("timestamp_ms"),
+                "change_score": row.get::<f64, _>("change_score"),
+                "file_path": row.get::<Option<String>, _>("file_path"),
+                "screen_resolution": row.get::<Option<String>, _>("screen_resolution"),
+                "active_app": row.get::<Option<String>, _>("active_app"),
+                "processing_flags": row.get::<i32, _>("processing_flags")
+            }));
+        }
+
+        Ok(frames)
+    }
+
+    /// Get the extracted text for a single frame, in detection order.
+    pub async fn get_text_extractions_for_frame(&self, frame_hash: &str) -> Result<Vec<TextExtraction>> {
+        let rows = sqlx::query(
+            r#"SELECT * FROM hf_text_extractions
+               WHERE frame_id = ?
+               ORDER BY paragraph_id, line_id"#
+        )
+        .bind(frame_hash)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut extractions = Vec::new();
+        for row in rows {
+            extractions.push(TextExtraction {
+                frame_id: row.get("frame_id"),
+                word_text: row.get("word_text"),
+                confidence: row.get("confidence"),
+                bbox_x: row.get("bbox_x"),
+                bbox_y: row.get("bbox_y"),
+                bbox_width: row.get("bbox_width"),
+                bbox_height: row.get("bbox_height"),
+                font_size_estimate: row.get("font_size_estimate"),
+                text_type: row.get("text_type"),
+                line_id: row.get("line_id"),
+                paragraph_id: row.get("paragraph_id"),
+            });
+        }
+
+        Ok(extractions)
+    }
+
+    /// Delete high-frequency frames older than `cutoff_timestamp_ms`, along with
+    /// their text extractions, detected tasks, and on-disk image files.
+    ///
+    /// When `dry_run` is true, nothing is deleted; the returned stats describe
+    /// what a real run would remove.
+    pub async fn cleanup_old_frames(&self, cutoff_timestamp_ms: i64, dry_run: bool) -> Result<CleanupStats> {
+        let rows = sqlx::query(
+            "SELECT frame_hash, file_path FROM hf_video_frames WHERE timestamp_ms < ?"
+        )
+        .bind(cutoff_timestamp_ms)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let frame_hashes: Vec<String> = rows.iter().map(|row| row.get("frame_hash")).collect();
+        let file_paths: Vec<String> = rows
+            .iter()
+            .filter_map(|row| row.get::<Option<String>, _>("file_path"))
+            .collect();
+
+        let mut bytes_reclaimed = 0i64;
+        for file_path in &file_paths {
+            if let Ok(metadata) = tokio::fs::metadata(file_path).await {
+                bytes_reclaimed += metadata.len() as i64;
+            }
+        }
+
+        if dry_run {
+            return Ok(CleanupStats {
+                frames_deleted: frame_hashes.len() as i64,
+                bytes_reclaimed,
+            });
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM hf_text_extractions WHERE frame_id IN (SELECT frame_hash FROM hf_video_frames WHERE timestamp_ms < ?)")
+            .bind(cutoff_timestamp_ms)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM hf_detected_tasks WHERE frame_id IN (SELECT frame_hash FROM hf_video_frames WHERE timestamp_ms < ?)")
+            .bind(cutoff_timestamp_ms)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM hf_video_frames WHERE timestamp_ms < ?")
+            .bind(cutoff_timestamp_ms)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        for file_path in &file_paths {
+            if let Err(e) = tokio::fs::remove_file(file_path).await {
+                log::warn!("Failed to remove frame file {}: {}", file_path, e);
+            }
+        }
+
+        Ok(CleanupStats {
+            frames_deleted: frame_hashes.len() as i64,
+            bytes_reclaimed,
+        })
+    }
+
     /// Get interaction opportunities
     pub async fn get_opportunities(
         &self,
@@ -611,6 +611,109 @@ async fn test_complex_query_scenarios() {
     // assert!(problem_to_code_correlation.code.unwrap().contains("twoSum"));
 }
 
+#[tokio::test]
+async fn test_find_frame_by_hash_detects_duplicate() {
+    let (manager, _temp_dir) = setup_test_db().await.unwrap();
+
+    let frame = HighFrequencyFrame {
+        timestamp_ms: Utc::now().timestamp_millis(),
+        session_id: "dedup-session".to_string(),
+        frame_hash: "deadbeef".to_string(),
+        change_score: 0.0,
+        file_path: Some("/tmp/dedup_frame.png".to_string()),
+        screen_resolution: Some("1920x1080".to_string()),
+        active_app: Some("TestApp".to_string()),
+        processing_flags: 0,
+    };
+
+    assert!(manager.find_frame_by_hash(&frame.frame_hash).await.unwrap().is_none());
+
+    manager.store_hf_frame(&frame).await.unwrap();
+
+    let found = manager.find_frame_by_hash(&frame.frame_hash).await.unwrap();
+    assert_eq!(found, Some("dedup-session".to_string()));
+}
+
+#[tokio::test]
+async fn test_cleanup_old_frames_removes_only_stale_frames_and_files() {
+    let (manager, _temp_dir) = setup_test_db().await.unwrap();
+
+    let now = Utc::now().timestamp_millis();
+    let old_timestamp = now - chrono::Duration::days(40).num_milliseconds();
+
+    let old_file = _temp_dir.path().join("old_frame.png");
+    let recent_file = _temp_dir.path().join("recent_frame.png");
+    tokio::fs::write(&old_file, b"old").await.unwrap();
+    tokio::fs::write(&recent_file, b"recent").await.unwrap();
+
+    let old_frame = HighFrequencyFrame {
+        timestamp_ms: old_timestamp,
+        session_id: "cleanup-session".to_string(),
+        frame_hash: "old-frame".to_string(),
+        change_score: 0.5,
+        file_path: Some(old_file.to_string_lossy().to_string()),
+        screen_resolution: Some("1920x1080".to_string()),
+        active_app: Some("TestApp".to_string()),
+        processing_flags: 0,
+    };
+    manager.store_hf_frame(&old_frame).await.unwrap();
+    manager.store_text_extraction(&TextExtraction {
+        frame_id: "old-frame".to_string(),
+        word_text: "stale".to_string(),
+        confidence: 0.9,
+        bbox_x: 0,
+        bbox_y: 0,
+        bbox_width: 10,
+        bbox_height: 10,
+        font_size_estimate: None,
+        text_type: None,
+        line_id: 0,
+        paragraph_id: 0,
+    }).await.unwrap();
+    manager.store_detected_task(&savant_db::visual_data::DetectedTask {
+        frame_id: "old-frame".to_string(),
+        task_type: "CodingProblem".to_string(),
+        confidence: 0.9,
+        description: "stale task".to_string(),
+        evidence_text: "{}".to_string(),
+        bounding_regions: None,
+        assistance_suggestions: "[]".to_string(),
+    }).await.unwrap();
+
+    let recent_frame = HighFrequencyFrame {
+        timestamp_ms: now,
+        session_id: "cleanup-session".to_string(),
+        frame_hash: "recent-frame".to_string(),
+        change_score: 0.5,
+        file_path: Some(recent_file.to_string_lossy().to_string()),
+        screen_resolution: Some("1920x1080".to_string()),
+        active_app: Some("TestApp".to_string()),
+        processing_flags: 0,
+    };
+    manager.store_hf_frame(&recent_frame).await.unwrap();
+
+    let cutoff = now - chrono::Duration::days(30).num_milliseconds();
+
+    // Dry run must not delete anything.
+    let dry_run_stats = manager.cleanup_old_frames(cutoff, true).await.unwrap();
+    assert_eq!(dry_run_stats.frames_deleted, 1);
+    assert_eq!(dry_run_stats.bytes_reclaimed, 3); // "old".len()
+    assert!(old_file.exists());
+
+    let stats = manager.cleanup_old_frames(cutoff, false).await.unwrap();
+    assert_eq!(stats.frames_deleted, 1);
+    assert_eq!(stats.bytes_reclaimed, 3);
+    assert!(!old_file.exists());
+    assert!(recent_file.exists());
+
+    let remaining = manager.get_frames_in_range(old_timestamp - 1000, now + 1000, 10).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0]["frame_hash"], "recent-frame");
+
+    let remaining_tasks = manager.get_recent_tasks(old_timestamp - 1000, now + 1000, 10).await.unwrap();
+    assert!(remaining_tasks.is_empty());
+}
+
 #[tokio::test]
 async fn test_performance_with_large_dataset() {
     let (manager, _temp_dir) = setup_test_db().await.unwrap();
@@ -674,3 +777,67 @@ async fn test_performance_with_large_dataset() {
     assert_eq!(results.len(), 100);
     assert!(query_time.as_millis() < 100); // Should be very fast with indexes
 }
+
+#[tokio::test]
+async fn test_get_frames_by_session_returns_only_that_sessions_frames_oldest_first() {
+    let (manager, _temp_dir) = setup_test_db().await.unwrap();
+
+    let base_time = Utc::now().timestamp_millis();
+    for (i, session_id) in ["session-a", "session-b", "session-a"].iter().enumerate() {
+        let frame = HighFrequencyFrame {
+            timestamp_ms: base_time + (i as i64 * 1000),
+            session_id: session_id.to_string(),
+            frame_hash: format!("frame-{}", i),
+            change_score: 0.5,
+            file_path: None,
+            screen_resolution: None,
+            active_app: None,
+            processing_flags: 0,
+        };
+        manager.store_hf_frame(&frame).await.unwrap();
+    }
+
+    let frames = manager.get_frames_by_session("session-a").await.unwrap();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0]["frame_hash"], "frame-0");
+    assert_eq!(frames[1]["frame_hash"], "frame-2");
+}
+
+#[tokio::test]
+async fn test_get_text_extractions_for_frame_is_scoped_to_that_frame() {
+    let (manager, _temp_dir) = setup_test_db().await.unwrap();
+
+    let base_time = Utc::now().timestamp_millis();
+    for frame_hash in ["frame-a", "frame-b"] {
+        let frame = HighFrequencyFrame {
+            timestamp_ms: base_time,
+            session_id: "test-session".to_string(),
+            frame_hash: frame_hash.to_string(),
+            change_score: 0.5,
+            file_path: None,
+            screen_resolution: None,
+            active_app: None,
+            processing_flags: 0,
+        };
+        manager.store_hf_frame(&frame).await.unwrap();
+
+        let extraction = TextExtraction {
+            frame_id: frame_hash.to_string(),
+            word_text: format!("text for {}", frame_hash),
+            confidence: 0.9,
+            bbox_x: 0,
+            bbox_y: 0,
+            bbox_width: 10,
+            bbox_height: 10,
+            font_size_estimate: None,
+            text_type: None,
+            line_id: 0,
+            paragraph_id: 0,
+        };
+        manager.store_text_extraction(&extraction).await.unwrap();
+    }
+
+    let extractions = manager.get_text_extractions_for_frame("frame-a").await.unwrap();
+    assert_eq!(extractions.len(), 1);
+    assert_eq!(extractions[0].word_text, "text for frame-a");
+}
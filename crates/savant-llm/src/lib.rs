@@ -1,10 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use savant_core::LlmRequest as CoreLlmRequest;
+use savant_core::{LlmProvider as CoreLlmProvider, LlmRequest as CoreLlmRequest};
 
 // Re-export the mock module
 pub mod mock;
+pub mod ollama;
+pub mod retry;
 
 /// LLM request with all parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,18 +18,52 @@ pub struct LLMRequest {
     pub max_tokens: Option<u32>,
 }
 
+/// Name of a core provider variant, e.g. `"ollama"`
+pub fn provider_name(provider: &CoreLlmProvider) -> &'static str {
+    match provider {
+        CoreLlmProvider::Ollama { .. } => "ollama",
+        CoreLlmProvider::OpenAI { .. } => "openai",
+        CoreLlmProvider::DeepSeek { .. } => "deepseek",
+        CoreLlmProvider::Anthropic { .. } => "anthropic",
+    }
+}
+
 impl From<CoreLlmRequest> for LLMRequest {
     fn from(req: CoreLlmRequest) -> Self {
         Self {
             prompt: req.prompt,
             model: req.model,
-            provider: None, // This would need to be extracted from req.provider
+            provider: Some(provider_name(&req.provider).to_string()),
             temperature: Some(req.options.temperature),
             max_tokens: Some(req.options.max_tokens),
         }
     }
 }
 
+impl From<LLMRequest> for CoreLlmRequest {
+    fn from(req: LLMRequest) -> Self {
+        let provider = match req.provider.as_deref() {
+            Some("openai") => CoreLlmProvider::OpenAI { api_key: String::new() },
+            Some("deepseek") => CoreLlmProvider::DeepSeek { api_key: String::new() },
+            Some("anthropic") => CoreLlmProvider::Anthropic { api_key: String::new() },
+            // Default to Ollama, since it's the only provider that needs no credentials
+            _ => CoreLlmProvider::Ollama { url: "http://localhost:11434".to_string() },
+        };
+
+        Self {
+            prompt: req.prompt,
+            model: req.model,
+            provider,
+            options: savant_core::LlmOptions {
+                temperature: req.temperature.unwrap_or(0.7),
+                max_tokens: req.max_tokens.unwrap_or(4096),
+                stream: false,
+            },
+            context: None,
+        }
+    }
+}
+
 /// LLM response with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LLMResponse {
@@ -153,14 +189,33 @@ mod tests {
         };
 
         let llm_request: LLMRequest = core_request.into();
-        
+
         assert_eq!(llm_request.prompt, "Convert me");
         assert_eq!(llm_request.model, "test-model");
-        assert_eq!(llm_request.provider, None); // Currently not extracted from enum
+        assert_eq!(llm_request.provider, Some("ollama".to_string()));
         assert_eq!(llm_request.temperature, Some(0.9));
         assert_eq!(llm_request.max_tokens, Some(2000));
     }
 
+    #[test]
+    fn test_llm_request_into_core_llm_request() {
+        let request = LLMRequest {
+            prompt: "Convert me back".to_string(),
+            model: "test-model".to_string(),
+            provider: Some("anthropic".to_string()),
+            temperature: Some(0.5),
+            max_tokens: Some(1000),
+        };
+
+        let core_request: CoreLlmRequest = request.into();
+
+        assert_eq!(core_request.prompt, "Convert me back");
+        assert_eq!(core_request.model, "test-model");
+        assert!(matches!(core_request.provider, LlmProvider::Anthropic { .. }));
+        assert_eq!(core_request.options.temperature, 0.5);
+        assert_eq!(core_request.options.max_tokens, 1000);
+    }
+
     #[test]
     fn test_llm_request_with_none_values() {
         let request = LLMRequest {
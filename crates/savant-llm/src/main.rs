@@ -125,18 +125,18 @@ async fn query_llm(prompt: &str, cli: &Cli) -> anyhow::Result<()> {
 async fn process_llm_request(request: LlmRequest) -> anyhow::Result<()> {
     let start_time = std::time::Instant::now();
     
-    let response = match &request.provider {
+    let (response, tokens_used) = match &request.provider {
         LlmProvider::Ollama { url } => {
             query_ollama(&request, url).await?
         }
         LlmProvider::OpenAI { api_key } => {
-            query_openai(&request, api_key).await?
+            (query_openai(&request, api_key).await?, None)
         }
         LlmProvider::DeepSeek { api_key } => {
-            query_deepseek(&request, api_key).await?
+            (query_deepseek(&request, api_key).await?, None)
         }
         LlmProvider::Anthropic { api_key } => {
-            query_anthropic(&request, api_key).await?
+            (query_anthropic(&request, api_key).await?, None)
         }
     };
 
@@ -146,7 +146,7 @@ async fn process_llm_request(request: LlmRequest) -> anyhow::Result<()> {
         content: response,
         model: request.model,
         provider: provider_name(&request.provider),
-        tokens_used: None, // TODO: Extract from provider response
+        tokens_used,
         processing_time_ms: processing_time,
         finished: true,
     };
@@ -245,9 +245,9 @@ fn provider_name(provider: &LlmProvider) -> String {
 }
 
 // Provider-specific implementations
-async fn query_ollama(request: &LlmRequest, url: &str) -> anyhow::Result<String> {
+async fn query_ollama(request: &LlmRequest, url: &str) -> anyhow::Result<(String, Option<u32>)> {
     let client = reqwest::Client::new();
-    
+
     let payload = serde_json::json!({
         "model": request.model,
         "prompt": request.prompt,
@@ -273,7 +273,20 @@ async fn query_ollama(request: &LlmRequest, url: &str) -> anyhow::Result<String>
         .as_str()
         .ok_or_else(|| anyhow::anyhow!("Invalid response format from Ollama"))?;
 
-    Ok(content.to_string())
+    let tokens_used = ollama_token_count(&result);
+
+    Ok((content.to_string(), tokens_used))
+}
+
+/// Sum Ollama's `prompt_eval_count` and `eval_count` into a single token total
+fn ollama_token_count(result: &serde_json::Value) -> Option<u32> {
+    let prompt_tokens = result["prompt_eval_count"].as_u64();
+    let eval_tokens = result["eval_count"].as_u64();
+
+    match (prompt_tokens, eval_tokens) {
+        (None, None) => None,
+        (a, b) => Some((a.unwrap_or(0) + b.unwrap_or(0)) as u32),
+    }
 }
 
 async fn query_openai(_request: &LlmRequest, _api_key: &str) -> anyhow::Result<String> {
@@ -320,4 +333,27 @@ async fn test_ollama_connection(url: &str) -> bool {
         Ok(response) => response.status().is_success(),
         Err(_) => false,
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ollama_token_count_sums_prompt_and_eval() {
+        let response = serde_json::json!({
+            "response": "Hello!",
+            "done": true,
+            "prompt_eval_count": 12,
+            "eval_count": 34
+        });
+
+        assert_eq!(ollama_token_count(&response), Some(46));
+    }
+
+    #[test]
+    fn test_ollama_token_count_missing_fields() {
+        let response = serde_json::json!({"response": "Hello!", "done": true});
+        assert_eq!(ollama_token_count(&response), None);
+    }
 }
\ No newline at end of file
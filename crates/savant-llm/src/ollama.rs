@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::Deserialize;
+
+use crate::retry::{retry_with_backoff, RetryConfig};
+use crate::{LLMProvider as LLMProviderTrait, LLMRequest, LLMResponse};
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// One line of Ollama's NDJSON `/api/generate` stream
+#[derive(Debug, Deserialize)]
+struct OllamaChunk {
+    response: String,
+    done: bool,
+    #[serde(default)]
+    done_reason: Option<String>,
+    #[serde(default)]
+    eval_count: Option<u32>,
+}
+
+/// LLMProvider backed by a local Ollama server
+#[derive(Debug, Clone)]
+pub struct OllamaProvider {
+    url: String,
+    client: reqwest::Client,
+    retry_config: RetryConfig,
+}
+
+impl OllamaProvider {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_timeout(url, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    pub fn with_timeout(url: impl Into<String>, timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("Failed to build Ollama HTTP client");
+
+        Self {
+            url: url.into(),
+            client,
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    fn payload(&self, request: &LLMRequest, stream: bool) -> serde_json::Value {
+        serde_json::json!({
+            "model": request.model,
+            "prompt": request.prompt,
+            "stream": stream,
+            "options": {
+                "temperature": request.temperature.unwrap_or(0.7),
+                "num_predict": request.max_tokens.unwrap_or(4096),
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl LLMProviderTrait for OllamaProvider {
+    async fn complete(&self, request: LLMRequest) -> Result<LLMResponse> {
+        let payload = self.payload(&request, false);
+        let url = format!("{}/api/generate", self.url);
+
+        let response = retry_with_backoff(&self.retry_config, || {
+            self.client.post(&url).json(&payload).send()
+        })
+        .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("Ollama API error: {}", response.status()));
+        }
+
+        let chunk: OllamaChunk = response.json().await?;
+
+        Ok(LLMResponse {
+            model: request.model,
+            content: chunk.response,
+            tokens_used: chunk.eval_count,
+            finish_reason: chunk.done_reason.or(Some("stop".to_string())),
+        })
+    }
+
+    async fn complete_streaming(
+        &self,
+        request: LLMRequest,
+        callback: Box<dyn Fn(String) + Send>,
+    ) -> Result<LLMResponse> {
+        let payload = self.payload(&request, true);
+        let url = format!("{}/api/generate", self.url);
+
+        // Only the initial connection is retried - once chunks start flowing the
+        // callback has already fired, so a mid-stream retry would duplicate output.
+        let response = retry_with_backoff(&self.retry_config, || {
+            self.client.post(&url).json(&payload).send()
+        })
+        .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("Ollama API error: {}", response.status()));
+        }
+
+        let mut content = String::new();
+        let mut tokens_used = None;
+        let mut finish_reason = Some("stop".to_string());
+        let mut buf = String::new();
+        let mut bytes = response.bytes_stream();
+
+        while let Some(next) = bytes.next().await {
+            let bytes = next?;
+            buf.push_str(&String::from_utf8_lossy(&bytes));
+
+            while let Some(pos) = buf.find('\n') {
+                let line = buf[..pos].trim().to_string();
+                buf.drain(..=pos);
+
+                if line.is_empty() {
+                    continue;
+                }
+
+                let chunk: OllamaChunk = serde_json::from_str(&line)?;
+                if !chunk.response.is_empty() {
+                    content.push_str(&chunk.response);
+                    callback(chunk.response);
+                }
+
+                if chunk.done {
+                    tokens_used = chunk.eval_count;
+                    finish_reason = chunk.done_reason.or(finish_reason);
+                }
+            }
+        }
+
+        Ok(LLMResponse {
+            model: request.model,
+            content,
+            tokens_used,
+            finish_reason,
+        })
+    }
+
+    async fn list_models(&self) -> Result<Vec<String>> {
+        let url = format!("{}/api/tags", self.url);
+        let response = retry_with_backoff(&self.retry_config, || self.client.get(&url).send()).await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("Failed to get models from Ollama"));
+        }
+
+        let result: serde_json::Value = response.json().await?;
+        let models = result["models"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("Invalid models response format"))?
+            .iter()
+            .filter_map(|m| m["name"].as_str())
+            .map(|s| s.to_string())
+            .collect();
+
+        Ok(models)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_complete_streaming_invokes_callback_per_chunk() {
+        let server = MockServer::start().await;
+
+        let body = [
+            serde_json::json!({"response": "Hel", "done": false}),
+            serde_json::json!({"response": "lo", "done": false}),
+            serde_json::json!({"response": "!", "done": true, "done_reason": "stop", "eval_count": 42}),
+        ]
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+            + "\n";
+
+        Mock::given(method("POST"))
+            .and(path("/api/generate"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/x-ndjson"))
+            .mount(&server)
+            .await;
+
+        let provider = OllamaProvider::new(server.uri());
+        let request = LLMRequest {
+            prompt: "hi".to_string(),
+            model: "llama3.2".to_string(),
+            provider: None,
+            temperature: None,
+            max_tokens: None,
+        };
+
+        let chunks = Arc::new(Mutex::new(Vec::new()));
+        let chunks_clone = chunks.clone();
+        let callback = Box::new(move |chunk: String| {
+            chunks_clone.lock().unwrap().push(chunk);
+        });
+
+        let response = provider
+            .complete_streaming(request, callback)
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.lock().unwrap().len(), 3);
+        assert_eq!(response.content, "Hello!");
+        assert_eq!(response.tokens_used, Some(42));
+        assert_eq!(response.finish_reason, Some("stop".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_complete_retries_then_succeeds() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/generate"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/generate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"response": "ok", "done": true, "done_reason": "stop"}),
+            ))
+            .mount(&server)
+            .await;
+
+        let mut provider = OllamaProvider::new(server.uri());
+        provider.retry_config = RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        };
+
+        let request = LLMRequest {
+            prompt: "hi".to_string(),
+            model: "llama3.2".to_string(),
+            provider: None,
+            temperature: None,
+            max_tokens: None,
+        };
+
+        let response = provider.complete(request).await.unwrap();
+        assert_eq!(response.content, "ok");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,167 @@
+use std::future::Future;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+/// Exponential backoff settings for retrying transient HTTP failures
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryConfig {
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1 << attempt.min(16));
+        let delay = exp.min(self.max_delay);
+
+        if self.jitter {
+            let jitter_ms = (delay.as_millis() as u64).saturating_mul(fastrand_fraction()) / 100;
+            delay.saturating_sub(Duration::from_millis(jitter_ms))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Cheap "jitter" percentage (0-49) taken from the clock's sub-second nanoseconds,
+/// without pulling in a RNG crate
+fn fastrand_fraction() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 50) as u64
+}
+
+/// Whether a response status should trigger a retry: 5xx/429 are retried,
+/// 4xx (other than 429) is not. Connection-level errors are always retried by
+/// [`retry_with_backoff`].
+fn should_retry_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Run `attempt` up to `config.max_attempts` times with exponential backoff,
+/// retrying on connection errors and retryable HTTP statuses.
+pub async fn retry_with_backoff<F, Fut>(
+    config: &RetryConfig,
+    mut attempt: F,
+) -> Result<reqwest::Response, reqwest::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<reqwest::Response, reqwest::Error>>,
+{
+    let mut last_err = None;
+
+    for attempt_num in 0..config.max_attempts {
+        match attempt().await {
+            Ok(response) => {
+                if response.status().is_success() || !should_retry_status(response.status()) {
+                    return Ok(response);
+                }
+                last_err = None;
+                if attempt_num + 1 < config.max_attempts {
+                    tokio::time::sleep(config.delay_for(attempt_num)).await;
+                    continue;
+                }
+                return Ok(response);
+            }
+            Err(err) => {
+                last_err = Some(err);
+                if attempt_num + 1 < config.max_attempts {
+                    tokio::time::sleep(config.delay_for(attempt_num)).await;
+                }
+            }
+        }
+    }
+
+    Err(last_err.expect("retry loop exits via Ok before exhausting attempts without an error"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_retries_on_server_error_then_succeeds() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let url = format!("{}/flaky", server.uri());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = attempts.clone();
+
+        let config = RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: false,
+        };
+
+        let response = retry_with_backoff(&config, || {
+            attempts_clone.fetch_add(1, Ordering::SeqCst);
+            client.get(&url).send()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_on_client_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/bad-request"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let client = reqwest::Client::new();
+        let url = format!("{}/bad-request", server.uri());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = attempts.clone();
+
+        let response = retry_with_backoff(&RetryConfig::default(), || {
+            attempts_clone.fetch_add(1, Ordering::SeqCst);
+            client.get(&url).send()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -6,8 +6,10 @@
 pub mod mcp_server;
 pub mod mcp_server_tools;
 pub mod mcp_server_prompts;
+pub mod metrics;
 
-pub use mcp_server::{MCPServer, MCPRequest, MCPResponse, MCPTransport, StdioTransport};
+pub use mcp_server::{MCPServer, MCPRequest, MCPResponse, MCPTransport, StdioTransport, WebSocketTransport};
+pub use metrics::{bind_metrics, serve_metrics, Metrics};
 
 // Re-export commonly needed types
 pub use savant_db::{TranscriptDatabase, LLMConfig};
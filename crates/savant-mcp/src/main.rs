@@ -5,19 +5,52 @@
 
 use anyhow::Result;
 use clap::{Arg, Command};
-use env_logger;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio;
+use tracing::info;
 
 use savant_mcp::{TranscriptDatabase, MCPServer, LLMConfig, StdioTransport};
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Parse command line arguments first
-    let matches = Command::new("savant-mcp")
+/// Initialize the global tracing subscriber, writing all log output to stderr
+/// so it never contaminates the stdout JSON-RPC stream.
+///
+/// `format` is either `"json"` (one JSON object per log line, for log
+/// aggregators and MCP clients) or `"text"` (human-readable, the default).
+fn init_logging(format: &str) {
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+
+    if format == "json" {
+        tracing_subscriber::fmt()
+            .json()
+            .with_env_filter(env_filter)
+            .with_writer(std::io::stderr)
+            .init();
+    } else {
+        tracing_subscriber::fmt()
+            .with_env_filter(env_filter)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+}
+
+/// Build the clap command graph. Kept separate from `main` so the same definition can be
+/// reused both to parse arguments and, for the `completions` subcommand, to generate a
+/// shell completion script from it.
+fn build_cli() -> Command {
+    Command::new("savant-mcp")
         .version("1.0.0")
         .about("Savant AI MCP Server - Database access for LLMs")
+        .subcommand(
+            Command::new("completions")
+                .about("Generate a shell completion script (bash, zsh, fish, or powershell) to stdout")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_parser(clap::value_parser!(savant_core::completions::Shell)),
+                ),
+        )
         .arg(
             Arg::new("database")
                 .short('d')
@@ -53,32 +86,74 @@ async fn main() -> Result<()> {
                 .help("LLM model name")
                 .default_value("llama3.2")
         )
+        .arg(
+            Arg::new("log-format")
+                .long("log-format")
+                .value_name("FORMAT")
+                .help("Log output format (text, json)")
+                .value_parser(["text", "json"])
+                .default_value("text")
+        )
+        .arg(
+            Arg::new("transport")
+                .long("transport")
+                .value_name("TRANSPORT")
+                .help("Transport to serve on (stdio, ws)")
+                .value_parser(["stdio", "ws"])
+                .default_value("stdio")
+        )
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .value_name("PORT")
+                .help("Port to listen on (only used with --transport ws)")
+                .default_value("8765")
+        )
         .arg(
             Arg::new("test")
                 .long("test")
                 .help("Run in test mode with mock data")
                 .action(clap::ArgAction::SetTrue)
         )
-        .get_matches();
-    
+        .arg(
+            Arg::new("metrics-port")
+                .long("metrics-port")
+                .value_name("PORT")
+                .help("Expose a Prometheus-style metrics endpoint on this port (disabled by default)")
+        )
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Parse command line arguments first
+    let matches = build_cli().get_matches();
+
+    if let Some(completions_matches) = matches.subcommand_matches("completions") {
+        let shell = *completions_matches
+            .get_one::<savant_core::completions::Shell>("shell")
+            .expect("required");
+        let mut command = build_cli();
+        let name = command.get_name().to_string();
+        clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+        return Ok(());
+    }
+
     // Set log level
     if let Some(level) = matches.get_one::<String>("log-level") {
         std::env::set_var("RUST_LOG", level);
     }
-    env_logger::init();
-    
+    init_logging(matches.get_one::<String>("log-format").map(|s| s.as_str()).unwrap_or("text"));
+
     // Print startup banner
-    eprintln!("🤖 Savant AI MCP Server v1.0.0");
-    eprintln!("   Model Context Protocol server for conversation database");
-    eprintln!("   Following UNIX philosophy: composable, focused, reliable");
-    eprintln!();
-    
+    info!("Savant AI MCP Server v1.0.0");
+    info!("Model Context Protocol server for conversation database");
+
     // Initialize database
     let db_path = matches.get_one::<String>("database")
         .map(|p| PathBuf::from(p));
-    
+
     let database = Arc::new(TranscriptDatabase::new(db_path).await?);
-    eprintln!("✅ Database initialized successfully");
+    info!("Database initialized successfully");
     
     // Configure LLM client
     let llm_config = LLMConfig {
@@ -95,24 +170,46 @@ async fn main() -> Result<()> {
     
     // Create MCP server with enhanced capabilities
     let mcp_server = MCPServer::new(database, Some(llm_configs)).await?;
-    eprintln!("✅ Enhanced MCP server created with LLM integration");
-    
+    info!("Enhanced MCP server created with LLM integration");
+
     // Check if running in test mode
     if matches.get_flag("test") {
-        eprintln!("🧪 Running in test mode");
+        info!("Running in test mode");
         return run_test_mode(&mcp_server).await;
     }
-    
-    eprintln!("🚀 Starting MCP server (stdio transport)");
-    eprintln!("   Listening for JSON-RPC 2.0 requests on stdin");
-    eprintln!("   Responses will be written to stdout");
-    eprintln!("   Press Ctrl+C to stop");
-    eprintln!();
-    
-    // Start server with stdio transport
-    let transport = StdioTransport::new();
-    mcp_server.start_server(transport).await?;
-    
+
+    if let Some(metrics_port) = matches.get_one::<String>("metrics-port") {
+        let metrics_port: u16 = metrics_port.parse()
+            .map_err(|e| anyhow::anyhow!("Invalid --metrics-port: {}", e))?;
+
+        let listener = savant_mcp::bind_metrics(metrics_port).await?;
+        let metrics = mcp_server.metrics.clone();
+        let pool = mcp_server.database.pool.clone();
+        info!("Metrics endpoint listening on port {}", metrics_port);
+        tokio::spawn(async move {
+            if let Err(e) = savant_mcp::serve_metrics(listener, metrics, pool).await {
+                tracing::error!("Metrics server exited with error: {}", e);
+            }
+        });
+    }
+
+    match matches.get_one::<String>("transport").map(|s| s.as_str()).unwrap_or("stdio") {
+        "ws" => {
+            let port: u16 = matches.get_one::<String>("port")
+                .and_then(|p| p.parse().ok())
+                .unwrap_or(8765);
+
+            info!("Starting MCP server (websocket transport) on port {}", port);
+            let listener = MCPServer::bind_websocket(port).await?;
+            Arc::new(mcp_server).serve_websocket(listener).await?;
+        }
+        _ => {
+            info!("Starting MCP server (stdio transport), listening for JSON-RPC 2.0 requests on stdin");
+            let transport = StdioTransport::new();
+            mcp_server.start_server(transport).await?;
+        }
+    }
+
     Ok(())
 }
 
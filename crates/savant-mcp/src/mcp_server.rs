@@ -8,18 +8,23 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
+use futures::{SinkExt, StreamExt};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::Mutex;
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
 use uuid::Uuid;
 
 use savant_db::{
-    TranscriptDatabase, 
+    TranscriptDatabase,
     LLMClientFactory, LLMConfig,
     QueryProcessor, ConversationContextManager, QueryOptimizer,
     QuerySecurityManager
 };
 use savant_db::natural_query::LLMClientWrapper;
 
+use crate::metrics::Metrics;
+
 /// MCP JSON-RPC 2.0 request
 #[derive(Debug, Deserialize)]
 pub struct MCPRequest {
@@ -98,6 +103,45 @@ pub struct MCPSession {
     pub client_info: Option<Value>,
     pub capabilities: Vec<String>,
     pub query_count: u64,
+    /// When this session was issued, used to evict it once [`SESSION_TTL`]
+    /// has passed -- `sessions` is otherwise never pruned, and `initialize`
+    /// hands out a fresh entry to anyone who asks.
+    pub created_at: std::time::Instant,
+}
+
+/// How long a session issued by `handle_initialize` stays valid.
+const SESSION_TTL: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
+/// Hard cap on concurrently live sessions, enforced in [`prune_sessions`] as a
+/// backstop against unbounded growth if TTL eviction alone isn't enough
+/// (e.g. a burst of short-lived connections all within the TTL window).
+const MAX_SESSIONS: usize = 1000;
+
+/// Peer identity used by [`MCPServer::handle_request`] call sites that aren't
+/// backed by a real connection (the `run_test_mode` smoke test in `main.rs`).
+/// Never reachable from [`MCPServer::start_server`], which always supplies
+/// the transport's real [`MCPTransport::peer_id`].
+const LOCAL_PEER_ID: &str = "local";
+
+/// Drop sessions older than [`SESSION_TTL`], then, if still at or over
+/// [`MAX_SESSIONS`], evict the oldest ones until one below the cap, leaving
+/// room for the session about to be inserted.
+fn prune_sessions(sessions: &mut HashMap<String, MCPSession>) {
+    let now = std::time::Instant::now();
+    sessions.retain(|_, session| now.duration_since(session.created_at) < SESSION_TTL);
+
+    if sessions.len() >= MAX_SESSIONS {
+        let mut by_age: Vec<(String, std::time::Instant)> = sessions
+            .iter()
+            .map(|(id, session)| (id.clone(), session.created_at))
+            .collect();
+        by_age.sort_by_key(|(_, created_at)| *created_at);
+
+        let overflow = sessions.len() - MAX_SESSIONS + 1;
+        for (id, _) in by_age.into_iter().take(overflow) {
+            sessions.remove(&id);
+        }
+    }
 }
 
 /// Transport abstraction for MCP communication
@@ -105,6 +149,14 @@ pub struct MCPSession {
 pub trait MCPTransport: Send + Sync {
     async fn receive(&mut self) -> Result<MCPRequest>;
     async fn send(&mut self, response: MCPResponse) -> Result<()>;
+    /// Send a server-initiated JSON-RPC notification (no `id`, no reply expected),
+    /// interleaved with ordinary responses on the same underlying stream.
+    async fn send_notification(&mut self, notification: Value) -> Result<()>;
+    /// Identity used to rate-limit this connection's `initialize` calls (see
+    /// `MCPServer::handle_initialize`). Unlike a client-supplied session id,
+    /// this isn't something a client can refresh for free -- it's fixed for
+    /// the lifetime of the underlying connection.
+    fn peer_id(&self) -> &str;
 }
 
 /// Stdio transport implementation
@@ -122,6 +174,12 @@ pub struct MCPServer {
     pub query_optimizer: QueryOptimizer,
     pub llm_client: Option<LLMClientWrapper>,
     pub sessions: Arc<Mutex<HashMap<String, MCPSession>>>,
+    /// Resource URIs (e.g. `conversations://<id>`) a client has subscribed to,
+    /// mapped to the segment count last observed for that conversation.
+    pub subscriptions: Arc<Mutex<HashMap<String, i64>>>,
+    /// Request counters, recorded for every handled request and exposed by the
+    /// optional metrics endpoint (see [`crate::metrics`]).
+    pub metrics: Arc<Metrics>,
 }
 
 impl StdioTransport {
@@ -131,6 +189,20 @@ impl StdioTransport {
             writer: tokio::io::stdout(),
         }
     }
+
+    async fn write_line(&mut self, value: &impl Serialize) -> Result<()> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| anyhow!("Failed to serialize message: {}", e))?;
+
+        self.writer.write_all(json.as_bytes()).await
+            .map_err(|e| anyhow!("Failed to write to stdout: {}", e))?;
+        self.writer.write_all(b"\n").await
+            .map_err(|e| anyhow!("Failed to write newline: {}", e))?;
+        self.writer.flush().await
+            .map_err(|e| anyhow!("Failed to flush stdout: {}", e))?;
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -149,17 +221,75 @@ impl MCPTransport for StdioTransport {
     }
 
     async fn send(&mut self, response: MCPResponse) -> Result<()> {
-        let json = serde_json::to_string(&response)
-            .map_err(|e| anyhow!("Failed to serialize response: {}", e))?;
+        self.write_line(&response).await
+    }
 
-        self.writer.write_all(json.as_bytes()).await
-            .map_err(|e| anyhow!("Failed to write to stdout: {}", e))?;
-        self.writer.write_all(b"\n").await
-            .map_err(|e| anyhow!("Failed to write newline: {}", e))?;
-        self.writer.flush().await
-            .map_err(|e| anyhow!("Failed to flush stdout: {}", e))?;
+    async fn send_notification(&mut self, notification: Value) -> Result<()> {
+        self.write_line(&notification).await
+    }
 
-        Ok(())
+    fn peer_id(&self) -> &str {
+        // One stdio transport per local child process -- there's only ever
+        // one peer on the other end, so a fixed id is enough to identify it.
+        "stdio"
+    }
+}
+
+/// WebSocket transport implementation, one instance per connected client.
+///
+/// Unlike [`StdioTransport`] (a single local child process), a socket can have
+/// many clients at once; [`MCPServer::serve_websocket`] accepts connections and
+/// gives each one its own `WebSocketTransport` plus its own `start_server` loop,
+/// so clients never interleave on the wire and each gets its own MCP session id
+/// from `initialize`.
+pub struct WebSocketTransport {
+    stream: WebSocketStream<TcpStream>,
+    peer_id: String,
+}
+
+impl WebSocketTransport {
+    pub fn new(stream: WebSocketStream<TcpStream>, peer_id: String) -> Self {
+        Self { stream, peer_id }
+    }
+
+    async fn write_message(&mut self, value: &impl Serialize) -> Result<()> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| anyhow!("Failed to serialize message: {}", e))?;
+
+        self.stream.send(Message::Text(json)).await
+            .map_err(|e| anyhow!("Failed to send websocket message: {}", e))
+    }
+}
+
+#[async_trait::async_trait]
+impl MCPTransport for WebSocketTransport {
+    async fn receive(&mut self) -> Result<MCPRequest> {
+        loop {
+            let message = self.stream.next().await
+                .ok_or_else(|| anyhow!("WebSocket connection closed"))?
+                .map_err(|e| anyhow!("WebSocket read error: {}", e))?;
+
+            match message {
+                Message::Text(text) => {
+                    return serde_json::from_str(&text)
+                        .map_err(|e| anyhow!("Failed to parse JSON: {}", e));
+                }
+                Message::Close(_) => return Err(anyhow!("WebSocket connection closed")),
+                _ => continue,
+            }
+        }
+    }
+
+    async fn send(&mut self, response: MCPResponse) -> Result<()> {
+        self.write_message(&response).await
+    }
+
+    async fn send_notification(&mut self, notification: Value) -> Result<()> {
+        self.write_message(&notification).await
+    }
+
+    fn peer_id(&self) -> &str {
+        &self.peer_id
     }
 }
 
@@ -177,7 +307,7 @@ impl MCPServer {
             match LLMClientFactory::create_client(&default_config) {
                 Ok(client) => Some(client),
                 Err(_) => {
-                    log::warn!("No LLM client available, using pattern-based fallback");
+                    tracing::warn!("No LLM client available, using pattern-based fallback");
                     None
                 }
             }
@@ -185,7 +315,7 @@ impl MCPServer {
 
         let query_processor = QueryProcessor::new(pool.clone(), llm_client.clone());
         let context_manager = Arc::new(ConversationContextManager::new());
-        let query_optimizer = QueryOptimizer::new(pool);
+        let query_optimizer = QueryOptimizer::new(pool).await?;
         let security = QuerySecurityManager::read_only();
 
         Ok(Self {
@@ -196,15 +326,17 @@ impl MCPServer {
             query_optimizer,
             llm_client,
             sessions: Arc::new(Mutex::new(HashMap::new())),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Metrics::new(),
         })
     }
 
     /// Create server with explicit LLM client
-    pub fn with_llm_client(database: Arc<TranscriptDatabase>, llm_client: LLMClientWrapper) -> Result<Self> {
+    pub async fn with_llm_client(database: Arc<TranscriptDatabase>, llm_client: LLMClientWrapper) -> Result<Self> {
         let pool = database.pool.clone();
         let query_processor = QueryProcessor::new(pool.clone(), Some(llm_client.clone()));
         let context_manager = Arc::new(ConversationContextManager::new());
-        let query_optimizer = QueryOptimizer::new(pool);
+        let query_optimizer = QueryOptimizer::new(pool).await?;
         let security = QuerySecurityManager::read_only();
 
         Ok(Self {
@@ -215,24 +347,40 @@ impl MCPServer {
             query_optimizer,
             llm_client: Some(llm_client),
             sessions: Arc::new(Mutex::new(HashMap::new())),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Metrics::new(),
         })
     }
 
     /// Start the MCP server with specified transport
     pub async fn start_server<T: MCPTransport>(&self, mut transport: T) -> Result<()> {
-        log::info!("Starting MCP server with enhanced query processing");
+        tracing::info!("Starting MCP server with enhanced query processing");
+
+        let peer_id = transport.peer_id().to_string();
+        let mut subscription_check = tokio::time::interval(std::time::Duration::from_secs(2));
 
         loop {
-            match transport.receive().await {
-                Ok(request) => {
-                    let response = self.handle_request(request).await;
-                    if let Err(e) = transport.send(response).await {
-                        log::error!("Failed to send response: {}", e);
+            tokio::select! {
+                request = transport.receive() => {
+                    match request {
+                        Ok(request) => {
+                            let response = self.handle_request_from(&peer_id, request).await;
+                            if let Err(e) = transport.send(response).await {
+                                tracing::error!("Failed to send response: {}", e);
+                            }
+                        }
+                        Err(e) => {
+                            tracing::error!("Failed to receive request: {}", e);
+                            break;
+                        }
                     }
                 }
-                Err(e) => {
-                    log::error!("Failed to receive request: {}", e);
-                    break;
+                _ = subscription_check.tick() => {
+                    for notification in self.check_subscription_updates().await {
+                        if let Err(e) = transport.send_notification(notification).await {
+                            tracing::error!("Failed to send notification: {}", e);
+                        }
+                    }
                 }
             }
         }
@@ -246,18 +394,80 @@ impl MCPServer {
         self.start_server(transport).await
     }
 
-    /// Handle incoming MCP requests with enhanced processing
+    /// Bind a TCP listener for the WebSocket transport on `port`.
+    ///
+    /// Pass `0` to let the OS pick a free port (e.g. in tests); the bound
+    /// address is available via `TcpListener::local_addr`.
+    pub async fn bind_websocket(port: u16) -> Result<TcpListener> {
+        let addr = format!("127.0.0.1:{}", port);
+        TcpListener::bind(&addr).await
+            .map_err(|e| anyhow!("Failed to bind websocket listener on {}: {}", addr, e))
+    }
+
+    /// Accept WebSocket connections on `listener`, spawning an isolated
+    /// request/response loop (the same one [`MCPServer::start_server`] uses for
+    /// stdio) for each client, so multiple UIs can connect concurrently and
+    /// each gets its own session id from `initialize` without interleaving on
+    /// another client's socket.
+    pub async fn serve_websocket(self: Arc<Self>, listener: TcpListener) -> Result<()> {
+        tracing::info!("Starting MCP server with WebSocket transport on {}", listener.local_addr()?);
+
+        loop {
+            let (stream, peer_addr) = match listener.accept().await {
+                Ok(pair) => pair,
+                Err(e) => {
+                    tracing::error!("Failed to accept websocket connection: {}", e);
+                    continue;
+                }
+            };
+
+            let server = Arc::clone(&self);
+            tokio::spawn(async move {
+                let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+                    Ok(ws_stream) => ws_stream,
+                    Err(e) => {
+                        tracing::error!("WebSocket handshake with {} failed: {}", peer_addr, e);
+                        return;
+                    }
+                };
+
+                tracing::info!("WebSocket client connected: {}", peer_addr);
+                let transport = WebSocketTransport::new(ws_stream, peer_addr.to_string());
+                if let Err(e) = server.start_server(transport).await {
+                    tracing::error!("WebSocket session with {} ended with error: {}", peer_addr, e);
+                }
+            });
+        }
+    }
+
+    /// Handle incoming MCP requests with enhanced processing.
+    ///
+    /// Only reachable from call sites with no real connection to identify
+    /// (e.g. `main.rs`'s `run_test_mode` smoke test), so `initialize` is
+    /// rate-limited under a fixed [`LOCAL_PEER_ID`] rather than a real peer
+    /// identity. [`MCPServer::start_server`] calls
+    /// [`MCPServer::handle_request_from`] directly with the transport's
+    /// actual peer id instead.
     pub async fn handle_request(&self, request: MCPRequest) -> MCPResponse {
+        self.handle_request_from(LOCAL_PEER_ID, request).await
+    }
+
+    /// Same as [`Self::handle_request`], but rate-limits `initialize` under
+    /// `peer_id` instead of a shared placeholder.
+    async fn handle_request_from(&self, peer_id: &str, request: MCPRequest) -> MCPResponse {
         let id = request.id.clone();
+        let request_id = id.as_ref().map(|v| v.to_string()).unwrap_or_else(|| "null".to_string());
+        let start_time = std::time::Instant::now();
 
         // Log request for debugging
-        log::debug!("Handling MCP request: {} {}", request.method, 
+        tracing::debug!("Handling MCP request: {} {}", request.method,
                    request.params.as_ref().map(|p| p.to_string()).unwrap_or_default());
 
         let result = match request.method.as_str() {
-            "initialize" => self.handle_initialize(request.params).await,
+            "initialize" => self.handle_initialize(peer_id, request.params).await,
             "resources/list" => self.handle_list_resources().await,
             "resources/read" => self.handle_read_resource(request.params).await,
+            "resources/subscribe" => self.handle_subscribe(request.params).await,
             "tools/list" => self.handle_list_tools().await,
             "tools/call" => self.handle_tool_call(request.params).await,
             "prompts/list" => self.handle_list_prompts().await,
@@ -265,6 +475,16 @@ impl MCPServer {
             _ => Err(anyhow!("Method not found: {}", request.method)),
         };
 
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+        tracing::info!(
+            request_id = %request_id,
+            method = %request.method,
+            duration_ms,
+            success = result.is_ok(),
+            "mcp_request_completed"
+        );
+        self.metrics.record_request(&request.method, duration_ms, result.is_ok()).await;
+
         match result {
             Ok(value) => {
                 match request.method.as_str() {
@@ -334,7 +554,7 @@ impl MCPServer {
                 }
             },
             Err(e) => {
-                log::error!("Request failed: {}", e);
+                tracing::error!("Request failed: {}", e);
                 MCPResponse::Error {
                     jsonrpc: "2.0".to_string(),
                     id,
@@ -349,7 +569,19 @@ impl MCPServer {
     }
 
     /// Handle initialization request
-    async fn handle_initialize(&self, params: Option<Value>) -> Result<Value> {
+    /// Handle initialization request.
+    ///
+    /// `peer_id` identifies the underlying connection (see
+    /// [`MCPTransport::peer_id`]), not anything the client controls, so
+    /// rate-limiting on it here closes the gap a client-supplied session id
+    /// left open: minting a fresh session still costs a fresh `initialize`
+    /// call on a real connection instead of being free.
+    async fn handle_initialize(&self, peer_id: &str, params: Option<Value>) -> Result<Value> {
+        self.security
+            .check_session_rate_limit(&format!("mcp-init:{}", peer_id))
+            .await
+            .map_err(|e| anyhow!("{}", e))?;
+
         let client_info = params.unwrap_or(json!({}));
         let session_id = Uuid::new_v4().to_string();
 
@@ -362,16 +594,18 @@ impl MCPServer {
                 "prompts".to_string(),
             ],
             query_count: 0,
+            created_at: std::time::Instant::now(),
         };
 
         let mut sessions = self.sessions.lock().await;
+        prune_sessions(&mut sessions);
         sessions.insert(session_id.clone(), session);
 
         Ok(json!({
             "protocolVersion": "2024-11-05",
             "capabilities": {
                 "resources": {
-                    "subscribe": false,
+                    "subscribe": true,
                     "listChanged": false
                 },
                 "tools": {
@@ -481,4 +715,78 @@ impl MCPServer {
             _ => Err(anyhow!("Unknown resource URI: {}", uri))
         }
     }
+
+    /// Handle resource subscription request
+    ///
+    /// Only `conversations://<id>` resources can be subscribed to. The current
+    /// segment count is recorded as the baseline so the first notification only
+    /// fires once new segments actually arrive.
+    async fn handle_subscribe(&self, params: Option<Value>) -> Result<Value> {
+        let params = params.ok_or_else(|| anyhow!("Missing parameters"))?;
+        let uri = params.get("uri")
+            .and_then(|u| u.as_str())
+            .ok_or_else(|| anyhow!("Missing uri parameter"))?;
+
+        let conversation_id = uri.strip_prefix("conversations://")
+            .ok_or_else(|| anyhow!("Unsupported subscription uri: {}", uri))?;
+
+        let count = self.conversation_segment_count(conversation_id).await?;
+
+        let mut subscriptions = self.subscriptions.lock().await;
+        subscriptions.insert(uri.to_string(), count);
+
+        Ok(json!({ "uri": uri, "subscribed": true }))
+    }
+
+    async fn conversation_segment_count(&self, conversation_id: &str) -> Result<i64> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM segments WHERE conversation_id = ?")
+            .bind(conversation_id)
+            .fetch_one(&self.database.pool)
+            .await?;
+
+        Ok(row.get("count"))
+    }
+
+    /// Check every subscribed conversation for new segments, returning a
+    /// `notifications/resources/updated` JSON-RPC notification for each one
+    /// that grew since it was last observed.
+    ///
+    /// Called on a timer by [`MCPServer::start_server`]; exposed as `pub` so
+    /// it can also be triggered manually (e.g. in tests, or by a future
+    /// explicit "refresh" request) without waiting for the timer to tick.
+    pub async fn check_subscription_updates(&self) -> Vec<Value> {
+        let uris: Vec<String> = {
+            let subscriptions = self.subscriptions.lock().await;
+            subscriptions.keys().cloned().collect()
+        };
+
+        let mut notifications = Vec::new();
+        for uri in uris {
+            let Some(conversation_id) = uri.strip_prefix("conversations://") else {
+                continue;
+            };
+
+            let count = match self.conversation_segment_count(conversation_id).await {
+                Ok(count) => count,
+                Err(e) => {
+                    tracing::warn!("Failed to check subscription {}: {}", uri, e);
+                    continue;
+                }
+            };
+
+            let mut subscriptions = self.subscriptions.lock().await;
+            if let Some(last_count) = subscriptions.get_mut(&uri) {
+                if count > *last_count {
+                    *last_count = count;
+                    notifications.push(json!({
+                        "jsonrpc": "2.0",
+                        "method": "notifications/resources/updated",
+                        "params": { "uri": uri }
+                    }));
+                }
+            }
+        }
+
+        notifications
+    }
 }
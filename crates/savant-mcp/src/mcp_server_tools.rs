@@ -5,9 +5,9 @@
 use anyhow::{anyhow, Result};
 use serde_json::{json, Value};
 use std::time::Instant;
-use sqlx::Row;
+use sqlx::{Column, Row, TypeInfo};
 
-use crate::mcp_server::MCPServer;
+use crate::mcp_server::{MCPServer, MCPSession};
 use savant_db::{UserFeedback, natural_query};
 
 impl MCPServer {
@@ -27,6 +27,11 @@ impl MCPServer {
                         "session_id": {
                             "type": "string",
                             "description": "Session ID for context management (optional)"
+                        },
+                        "preview": {
+                            "type": "boolean",
+                            "description": "Return the generated SQL, bound parameters, and complexity assessment without executing the query",
+                            "default": false
                         }
                     },
                     "required": ["query"]
@@ -75,6 +80,37 @@ impl MCPServer {
                     "required": ["term"]
                 }
             }),
+            json!({
+                "name": "search_transcripts",
+                "description": "Full-text keyword search across transcript segments, returning ranked matches with surrounding context",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "query": {
+                            "type": "string",
+                            "description": "Keyword or phrase to search for"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum number of matching segments to return",
+                            "default": 20
+                        },
+                        "speaker": {
+                            "type": "string",
+                            "description": "Restrict results to segments from this speaker (optional)"
+                        },
+                        "since": {
+                            "type": "string",
+                            "description": "Only include segments at or after this ISO 8601 timestamp (optional)"
+                        },
+                        "until": {
+                            "type": "string",
+                            "description": "Only include segments at or before this ISO 8601 timestamp (optional)"
+                        }
+                    },
+                    "required": ["query"]
+                }
+            }),
             json!({
                 "name": "get_conversation_context",
                 "description": "Retrieve detailed context and segments for specific conversations",
@@ -160,6 +196,24 @@ impl MCPServer {
                     "required": ["partial_query"]
                 }
             }),
+            json!({
+                "name": "execute_sql",
+                "description": "Execute a raw read-only SQL SELECT query against the database, for cases the natural language query path can't express. Validated for safety (no writes, no multiple statements, no high-complexity plans) before execution.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "query": {
+                            "type": "string",
+                            "description": "A single SELECT statement"
+                        },
+                        "limit": {
+                            "type": "integer",
+                            "description": "Maximum number of rows to return (capped at the server's configured limit)"
+                        }
+                    },
+                    "required": ["query"]
+                }
+            }),
             json!({
                 "name": "get_database_stats",
                 "description": "Get overall database statistics and performance metrics",
@@ -189,24 +243,55 @@ impl MCPServer {
             
         let arguments = params.get("arguments")
             .ok_or_else(|| anyhow!("Missing tool arguments"))?;
-        
-        log::debug!("Executing tool: {} with args: {}", tool_name, arguments);
-        
+
+        // Rate-limit on a server-issued session id, not a client-supplied one
+        // -- otherwise any client defeats the limit by minting a fresh id
+        // (or evades it entirely by omitting it and sharing a "default"
+        // bucket with every other unauthenticated caller).
+        let session_id = arguments.get("session_id").and_then(|s| s.as_str());
+        let session_id = match session_id {
+            Some(id) if self.sessions.lock().await.contains_key(id) => id,
+            _ => {
+                return Ok(json!({
+                    "content": [{
+                        "type": "text",
+                        "text": "Error: missing or unknown session_id; call \"initialize\" first"
+                    }],
+                    "isError": true
+                }));
+            }
+        };
+
+        if let Err(e) = self.security.check_session_rate_limit(session_id).await {
+            return Ok(json!({
+                "content": [{ "type": "text", "text": format!("Error: {}", e) }],
+                "isError": true
+            }));
+        }
+
+        tracing::debug!("Executing tool: {} with args: {}", tool_name, arguments);
+
         let start_time = Instant::now();
         let result = match tool_name {
             "query_conversations" => self.tool_query_conversations(arguments).await,
             "get_speaker_analytics" => self.tool_get_speaker_analytics(arguments).await,
             "search_semantic" => self.tool_search_semantic(arguments).await,
+            "search_transcripts" => self.tool_search_transcripts(arguments).await,
             "get_conversation_context" => self.tool_get_conversation_context(arguments).await,
             "list_speakers" => self.tool_list_speakers(arguments).await,
             "learn_from_feedback" => self.tool_learn_from_feedback(arguments).await,
             "get_query_suggestions" => self.tool_get_query_suggestions(arguments).await,
+            "execute_sql" => self.tool_execute_sql(arguments).await,
             "get_database_stats" => self.tool_get_database_stats(arguments).await,
             _ => Err(anyhow!("Unknown tool: {}", tool_name)),
         };
         
         let execution_time = start_time.elapsed();
-        log::debug!("Tool {} executed in {:?}", tool_name, execution_time);
+        tracing::debug!(
+            tool = %tool_name,
+            duration_ms = execution_time.as_millis() as u64,
+            "tool_call_completed"
+        );
         
         match result {
             Ok(content) => Ok(json!({
@@ -219,7 +304,7 @@ impl MCPServer {
                 "isError": false
             })),
             Err(e) => {
-                log::error!("Tool {} failed: {}", tool_name, e);
+                tracing::error!("Tool {} failed: {}", tool_name, e);
                 Ok(json!({
                     "content": [
                         {
@@ -242,16 +327,31 @@ impl MCPServer {
         let session_id = args.get("session_id")
             .and_then(|s| s.as_str())
             .unwrap_or("default");
-        
+
+        let preview = args.get("preview")
+            .and_then(|p| p.as_bool())
+            .unwrap_or(false);
+
         // Validate query with enhanced security
         let sanitized_query = self.security.validate_natural_query(query)?;
-        
+
         // Process query with LLM-powered understanding
         let llm_result = self.query_processor.process_query(&sanitized_query, session_id).await?;
-        
+
         // Estimate complexity for security validation
         let complexity = self.security.estimate_query_cost(&llm_result.sql_query);
-        
+
+        if preview {
+            return Ok(serde_json::to_string_pretty(&json!({
+                "preview": true,
+                "intent": llm_result.intent,
+                "sql_query": llm_result.sql_query,
+                "parameters": llm_result.parameters,
+                "complexity": format!("{:?}", complexity),
+                "confidence": llm_result.confidence
+            }))?);
+        }
+
         // Enhanced security validation
         self.security.validate_query(&llm_result.sql_query, complexity).await?;
         
@@ -268,6 +368,60 @@ impl MCPServer {
         Ok(formatted_result)
     }
     
+    /// Run a raw, read-only SQL query for clients that can't express what they need
+    /// through the natural-language path. Validated through [`QuerySecurityManager`]
+    /// (single SELECT statement, no writes, no excessively expensive plans) before
+    /// it ever touches the database.
+    ///
+    /// [`QuerySecurityManager`]: savant_db::QuerySecurityManager
+    async fn tool_execute_sql(&self, args: &Value) -> Result<String> {
+        let query = args.get("query")
+            .and_then(|q| q.as_str())
+            .ok_or_else(|| anyhow!("Missing query parameter"))?;
+
+        let requested_limit = args.get("limit")
+            .and_then(|l| l.as_u64())
+            .map(|l| l as usize);
+        let limit = requested_limit
+            .map(|l| l.min(self.security.max_result_limit))
+            .unwrap_or(self.security.max_result_limit);
+
+        let complexity = self.security.estimate_query_cost(query);
+        self.security.validate_query(query, complexity).await?;
+        self.security.validate_query_plan(&self.database.pool, query).await?;
+
+        let limited_query = format!("SELECT * FROM ({query}) LIMIT {limit}");
+        let rows = sqlx::query(&limited_query).fetch_all(&self.database.pool).await?;
+
+        let results: Vec<Value> = rows.into_iter().map(|row| {
+            let mut obj = serde_json::Map::new();
+            for column in row.columns() {
+                let column_name = column.name();
+                let value = match column.type_info().name() {
+                    "TEXT" => serde_json::Value::String(
+                        row.get::<Option<String>, _>(column_name).unwrap_or_default()
+                    ),
+                    "INTEGER" => serde_json::Value::Number(
+                        serde_json::Number::from(row.get::<i64, _>(column_name))
+                    ),
+                    "REAL" => serde_json::Value::Number(
+                        serde_json::Number::from_f64(row.get::<f64, _>(column_name))
+                            .unwrap_or(serde_json::Number::from(0))
+                    ),
+                    _ => serde_json::Value::Null,
+                };
+                obj.insert(column_name.to_string(), value);
+            }
+            serde_json::Value::Object(obj)
+        }).collect();
+
+        Ok(serde_json::to_string_pretty(&json!({
+            "row_count": results.len(),
+            "limit": limit,
+            "results": results
+        }))?)
+    }
+
     /// Get detailed speaker analytics with interaction data
     async fn tool_get_speaker_analytics(&self, args: &Value) -> Result<String> {
         let speaker = args.get("speaker")
@@ -455,7 +609,158 @@ impl MCPServer {
             "search_type": "exact"
         }))?)
     }
-    
+
+    /// Full-text keyword search over transcript segments, with an optional
+    /// speaker/time filter and a `LIKE`-based fallback when FTS finds nothing.
+    /// Each result is annotated with the segment immediately before and after
+    /// it in the same conversation for surrounding context.
+    async fn tool_search_transcripts(&self, args: &Value) -> Result<String> {
+        let query_text = args.get("query")
+            .and_then(|q| q.as_str())
+            .ok_or_else(|| anyhow!("Missing query parameter"))?;
+
+        let limit = args.get("limit")
+            .and_then(|l| l.as_i64())
+            .unwrap_or(20) as i32;
+
+        let speaker = args.get("speaker").and_then(|s| s.as_str());
+        let since = args.get("since").and_then(|s| s.as_str());
+        let until = args.get("until").and_then(|s| s.as_str());
+
+        let mut filters = String::new();
+        if speaker.is_some() {
+            filters.push_str(" AND s.speaker = ?");
+        }
+        if since.is_some() {
+            filters.push_str(" AND s.timestamp >= ?");
+        }
+        if until.is_some() {
+            filters.push_str(" AND s.timestamp <= ?");
+        }
+
+        let fts_sql = format!(
+            r#"
+            SELECT
+                s.id,
+                s.conversation_id,
+                s.text,
+                s.speaker,
+                s.timestamp,
+                s.confidence,
+                c.title as conversation_title,
+                rank
+            FROM segments_fts
+            JOIN segments s ON segments_fts.rowid = s.id
+            JOIN conversations c ON s.conversation_id = c.id
+            WHERE segments_fts MATCH ?{filters}
+            ORDER BY rank, s.timestamp DESC
+            LIMIT ?
+            "#
+        );
+
+        let mut fts_query = sqlx::query(&fts_sql).bind(format!("\"{}\"", query_text));
+        if let Some(speaker) = speaker {
+            fts_query = fts_query.bind(speaker);
+        }
+        if let Some(since) = since {
+            fts_query = fts_query.bind(since);
+        }
+        if let Some(until) = until {
+            fts_query = fts_query.bind(until);
+        }
+        fts_query = fts_query.bind(limit);
+
+        let mut rows = fts_query.fetch_all(&self.database.pool).await.unwrap_or_default();
+        let mut search_type = "fts";
+
+        if rows.is_empty() {
+            let like_sql = format!(
+                r#"
+                SELECT
+                    s.id,
+                    s.conversation_id,
+                    s.text,
+                    s.speaker,
+                    s.timestamp,
+                    s.confidence,
+                    c.title as conversation_title,
+                    0 as rank
+                FROM segments s
+                JOIN conversations c ON s.conversation_id = c.id
+                WHERE s.text LIKE ?{filters}
+                ORDER BY s.timestamp DESC
+                LIMIT ?
+                "#
+            );
+
+            let mut like_query = sqlx::query(&like_sql).bind(format!("%{}%", query_text));
+            if let Some(speaker) = speaker {
+                like_query = like_query.bind(speaker);
+            }
+            if let Some(since) = since {
+                like_query = like_query.bind(since);
+            }
+            if let Some(until) = until {
+                like_query = like_query.bind(until);
+            }
+            like_query = like_query.bind(limit);
+
+            rows = like_query.fetch_all(&self.database.pool).await?;
+            search_type = "like_fallback";
+        }
+
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let conversation_id: String = row.get("conversation_id");
+            let timestamp: chrono::DateTime<chrono::Utc> = row.get("timestamp");
+
+            let before = sqlx::query(
+                "SELECT text, speaker, timestamp FROM segments WHERE conversation_id = ? AND timestamp < ? ORDER BY timestamp DESC LIMIT 1"
+            )
+            .bind(&conversation_id)
+            .bind(timestamp)
+            .fetch_optional(&self.database.pool)
+            .await?;
+
+            let after = sqlx::query(
+                "SELECT text, speaker, timestamp FROM segments WHERE conversation_id = ? AND timestamp > ? ORDER BY timestamp ASC LIMIT 1"
+            )
+            .bind(&conversation_id)
+            .bind(timestamp)
+            .fetch_optional(&self.database.pool)
+            .await?;
+
+            let context_segment = |row: Option<sqlx::sqlite::SqliteRow>| {
+                row.map(|row| json!({
+                    "text": row.get::<String, _>("text"),
+                    "speaker": row.get::<Option<String>, _>("speaker"),
+                    "timestamp": row.get::<chrono::DateTime<chrono::Utc>, _>("timestamp"),
+                }))
+            };
+
+            results.push(json!({
+                "segment_id": row.get::<String, _>("id"),
+                "text": row.get::<String, _>("text"),
+                "speaker": row.get::<Option<String>, _>("speaker"),
+                "timestamp": timestamp,
+                "confidence": row.get::<Option<f64>, _>("confidence"),
+                "conversation_title": row.get::<Option<String>, _>("conversation_title"),
+                "conversation_id": conversation_id,
+                "context": {
+                    "before": context_segment(before),
+                    "after": context_segment(after)
+                }
+            }));
+        }
+
+        Ok(serde_json::to_string_pretty(&json!({
+            "query": query_text,
+            "results": results,
+            "total_found": results.len(),
+            "search_type": search_type
+        }))?)
+    }
+
     /// Get detailed conversation context
     async fn tool_get_conversation_context(&self, args: &Value) -> Result<String> {
         let conversation_id = args.get("conversation_id")
@@ -727,4 +1032,121 @@ impl MCPServer {
             "summary": format!("Query executed successfully with {} confidence", llm_result.confidence)
         }))?)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use savant_db::TranscriptDatabase;
+    use std::sync::Arc;
+
+    async fn test_server() -> MCPServer {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("mcp-tools-test.db");
+        let database = Arc::new(TranscriptDatabase::new(Some(db_path)).await.unwrap());
+        // Keep the temp dir alive for the test's duration by leaking it;
+        // it's cleaned up when the sandbox/test process exits.
+        std::mem::forget(temp_dir);
+        MCPServer::new(database, None).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_preview_mode_returns_sql_without_executing_query() {
+        let server = test_server().await;
+
+        let response = server.tool_query_conversations(&json!({
+            "query": "list speakers",
+            "preview": true
+        })).await.unwrap();
+
+        let parsed: Value = serde_json::from_str(&response).unwrap();
+        assert_eq!(parsed["preview"], json!(true));
+        assert!(parsed["sql_query"].as_str().unwrap().contains("SELECT"));
+        assert!(parsed.get("complexity").is_some());
+        // The non-preview path's execute_structured_query/format_query_results
+        // wraps its output in a "results" key - its absence here shows the
+        // query was never executed.
+        assert!(parsed.get("results").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_execute_sql_allows_plain_select() {
+        let server = test_server().await;
+
+        let response = server.tool_execute_sql(&json!({
+            "query": "SELECT id, title FROM conversations WHERE id = 'c1'"
+        })).await.unwrap();
+
+        let parsed: Value = serde_json::from_str(&response).unwrap();
+        assert_eq!(parsed["row_count"], json!(0));
+        assert!(parsed["results"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_sql_rejects_non_select_statement() {
+        let server = test_server().await;
+
+        let result = server.tool_execute_sql(&json!({
+            "query": "INSERT INTO conversations (id, title, start_time) VALUES ('x', 'y', CURRENT_TIMESTAMP)"
+        })).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_sql_rejects_multiple_statements() {
+        let server = test_server().await;
+
+        let result = server.tool_execute_sql(&json!({
+            "query": "SELECT 1; DROP TABLE conversations;"
+        })).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_call_rejects_unknown_session_id() {
+        let server = test_server().await;
+
+        let response = server.handle_tool_call(Some(json!({
+            "name": "list_speakers",
+            "arguments": { "session_id": "attacker-chosen" }
+        }))).await.unwrap();
+
+        assert_eq!(response["isError"], json!(true));
+        assert!(response["content"][0]["text"].as_str().unwrap().contains("session_id"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_call_rejects_missing_session_id() {
+        let server = test_server().await;
+
+        let response = server.handle_tool_call(Some(json!({
+            "name": "list_speakers",
+            "arguments": {}
+        }))).await.unwrap();
+
+        assert_eq!(response["isError"], json!(true));
+        assert!(response["content"][0]["text"].as_str().unwrap().contains("session_id"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_tool_call_accepts_server_issued_session_id() {
+        let server = test_server().await;
+        let session_id = "server-issued-session".to_string();
+        server.sessions.lock().await.insert(session_id.clone(), MCPSession {
+            id: session_id.clone(),
+            client_info: None,
+            capabilities: vec![],
+            query_count: 0,
+            created_at: std::time::Instant::now(),
+        });
+
+        let response = server.handle_tool_call(Some(json!({
+            "name": "list_speakers",
+            "arguments": { "session_id": session_id }
+        }))).await.unwrap();
+
+        assert_eq!(response["isError"], json!(false));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+//! Optional Prometheus-style metrics endpoint for the MCP server.
+//!
+//! Off by default; enabled by passing `--metrics-port` to `savant-mcp-server`.
+//! Served over its own bare HTTP listener (no framework dependency, same
+//! minimal-TCP approach as [`crate::mcp_server::WebSocketTransport`]) so it
+//! stays fully isolated from the JSON-RPC transport (stdio or websocket).
+
+use anyhow::{anyhow, Result};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+/// Per-method request counters and cumulative duration, rendered as
+/// Prometheus text format by [`Metrics::render`].
+#[derive(Debug, Default)]
+pub struct Metrics {
+    requests_total: Mutex<HashMap<String, u64>>,
+    errors_total: Mutex<HashMap<String, u64>>,
+    duration_ms_sum: Mutex<HashMap<String, f64>>,
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Records one completed request. Called by the server's request dispatcher for
+    /// every request, whether it came through [`crate::mcp_server::MCPServer::handle_request`]
+    /// or the WebSocket transport.
+    pub async fn record_request(&self, method: &str, duration_ms: u64, success: bool) {
+        *self.requests_total.lock().await.entry(method.to_string()).or_insert(0) += 1;
+        *self.duration_ms_sum.lock().await.entry(method.to_string()).or_insert(0.0) += duration_ms as f64;
+
+        if !success {
+            *self.errors_total.lock().await.entry(method.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    /// Renders current counters plus live DB pool stats in Prometheus text format.
+    pub async fn render(&self, pool: &SqlitePool) -> String {
+        let requests_total = self.requests_total.lock().await;
+        let errors_total = self.errors_total.lock().await;
+        let duration_ms_sum = self.duration_ms_sum.lock().await;
+
+        let mut out = String::new();
+
+        out.push_str("# HELP savant_mcp_requests_total Total MCP requests handled, by method\n");
+        out.push_str("# TYPE savant_mcp_requests_total counter\n");
+        for (method, count) in requests_total.iter() {
+            out.push_str(&format!("savant_mcp_requests_total{{method=\"{}\"}} {}\n", method, count));
+        }
+
+        out.push_str("# HELP savant_mcp_errors_total Total MCP requests that returned an error, by method\n");
+        out.push_str("# TYPE savant_mcp_errors_total counter\n");
+        for (method, count) in errors_total.iter() {
+            out.push_str(&format!("savant_mcp_errors_total{{method=\"{}\"}} {}\n", method, count));
+        }
+
+        out.push_str("# HELP savant_mcp_request_duration_ms_sum Cumulative request duration in milliseconds, by method\n");
+        out.push_str("# TYPE savant_mcp_request_duration_ms_sum counter\n");
+        for (method, sum) in duration_ms_sum.iter() {
+            out.push_str(&format!("savant_mcp_request_duration_ms_sum{{method=\"{}\"}} {}\n", method, sum));
+        }
+
+        out.push_str("# HELP savant_mcp_db_pool_connections Current SQLite connection pool size\n");
+        out.push_str("# TYPE savant_mcp_db_pool_connections gauge\n");
+        out.push_str(&format!("savant_mcp_db_pool_connections {}\n", pool.size()));
+
+        out.push_str("# HELP savant_mcp_db_pool_idle_connections Idle connections in the SQLite connection pool\n");
+        out.push_str("# TYPE savant_mcp_db_pool_idle_connections gauge\n");
+        out.push_str(&format!("savant_mcp_db_pool_idle_connections {}\n", pool.num_idle()));
+
+        out
+    }
+}
+
+/// Binds a TCP listener for the metrics endpoint on `port`.
+///
+/// Pass `0` to let the OS pick a free port (e.g. in tests); the bound address
+/// is available via `TcpListener::local_addr`.
+pub async fn bind_metrics(port: u16) -> Result<TcpListener> {
+    let addr = format!("127.0.0.1:{}", port);
+    TcpListener::bind(&addr).await
+        .map_err(|e| anyhow!("Failed to bind metrics listener on {}: {}", addr, e))
+}
+
+/// Accepts connections on `listener` and responds to every request with the
+/// current metrics snapshot, regardless of path or method - this endpoint is
+/// for scraping, not browsing, so there's no routing to speak of.
+pub async fn serve_metrics(listener: TcpListener, metrics: Arc<Metrics>, pool: SqlitePool) -> Result<()> {
+    tracing::info!("Serving metrics on {}", listener.local_addr()?);
+
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(pair) => pair,
+            Err(e) => {
+                tracing::error!("Failed to accept metrics connection: {}", e);
+                continue;
+            }
+        };
+
+        let metrics = Arc::clone(&metrics);
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_metrics_connection(stream, &metrics, &pool).await {
+                tracing::error!("Metrics connection error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_metrics_connection(mut stream: TcpStream, metrics: &Metrics, pool: &SqlitePool) -> Result<()> {
+    // We don't care about the request beyond "a client connected" - drain
+    // whatever it sent so the socket doesn't reset before we can respond.
+    let mut buf = [0u8; 1024];
+    let _ = stream.read(&mut buf).await;
+
+    let body = metrics.render(pool).await;
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+
+    stream.write_all(response.as_bytes()).await
+        .map_err(|e| anyhow!("Failed to write metrics response: {}", e))?;
+    stream.flush().await
+        .map_err(|e| anyhow!("Failed to flush metrics response: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn test_pool() -> SqlitePool {
+        SqlitePoolOptions::new().connect("sqlite::memory:").await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_scrape_endpoint_after_requests_reflects_incremented_counters() {
+        let metrics = Metrics::new();
+        metrics.record_request("tools/call", 12, true).await;
+        metrics.record_request("tools/call", 8, true).await;
+        metrics.record_request("tools/list", 3, false).await;
+
+        let pool = test_pool().await;
+        let listener = bind_metrics(0).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(serve_metrics(listener, metrics, pool));
+
+        // Give the spawned listener a moment to start accepting.
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await.unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.contains("savant_mcp_requests_total{method=\"tools/call\"} 2"));
+        assert!(response.contains("savant_mcp_requests_total{method=\"tools/list\"} 1"));
+        assert!(response.contains("savant_mcp_errors_total{method=\"tools/list\"} 1"));
+    }
+}
@@ -0,0 +1,87 @@
+use savant_mcp::{MCPRequest, MCPServer};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+/// A `tracing_subscriber::fmt::MakeWriter` that appends everything written to
+/// it into a shared in-memory buffer, so a test can inspect log output
+/// without touching the real stderr stream.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for SharedBuffer {
+    type Writer = SharedBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+async fn setup_test_server() -> (Arc<MCPServer>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+    drop(pool);
+
+    let database = Arc::new(savant_db::TranscriptDatabase::new(Some(db_path)).await.unwrap());
+    let server = Arc::new(MCPServer::new(database, None).await.unwrap());
+    (server, temp_dir)
+}
+
+#[tokio::test]
+async fn test_json_log_format_emits_valid_json_lines_with_request_fields() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    let buffer = SharedBuffer::default();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_writer(buffer.clone())
+        .finish();
+
+    let request = MCPRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(42)),
+        method: "tools/list".to_string(),
+        params: None,
+    };
+
+    let _guard = tracing::subscriber::set_default(subscriber);
+    server.handle_request(request).await;
+    drop(_guard);
+
+    let captured = buffer.0.lock().unwrap().clone();
+    let output = String::from_utf8(captured).unwrap();
+
+    let mut found_completion_event = false;
+    for line in output.lines().filter(|l| !l.trim().is_empty()) {
+        let parsed: Value = serde_json::from_str(line)
+            .unwrap_or_else(|e| panic!("log line was not valid JSON: {} ({})", line, e));
+
+        let fields = &parsed["fields"];
+        if fields["method"] == "tools/list" {
+            found_completion_event = true;
+            assert_eq!(fields["request_id"], "42");
+            assert!(fields["duration_ms"].is_number());
+        }
+    }
+
+    assert!(found_completion_event, "expected a log line for the tools/list request");
+}
@@ -0,0 +1,123 @@
+use savant_mcp::{MCPRequest, MCPResponse, MCPServer};
+use serde_json::json;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+async fn setup_test_server() -> (Arc<MCPServer>, sqlx::Pool<sqlx::Sqlite>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE conversations (
+            id TEXT PRIMARY KEY,
+            title TEXT,
+            start_time DATETIME NOT NULL,
+            end_time DATETIME,
+            context TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE segments (
+            id TEXT PRIMARY KEY,
+            conversation_id TEXT NOT NULL,
+            timestamp DATETIME NOT NULL,
+            speaker TEXT NOT NULL,
+            audio_source TEXT NOT NULL,
+            text TEXT NOT NULL,
+            start_time REAL NOT NULL,
+            end_time REAL NOT NULL,
+            confidence REAL,
+            metadata TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        INSERT INTO conversations (id, start_time, title)
+        VALUES ('conv-1', datetime('now', '-1 hour'), 'Test Conversation')
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let database = Arc::new(savant_db::TranscriptDatabase::new(Some(db_path)).await.unwrap());
+    let server = Arc::new(MCPServer::new(database, None).await.unwrap());
+    (server, pool, temp_dir)
+}
+
+#[tokio::test]
+async fn test_subscribe_to_conversation_accepts_known_uri() {
+    let (server, _pool, _temp_dir) = setup_test_server().await;
+
+    let response = server
+        .handle_request(MCPRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "resources/subscribe".to_string(),
+            params: Some(json!({ "uri": "conversations://conv-1" })),
+        })
+        .await;
+
+    let MCPResponse::ToolResult { result, .. } = response else {
+        panic!("Expected ToolResult response");
+    };
+    assert_eq!(result["uri"], "conversations://conv-1");
+    assert_eq!(result["subscribed"], true);
+}
+
+#[tokio::test]
+async fn test_new_segment_triggers_resource_updated_notification() {
+    let (server, pool, _temp_dir) = setup_test_server().await;
+
+    server
+        .handle_request(MCPRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "resources/subscribe".to_string(),
+            params: Some(json!({ "uri": "conversations://conv-1" })),
+        })
+        .await;
+
+    // No new segments yet: nothing to report.
+    assert!(server.check_subscription_updates().await.is_empty());
+
+    sqlx::query(
+        r#"
+        INSERT INTO segments (id, conversation_id, timestamp, speaker, audio_source, text, start_time, end_time, confidence)
+        VALUES ('seg-1', 'conv-1', datetime('now'), 'user', 'microphone', 'a new segment arrived', 0.0, 2.0, 0.9)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let notifications = server.check_subscription_updates().await;
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0]["method"], "notifications/resources/updated");
+    assert_eq!(notifications[0]["params"]["uri"], "conversations://conv-1");
+
+    // A second check with no further changes should not re-notify.
+    assert!(server.check_subscription_updates().await.is_empty());
+}
@@ -0,0 +1,162 @@
+use savant_mcp::{MCPRequest, MCPResponse, MCPServer};
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+async fn setup_test_server() -> (Arc<MCPServer>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE conversations (
+            id TEXT PRIMARY KEY,
+            title TEXT,
+            start_time DATETIME NOT NULL,
+            end_time DATETIME,
+            context TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE segments (
+            id TEXT PRIMARY KEY,
+            conversation_id TEXT NOT NULL,
+            timestamp DATETIME NOT NULL,
+            speaker TEXT NOT NULL,
+            audio_source TEXT NOT NULL,
+            text TEXT NOT NULL,
+            start_time REAL NOT NULL,
+            end_time REAL NOT NULL,
+            confidence REAL,
+            metadata TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        INSERT INTO conversations (id, start_time, title)
+        VALUES ('conv-1', datetime('now', '-1 hour'), 'Test Conversation')
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        INSERT INTO segments (id, conversation_id, timestamp, speaker, audio_source, text, start_time, end_time, confidence)
+        VALUES
+            ('seg-1', 'conv-1', datetime('now', '-30 minutes'), 'user', 'microphone', 'How do I implement a binary search tree?', 0.0, 5.0, 0.95),
+            ('seg-2', 'conv-1', datetime('now', '-29 minutes'), 'assistant', 'system', 'To implement a binary search tree, start with a Node struct.', 0.0, 15.0, 0.98),
+            ('seg-3', 'conv-1', datetime('now', '-15 minutes'), 'user', 'microphone', 'Can you show me the code in Python?', 0.0, 3.0, 0.93)
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    drop(pool);
+
+    let database = Arc::new(savant_db::TranscriptDatabase::new(Some(db_path)).await.unwrap());
+    let server = Arc::new(MCPServer::new(database, None).await.unwrap());
+    (server, temp_dir)
+}
+
+#[tokio::test]
+async fn test_search_transcripts_registered_with_valid_schema() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    let response = server
+        .handle_request(MCPRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "tools/list".to_string(),
+            params: None,
+        })
+        .await;
+
+    let MCPResponse::ToolsList { result, .. } = response else {
+        panic!("Expected ToolsList response");
+    };
+    let tools = result["tools"].as_array().expect("result.tools should be an array");
+    let tool = tools
+        .iter()
+        .find(|t| t["name"] == "search_transcripts")
+        .expect("search_transcripts should be registered");
+
+    let schema = &tool["inputSchema"];
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["query"]));
+    assert!(schema["properties"]["query"].is_object());
+    assert!(schema["properties"]["speaker"].is_object());
+    assert!(schema["properties"]["since"].is_object());
+    assert!(schema["properties"]["until"].is_object());
+}
+
+#[tokio::test]
+async fn test_search_transcripts_returns_ranked_segments_with_context() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    // tools/call only accepts session ids issued by `initialize`.
+    let MCPResponse::ToolResult { result: init, .. } = server
+        .handle_request(MCPRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: "initialize".to_string(),
+            params: None,
+        })
+        .await
+    else {
+        panic!("Expected initialize result");
+    };
+    let session_id = init["sessionId"].as_str().unwrap().to_string();
+
+    let response = server
+        .handle_request(MCPRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(2)),
+            method: "tools/call".to_string(),
+            params: Some(json!({
+                "name": "search_transcripts",
+                "arguments": {
+                    "query": "binary search tree",
+                    "limit": 10,
+                    "session_id": session_id
+                }
+            })),
+        })
+        .await;
+
+    let MCPResponse::ToolResult { content, .. } = response else {
+        panic!("Expected ToolResult response");
+    };
+    let results: Value = serde_json::from_str(&content[0].text).unwrap();
+
+    let matches = results["results"].as_array().expect("results should be an array");
+    assert!(!matches.is_empty(), "expected at least one matching segment");
+
+    let first = &matches[0];
+    assert!(first["segment_id"].is_string());
+    assert!(first["text"].as_str().unwrap().contains("binary search tree"));
+    assert!(first["context"]["before"].is_object() || first["context"]["before"].is_null());
+    assert!(first["context"]["after"].is_object() || first["context"]["after"].is_null());
+}
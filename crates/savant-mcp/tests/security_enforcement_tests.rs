@@ -0,0 +1,142 @@
+use savant_db::security::QueryComplexity;
+use savant_mcp::{MCPRequest, MCPResponse, MCPServer};
+use serde_json::json;
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+async fn setup_test_server() -> (Arc<MCPServer>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE conversations (
+            id TEXT PRIMARY KEY,
+            title TEXT,
+            start_time DATETIME NOT NULL,
+            end_time DATETIME,
+            context TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE segments (
+            id TEXT PRIMARY KEY,
+            conversation_id TEXT NOT NULL,
+            timestamp DATETIME NOT NULL,
+            speaker TEXT NOT NULL,
+            audio_source TEXT NOT NULL,
+            text TEXT NOT NULL,
+            start_time REAL NOT NULL,
+            end_time REAL NOT NULL,
+            confidence REAL,
+            metadata TEXT,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    drop(pool);
+
+    let database = Arc::new(savant_db::TranscriptDatabase::new(Some(db_path)).await.unwrap());
+    let server = Arc::new(MCPServer::new(database, None).await.unwrap());
+    (server, temp_dir)
+}
+
+/// `MCPServer::security` routes every generated SQL query through
+/// `QuerySecurityManager`, which rejects `QueryComplexity::High` outright
+/// rather than letting an expensive cross join through.
+#[tokio::test]
+async fn test_high_complexity_query_is_blocked() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    let expensive_join = "SELECT * FROM conversations c \
+         JOIN segments s ON s.conversation_id = c.id \
+         JOIN speakers sp ON sp.id = s.speaker \
+         GROUP BY c.id ORDER BY c.id LIMIT 10";
+
+    let complexity = server.security.estimate_query_cost(expensive_join);
+    assert_eq!(complexity, QueryComplexity::High);
+
+    let result = server.security.validate_query(expensive_join, complexity).await;
+    assert!(result.is_err(), "high complexity query should be rejected");
+    assert!(result.unwrap_err().to_string().contains("complexity"));
+}
+
+#[tokio::test]
+async fn test_tool_call_rate_limit_exceeded_returns_error_content() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    // tools/call only accepts session ids issued by `initialize`.
+    let initialize = |i: i64| MCPRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(i)),
+        method: "initialize".to_string(),
+        params: None,
+    };
+    let MCPResponse::ToolResult { result: init, .. } = server.handle_request(initialize(-1)).await else {
+        panic!("Expected initialize result");
+    };
+    let session_id = init["sessionId"].as_str().unwrap().to_string();
+    let MCPResponse::ToolResult { result: init, .. } = server.handle_request(initialize(-2)).await else {
+        panic!("Expected initialize result");
+    };
+    let other_session_id = init["sessionId"].as_str().unwrap().to_string();
+
+    let call = |i: i64| MCPRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(i)),
+        method: "tools/call".to_string(),
+        params: Some(json!({
+            "name": "get_database_stats",
+            "arguments": { "session_id": session_id }
+        })),
+    };
+
+    for i in 0..60 {
+        let response = server.handle_request(call(i)).await;
+        let MCPResponse::ToolResult { result, .. } = response else {
+            panic!("Expected ToolResult response");
+        };
+        assert_eq!(result["isError"], false, "request {i} should not be rate limited yet");
+    }
+
+    let response = server.handle_request(call(60)).await;
+    let MCPResponse::ToolResult { result, content, .. } = response else {
+        panic!("Expected ToolResult response");
+    };
+    assert_eq!(result["isError"], true);
+    assert!(content[0].text.contains("Rate limit exceeded"));
+
+    // A different session still has its own budget.
+    let other_session = MCPRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(61)),
+        method: "tools/call".to_string(),
+        params: Some(json!({
+            "name": "get_database_stats",
+            "arguments": { "session_id": other_session_id }
+        })),
+    };
+    let response = server.handle_request(other_session).await;
+    let MCPResponse::ToolResult { result, .. } = response else {
+        panic!("Expected ToolResult response");
+    };
+    assert_eq!(result["isError"], false);
+}
@@ -0,0 +1,61 @@
+use futures::{SinkExt, StreamExt};
+use savant_mcp::MCPServer;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqlitePoolOptions;
+use std::sync::Arc;
+use tempfile::TempDir;
+use tokio_tungstenite::tungstenite::Message;
+
+async fn setup_test_server() -> (Arc<MCPServer>, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .unwrap();
+    drop(pool);
+
+    let database = Arc::new(savant_db::TranscriptDatabase::new(Some(db_path)).await.unwrap());
+    let server = Arc::new(MCPServer::new(database, None).await.unwrap());
+    (server, temp_dir)
+}
+
+#[tokio::test]
+async fn test_websocket_client_can_list_tools() {
+    let (server, _temp_dir) = setup_test_server().await;
+
+    let listener = MCPServer::bind_websocket(0).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        server.serve_websocket(listener).await.unwrap();
+    });
+
+    let (mut ws_stream, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .expect("client should connect to the websocket server");
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/list",
+        "params": null
+    });
+    ws_stream.send(Message::Text(request.to_string())).await.unwrap();
+
+    let message = tokio::time::timeout(std::time::Duration::from_secs(5), ws_stream.next())
+        .await
+        .expect("should receive a response before timing out")
+        .expect("stream should not close before responding")
+        .expect("message should be a valid websocket frame");
+
+    let Message::Text(text) = message else {
+        panic!("Expected a text frame, got {:?}", message);
+    };
+    let response: Value = serde_json::from_str(&text).unwrap();
+
+    let tools = response["result"]["tools"].as_array().expect("result.tools should be an array");
+    assert!(tools.iter().any(|t| t["name"] == "search_transcripts"));
+}
@@ -18,6 +18,10 @@ pub struct StructuredContent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeBlock {
     pub language: Option<String>,
+    /// Confidence in `language`, in `[0.0, 1.0]`. `None` if no signatures were checked
+    /// (e.g. an empty block); low alongside a `language` of `"unknown"` when no
+    /// language's signatures matched strongly enough to call it.
+    pub language_confidence: Option<f32>,
     pub content: String,
     pub line_numbers: Option<Vec<u32>>,
     pub bounding_box: BoundingBox,
@@ -176,28 +180,142 @@ pub struct MeetingContext {
     pub screen_sharing: bool,
 }
 
+/// Default horizontal pixel width of one indentation level (roughly one
+/// tab-stop at typical editor font sizes)
+const DEFAULT_PIXELS_PER_INDENT_LEVEL: f32 = 20.0;
+
+/// Below this confidence, `detect_language_with_confidence` reports `"unknown"` rather
+/// than guessing between closely-matching languages.
+const LANGUAGE_CONFIDENCE_THRESHOLD: f32 = 0.3;
+
+/// A regex signature for a language, weighted by how unambiguously it identifies that
+/// language (e.g. `fn ... ->` is strongly Rust-specific; a bare `{`/`;` is common to the
+/// whole C family and carries little weight on its own).
+struct LanguageSignature {
+    pattern: regex::Regex,
+    weight: f32,
+}
+
 pub struct StructuredContentAnalyzer {
     syntax_patterns: HashMap<String, Vec<regex::Regex>>,
+    language_signatures: HashMap<String, Vec<LanguageSignature>>,
+    indent_width: usize,
+    pixels_per_indent_level: f32,
 }
 
 impl StructuredContentAnalyzer {
     pub fn new() -> Self {
         let mut syntax_patterns = HashMap::new();
-        
+
         // Common programming language patterns
         syntax_patterns.insert("rust".to_string(), vec![
             regex::Regex::new(r"\b(fn|let|mut|const|struct|enum|impl|trait|use|mod|pub|crate)\b").unwrap(),
         ]);
-        
+
         syntax_patterns.insert("javascript".to_string(), vec![
             regex::Regex::new(r"\b(function|const|let|var|class|async|await|import|export)\b").unwrap(),
         ]);
-        
+
         syntax_patterns.insert("python".to_string(), vec![
             regex::Regex::new(r"\b(def|class|import|from|if|else|elif|for|while|try|except)\b").unwrap(),
         ]);
 
-        Self { syntax_patterns }
+        Self {
+            syntax_patterns,
+            language_signatures: Self::build_language_signatures(),
+            indent_width: 4,
+            pixels_per_indent_level: DEFAULT_PIXELS_PER_INDENT_LEVEL,
+        }
+    }
+
+    /// Keyword/syntax signatures used for language classification, grouped by language
+    /// and weighted by specificity. C-family signatures (curly braces + semicolons) are
+    /// intentionally generic and low-weight since they overlap with several languages.
+    fn build_language_signatures() -> HashMap<String, Vec<LanguageSignature>> {
+        let sig = |pattern: &str, weight: f32| LanguageSignature {
+            pattern: regex::Regex::new(pattern).unwrap(),
+            weight,
+        };
+
+        let mut signatures = HashMap::new();
+
+        signatures.insert("python".to_string(), vec![
+            sig(r"\bdef\s+\w+\s*\(.*\)\s*:", 0.4),
+            sig(r"\bimport\s+\w+", 0.2),
+            sig(r"\bfrom\s+\w+\s+import\b", 0.2),
+            sig(r"\belif\b", 0.2),
+            sig(r":\s*$", 0.15),
+            sig(r"\bself\b", 0.15),
+        ]);
+
+        signatures.insert("rust".to_string(), vec![
+            sig(r"\bfn\s+\w+\s*\(", 0.35),
+            sig(r"->", 0.2),
+            sig(r"\blet\s+(mut\s+)?\w+", 0.15),
+            sig(r"::", 0.2),
+            sig(r"\bpub\b", 0.15),
+        ]);
+
+        signatures.insert("go".to_string(), vec![
+            sig(r"\bfunc\s+\w+\s*\(", 0.35),
+            sig(r"\bpackage\s+\w+", 0.3),
+            sig(r":=", 0.25),
+            sig(r"\bimport\s*\(", 0.15),
+        ]);
+
+        signatures.insert("javascript".to_string(), vec![
+            sig(r"\bfunction\s+\w+\s*\(", 0.3),
+            sig(r"=>", 0.2),
+            sig(r"\b(const|let|var)\s+\w+\s*=", 0.15),
+            sig(r"\brequire\s*\(", 0.15),
+            sig(r"\bconsole\.log\s*\(", 0.2),
+        ]);
+
+        signatures.insert("c".to_string(), vec![
+            sig(r"#include\s*[<\x22]", 0.35),
+            sig(r"\bint\s+main\s*\(", 0.3),
+            sig(r";\s*$", 0.1),
+            sig(r"\{", 0.1),
+        ]);
+
+        signatures
+    }
+
+    /// Score `text` against every language's signatures and return the best match with
+    /// its confidence in `[0.0, 1.0]`, or `("unknown", confidence)` if no language's
+    /// signatures matched strongly enough to distinguish it from the rest.
+    fn detect_language_with_confidence(&self, text: &str) -> (String, f32) {
+        let mut scores: Vec<(&str, f32)> = self
+            .language_signatures
+            .iter()
+            .map(|(language, signatures)| {
+                let total_weight: f32 = signatures.iter().map(|s| s.weight).sum();
+                let matched_weight: f32 = signatures
+                    .iter()
+                    .filter(|s| s.pattern.is_match(text))
+                    .map(|s| s.weight)
+                    .sum();
+
+                let score = if total_weight > 0.0 { matched_weight / total_weight } else { 0.0 };
+                (language.as_str(), score)
+            })
+            .collect();
+
+        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        match scores.first() {
+            Some(&(language, score)) if score >= LANGUAGE_CONFIDENCE_THRESHOLD => {
+                (language.to_string(), score)
+            }
+            Some(&(_, score)) => ("unknown".to_string(), score),
+            None => ("unknown".to_string(), 0.0),
+        }
+    }
+
+    /// Spaces inserted per inferred indentation level (default 4)
+    pub fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
     }
 
     pub fn analyze(&self, text_blocks: &[TextBlock]) -> Result<StructuredContent> {
@@ -256,14 +374,10 @@ impl StructuredContentAnalyzer {
         // Group consecutive code blocks
         let mut current_block_text = String::new();
         let mut current_bounding_box: Option<BoundingBox> = None;
-        let mut detected_language: Option<String> = None;
+        let left_margin = blocks.iter().map(|b| b.bounding_box.x).min().unwrap_or(0);
 
         for block in blocks {
-            // Detect programming language
-            if detected_language.is_none() {
-                detected_language = self.detect_programming_language(&block.text);
-            }
-
+            current_block_text.push_str(&self.reconstruct_indentation(block, left_margin));
             current_block_text.push_str(&block.text);
             current_block_text.push('\n');
 
@@ -284,10 +398,13 @@ impl StructuredContentAnalyzer {
         }
 
         if !current_block_text.trim().is_empty() {
+            let (language, confidence) = self.detect_language_with_confidence(&current_block_text);
+            let detected_language = if language == "unknown" { None } else { Some(language.clone()) };
             let syntax_elements = self.analyze_syntax_elements(&current_block_text, &detected_language);
-            
+
             code_blocks.push(CodeBlock {
-                language: detected_language,
+                language: Some(language),
+                language_confidence: Some(confidence),
                 content: current_block_text.trim().to_string(),
                 line_numbers: None,
                 bounding_box: current_bounding_box.unwrap_or_default(),
@@ -298,15 +415,12 @@ impl StructuredContentAnalyzer {
         Ok(code_blocks)
     }
 
-    fn detect_programming_language(&self, text: &str) -> Option<String> {
-        for (language, patterns) in &self.syntax_patterns {
-            for pattern in patterns {
-                if pattern.is_match(text) {
-                    return Some(language.clone());
-                }
-            }
-        }
-        None
+    /// Infer leading whitespace for a code line from how far its bounding box
+    /// sits to the right of the block's left margin
+    fn reconstruct_indentation(&self, block: &TextBlock, left_margin: u32) -> String {
+        let offset_px = block.bounding_box.x.saturating_sub(left_margin) as f32;
+        let level = (offset_px / self.pixels_per_indent_level).round() as usize;
+        " ".repeat(level * self.indent_width)
     }
 
     fn analyze_syntax_elements(&self, code: &str, language: &Option<String>) -> Vec<SyntaxElement> {
@@ -432,4 +546,59 @@ impl Default for BoundingBox {
     fn default() -> Self {
         Self { x: 0, y: 0, width: 0, height: 0 }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language_with_confidence_identifies_python() {
+        let analyzer = StructuredContentAnalyzer::new();
+        let (language, confidence) = analyzer.detect_language_with_confidence(
+            "def greet(name):\n    if name:\n        print(f'hello {name}')\n    elif True:\n        pass",
+        );
+        assert_eq!(language, "python");
+        assert!(confidence >= LANGUAGE_CONFIDENCE_THRESHOLD, "confidence too low: {confidence}");
+    }
+
+    #[test]
+    fn test_detect_language_with_confidence_identifies_rust() {
+        let analyzer = StructuredContentAnalyzer::new();
+        let (language, confidence) = analyzer.detect_language_with_confidence(
+            "pub fn add(a: i32, b: i32) -> i32 {\n    let sum = a + b;\n    std::cmp::max(sum, 0)\n}",
+        );
+        assert_eq!(language, "rust");
+        assert!(confidence >= LANGUAGE_CONFIDENCE_THRESHOLD, "confidence too low: {confidence}");
+    }
+
+    #[test]
+    fn test_detect_language_with_confidence_identifies_go() {
+        let analyzer = StructuredContentAnalyzer::new();
+        let (language, confidence) = analyzer.detect_language_with_confidence(
+            "package main\n\nfunc main() {\n\tresult := compute()\n\tprintln(result)\n}",
+        );
+        assert_eq!(language, "go");
+        assert!(confidence >= LANGUAGE_CONFIDENCE_THRESHOLD, "confidence too low: {confidence}");
+    }
+
+    #[test]
+    fn test_detect_language_with_confidence_identifies_javascript() {
+        let analyzer = StructuredContentAnalyzer::new();
+        let (language, confidence) = analyzer.detect_language_with_confidence(
+            "const greet = (name) => {\n  console.log(`hello ${name}`);\n};",
+        );
+        assert_eq!(language, "javascript");
+        assert!(confidence >= LANGUAGE_CONFIDENCE_THRESHOLD, "confidence too low: {confidence}");
+    }
+
+    #[test]
+    fn test_detect_language_with_confidence_is_unknown_for_plain_english() {
+        let analyzer = StructuredContentAnalyzer::new();
+        let (language, confidence) = analyzer.detect_language_with_confidence(
+            "Thanks for joining the call today, let's plan next steps for the project before Friday.",
+        );
+        assert_eq!(language, "unknown");
+        assert!(confidence < LANGUAGE_CONFIDENCE_THRESHOLD, "expected low confidence, got {confidence}");
+    }
 }
\ No newline at end of file
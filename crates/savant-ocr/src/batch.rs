@@ -0,0 +1,107 @@
+//! Batch OCR processing over a pool of reusable engine instances
+//!
+//! `OCRProcessor::new` spins up a fresh Tesseract/EasyOCR engine, which is fine for a
+//! single image but wasteful when processing directories of hundreds of screenshots one
+//! at a time. `BatchAnalyzer` amortizes that setup cost across a small pool of
+//! processors, round-robining work across them with bounded concurrency.
+
+use crate::{OCRConfig, OCRProcessor, OCRResult};
+use anyhow::{Context, Result};
+use futures::stream::{self, Stream, StreamExt};
+use std::path::PathBuf;
+
+/// A pool of reusable [`OCRProcessor`] instances for analyzing many images without
+/// re-initializing the underlying OCR engine per image.
+pub struct BatchAnalyzer {
+    processors: Vec<OCRProcessor>,
+}
+
+impl BatchAnalyzer {
+    /// Build a pool of `pool_size` processors (clamped to at least 1), each sharing the
+    /// same config but owning an independent engine instance.
+    pub fn new(config: OCRConfig, pool_size: usize) -> Result<Self> {
+        let pool_size = pool_size.max(1);
+        let mut processors = Vec::with_capacity(pool_size);
+        let first = OCRProcessor::new(config.clone())?;
+
+        for _ in 1..pool_size {
+            let engine = first.engine.clone_engine()?;
+            processors.push(OCRProcessor {
+                engine,
+                preprocessor: crate::ImagePreprocessor::new(config.preprocessing.clone()),
+                text_classifier: crate::TextClassifier::new(),
+                content_analyzer: crate::StructuredContentAnalyzer::new(),
+                config: config.clone(),
+                cache: None,
+            });
+        }
+        processors.push(first);
+
+        Ok(Self { processors })
+    }
+
+    /// Analyze every path in `paths`, running at most `concurrency` OCR passes at once
+    /// (further bounded by the pool size), yielding `(path, OCRResult)` pairs as a
+    /// stream in completion order rather than input order.
+    pub fn analyze_many<'a>(
+        &'a self,
+        paths: &'a [PathBuf],
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<(PathBuf, OCRResult)>> + 'a {
+        let pool_size = self.processors.len();
+        let bound = concurrency.clamp(1, pool_size);
+
+        stream::iter(paths.iter().cloned().enumerate())
+            .map(move |(i, path)| {
+                let processor = &self.processors[i % pool_size];
+                async move {
+                    let image = image::open(&path)
+                        .with_context(|| format!("failed to open image: {}", path.display()))?;
+                    let result = processor.process_image(&image).await?;
+                    Ok((path, result))
+                }
+            })
+            .buffer_unordered(bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PreprocessingConfig;
+    use image::{DynamicImage, RgbImage};
+
+    fn write_blank_fixture(dir: &std::path::Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        let image = DynamicImage::ImageRgb8(RgbImage::new(32, 32));
+        image.save(&path).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_analyze_many_completes_all_fixtures_concurrently() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<PathBuf> = (0..4)
+            .map(|i| write_blank_fixture(dir.path(), &format!("fixture-{i}.png")))
+            .collect();
+
+        let config = OCRConfig {
+            preprocessing: PreprocessingConfig { enabled: false, ..Default::default() },
+            ..Default::default()
+        };
+        let analyzer = BatchAnalyzer::new(config, 2).unwrap();
+
+        let results: Vec<_> = analyzer.analyze_many(&paths, 2).collect().await;
+        assert_eq!(results.len(), paths.len());
+
+        let completed: Vec<PathBuf> = results
+            .into_iter()
+            .map(|r| r.map(|(path, _)| path))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        for path in &paths {
+            assert!(completed.contains(path));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Persistent OCR result cache keyed by a hash of the preprocessed image plus
+//! the active [`OCRConfig`], so repeated OCR of an unchanged screenshot under
+//! an unchanged config is free. See [`crate::OCRProcessor::with_cache`].
+
+use crate::{OCRConfig, OCRResult};
+use anyhow::Result;
+use image::DynamicImage;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Disk-backed cache mapping an `(image, config)` hash to a serialized [`OCRResult`].
+/// Tracks hit/miss counts so tests and callers can observe cache behavior without
+/// depending on timing.
+pub struct OCRCache {
+    cache_dir: PathBuf,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl OCRCache {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        let cache_dir = cache_dir.into();
+        std::fs::create_dir_all(&cache_dir)?;
+        Ok(Self {
+            cache_dir,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        })
+    }
+
+    /// Hashes the image's raw pixels (so any content change invalidates the entry)
+    /// together with a hash of `config` (so any preprocessing/engine/confidence
+    /// change invalidates it too).
+    pub fn key(image: &DynamicImage, config: &OCRConfig) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        image.as_bytes().hash(&mut hasher);
+        image.width().hash(&mut hasher);
+        image.height().hash(&mut hasher);
+        serde_json::to_string(config).unwrap_or_default().hash(&mut hasher);
+        format!("{:x}", hasher.finish())
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}.json", key))
+    }
+
+    /// Returns the cached result for `key`, if present and readable, recording a
+    /// hit or miss accordingly.
+    pub fn get(&self, key: &str) -> Option<OCRResult> {
+        let result = std::fs::read_to_string(self.path_for(key))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok());
+
+        if result.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        result
+    }
+
+    pub fn put(&self, key: &str, result: &OCRResult) -> Result<()> {
+        std::fs::write(self.path_for(key), serde_json::to_string(result)?)?;
+        Ok(())
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ImageMetadata, StructuredContent};
+    use chrono::Utc;
+
+    fn sample_result() -> OCRResult {
+        OCRResult {
+            text_blocks: vec![],
+            structured_content: StructuredContent::default(),
+            overall_confidence: 0.9,
+            processing_time_ms: 5,
+            detected_language: "eng".to_string(),
+            image_metadata: ImageMetadata {
+                width: 10,
+                height: 10,
+                format: "DynamicImage".to_string(),
+                file_size: None,
+                timestamp: Utc::now(),
+            },
+            confidence_distribution: vec![],
+            low_confidence_ratio: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_key_is_stable_for_same_config_and_changes_with_config() {
+        let image = DynamicImage::new_rgba8(4, 4);
+        let config_a = OCRConfig::default();
+        let mut config_b = OCRConfig::default();
+        config_b.min_confidence = 0.9;
+
+        assert_eq!(OCRCache::key(&image, &config_a), OCRCache::key(&image, &config_a));
+        assert_ne!(OCRCache::key(&image, &config_a), OCRCache::key(&image, &config_b));
+    }
+
+    #[test]
+    fn test_get_put_round_trip_records_miss_then_hit() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = OCRCache::new(temp_dir.path()).unwrap();
+        let image = DynamicImage::new_rgba8(4, 4);
+        let config = OCRConfig::default();
+        let key = OCRCache::key(&image, &config);
+
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.misses(), 1);
+
+        cache.put(&key, &sample_result()).unwrap();
+        let cached = cache.get(&key).unwrap();
+        assert_eq!(cached.detected_language, "eng");
+        assert_eq!(cache.hits(), 1);
+    }
+}
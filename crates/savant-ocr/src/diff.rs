@@ -0,0 +1,154 @@
+//! Diffing OCR results across consecutive frames
+//!
+//! Lets the video pipeline record only textual deltas between frames (what appeared,
+//! disappeared, or scrolled) instead of re-dumping the full OCR result every frame.
+
+use crate::{BoundingBox, OCRResult, TextBlock};
+use serde::{Deserialize, Serialize};
+
+/// Bounding-box center distance (in pixels) below which two blocks with identical text
+/// are treated as the same, stationary block rather than one that moved.
+const MOVE_PROXIMITY_PX: f32 = 50.0;
+
+/// A block whose text is unchanged between frames but whose position shifted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovedBlock {
+    pub text: String,
+    pub from: BoundingBox,
+    pub to: BoundingBox,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OcrDiff {
+    pub added: Vec<TextBlock>,
+    pub removed: Vec<TextBlock>,
+    pub moved: Vec<MovedBlock>,
+}
+
+/// Diff two OCR results, matching blocks by text content and bounding-box proximity.
+/// For each block in `prev`, the closest same-text block in `next` is considered its
+/// match: if it shifted beyond [`MOVE_PROXIMITY_PX`] it's reported as moved, otherwise
+/// it's treated as unchanged and dropped from the diff. Text with no match in `next` is
+/// removed; text left over in `next` after matching is added.
+pub fn diff_results(prev: &OCRResult, next: &OCRResult) -> OcrDiff {
+    let mut remaining_next: Vec<&TextBlock> = next.text_blocks.iter().collect();
+    let mut diff = OcrDiff::default();
+
+    for prev_block in &prev.text_blocks {
+        let closest_match = remaining_next
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.text == prev_block.text)
+            .min_by(|(_, a), (_, b)| {
+                center_distance(&prev_block.bounding_box, &a.bounding_box)
+                    .partial_cmp(&center_distance(&prev_block.bounding_box, &b.bounding_box))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(idx, candidate)| (idx, (*candidate).clone()));
+
+        match closest_match {
+            Some((idx, next_block)) => {
+                let distance = center_distance(&prev_block.bounding_box, &next_block.bounding_box);
+                if distance > MOVE_PROXIMITY_PX {
+                    diff.moved.push(MovedBlock {
+                        text: prev_block.text.clone(),
+                        from: prev_block.bounding_box.clone(),
+                        to: next_block.bounding_box.clone(),
+                    });
+                }
+                remaining_next.remove(idx);
+            }
+            None => diff.removed.push(prev_block.clone()),
+        }
+    }
+
+    diff.added = remaining_next.into_iter().cloned().collect();
+
+    diff
+}
+
+/// Euclidean distance between the centers of two bounding boxes
+fn center_distance(a: &BoundingBox, b: &BoundingBox) -> f32 {
+    let ax = a.x as f32 + a.width as f32 / 2.0;
+    let ay = a.y as f32 + a.height as f32 / 2.0;
+    let bx = b.x as f32 + b.width as f32 / 2.0;
+    let by = b.y as f32 + b.height as f32 / 2.0;
+
+    ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{analyzer::StructuredContent, ImageMetadata, TextType};
+    use chrono::Utc;
+
+    fn block(text: &str, x: u32, y: u32) -> TextBlock {
+        TextBlock {
+            text: text.to_string(),
+            confidence: 0.9,
+            bounding_box: BoundingBox { x, y, width: 100, height: 20 },
+            font_info: None,
+            semantic_type: TextType::Unknown,
+            language: None,
+        }
+    }
+
+    fn result(blocks: Vec<TextBlock>) -> OCRResult {
+        let (confidence_distribution, low_confidence_ratio) = OCRResult::confidence_stats(&blocks);
+        OCRResult {
+            text_blocks: blocks,
+            structured_content: StructuredContent::default(),
+            overall_confidence: 0.9,
+            processing_time_ms: 0,
+            detected_language: "eng".to_string(),
+            image_metadata: ImageMetadata {
+                width: 800,
+                height: 600,
+                format: "png".to_string(),
+                file_size: None,
+                timestamp: Utc::now(),
+            },
+            confidence_distribution,
+            low_confidence_ratio,
+        }
+    }
+
+    #[test]
+    fn test_diff_results_categorizes_added_and_moved_blocks() {
+        let prev = result(vec![
+            block("Hello", 10, 10),
+            block("Stationary", 10, 100),
+        ]);
+        let next = result(vec![
+            block("Hello", 10, 400), // moved far down
+            block("Stationary", 10, 100), // unchanged
+            block("New Line", 10, 200), // newly appeared
+        ]);
+
+        let diff = diff_results(&prev, &next);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].text, "New Line");
+
+        assert_eq!(diff.moved.len(), 1);
+        assert_eq!(diff.moved[0].text, "Hello");
+        assert_eq!(diff.moved[0].from.y, 10);
+        assert_eq!(diff.moved[0].to.y, 400);
+
+        assert!(diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_results_reports_removed_text_with_no_match() {
+        let prev = result(vec![block("Gone", 10, 10)]);
+        let next = result(vec![]);
+
+        let diff = diff_results(&prev, &next);
+
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].text, "Gone");
+        assert!(diff.added.is_empty());
+        assert!(diff.moved.is_empty());
+    }
+}
@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use image::DynamicImage;
+use serde::Deserialize;
+use std::process::Command;
 use crate::{BoundingBox, TextBlock, TextType};
 
 #[async_trait]
@@ -11,16 +13,91 @@ pub trait OCREngine: Send {
     fn clone_engine(&self) -> Result<Box<dyn OCREngine>>;
 }
 
+/// Common install locations for Tesseract's `tessdata` directory, checked
+/// when `TESSDATA_PREFIX` isn't set
+const COMMON_TESSDATA_DIRS: &[&str] = &[
+    "/usr/share/tesseract-ocr/4.00/tessdata",
+    "/usr/share/tesseract-ocr/5/tessdata",
+    "/usr/share/tessdata",
+    "/opt/homebrew/share/tessdata",
+    "/usr/local/share/tessdata",
+];
+
 pub struct TesseractEngine {
     languages: Vec<String>,
 }
 
 impl TesseractEngine {
     pub fn new(languages: &[String]) -> Result<Self> {
+        Self::validate_languages(languages)?;
+
         Ok(Self {
             languages: languages.to_vec(),
         })
     }
+
+    /// Locate the tessdata directory, preferring `TESSDATA_PREFIX` when set
+    fn tessdata_dir() -> Option<std::path::PathBuf> {
+        if let Ok(dir) = std::env::var("TESSDATA_PREFIX") {
+            let path = std::path::PathBuf::from(dir);
+            if path.is_dir() {
+                return Some(path);
+            }
+        }
+
+        COMMON_TESSDATA_DIRS
+            .iter()
+            .map(std::path::PathBuf::from)
+            .find(|path| path.is_dir())
+    }
+
+    /// List the language codes with installed `.traineddata` files
+    pub fn available_languages() -> Vec<String> {
+        let Some(dir) = Self::tessdata_dir() else {
+            return Vec::new();
+        };
+
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            return Vec::new();
+        };
+
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) == Some("traineddata") {
+                    path.file_stem().and_then(|s| s.to_str()).map(String::from)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Ensure every requested language has a `.traineddata` file installed,
+    /// returning a helpful error naming the missing language pack otherwise
+    fn validate_languages(languages: &[String]) -> Result<()> {
+        let available = Self::available_languages();
+
+        // If we can't find a tessdata directory at all, defer to Tesseract's
+        // own error at call time rather than guessing
+        if available.is_empty() {
+            return Ok(());
+        }
+
+        for lang in languages {
+            if !available.contains(lang) {
+                anyhow::bail!(
+                    "Tesseract language pack '{lang}' is not installed (expected '{lang}.traineddata' \
+                     in the tessdata directory). Download it from \
+                     https://github.com/tesseract-ocr/tessdata and place it in your TESSDATA_PREFIX \
+                     directory, or install it via your package manager (e.g. `apt install tesseract-ocr-{lang}`)."
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -117,3 +194,133 @@ impl TesseractEngine {
         Ok(text_blocks)
     }
 }
+
+/// Default name of the EasyOCR/PaddleOCR bridge script, looked up on `PATH`
+/// unless overridden by `SAVANT_EASYOCR_BIN`.
+const DEFAULT_EASYOCR_BIN: &str = "easyocr-bridge";
+
+/// One detection emitted by the bridge script's JSON output:
+/// `{"text": "...", "confidence": 0.97, "x": 10, "y": 20, "width": 100, "height": 24}`
+#[derive(Debug, Deserialize)]
+struct EasyOcrDetection {
+    text: String,
+    confidence: f32,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// OCREngine backed by an EasyOCR/PaddleOCR Python subprocess, invoked once
+/// per image with a temp-file path and expected to print JSON detections to stdout.
+pub struct EasyOcrEngine {
+    languages: Vec<String>,
+    binary_path: String,
+}
+
+impl EasyOcrEngine {
+    pub fn new(languages: &[String]) -> Result<Self> {
+        let binary_path =
+            std::env::var("SAVANT_EASYOCR_BIN").unwrap_or_else(|_| DEFAULT_EASYOCR_BIN.to_string());
+
+        if which::which(&binary_path).is_err() {
+            anyhow::bail!(
+                "EasyOCR bridge binary '{binary_path}' not found on PATH. \
+                 Install it with `pip install easyocr` and place a wrapper script \
+                 (or set SAVANT_EASYOCR_BIN to its path) that takes an image path \
+                 and prints JSON detections to stdout."
+            );
+        }
+
+        Ok(Self {
+            languages: languages.to_vec(),
+            binary_path,
+        })
+    }
+}
+
+#[async_trait]
+impl OCREngine for EasyOcrEngine {
+    async fn extract_text(&self, image: &DynamicImage) -> Result<Vec<TextBlock>> {
+        let temp_file = tempfile::Builder::new()
+            .suffix(".png")
+            .tempfile()
+            .context("Failed to create temp file for EasyOCR input")?;
+
+        image
+            .save_with_format(temp_file.path(), image::ImageFormat::Png)
+            .context("Failed to write image for EasyOCR")?;
+
+        let binary_path = self.binary_path.clone();
+        let languages = self.languages.join(",");
+        let image_path = temp_file.path().to_path_buf();
+
+        let output = tokio::task::spawn_blocking(move || {
+            Command::new(&binary_path)
+                .arg(&image_path)
+                .arg("--lang")
+                .arg(&languages)
+                .output()
+        })
+        .await
+        .context("EasyOCR subprocess task panicked")?
+        .context("Failed to run EasyOCR subprocess")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "EasyOCR subprocess exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        let detections: Vec<EasyOcrDetection> = serde_json::from_slice(&output.stdout)
+            .context("Failed to parse EasyOCR JSON output")?;
+
+        Ok(detections
+            .into_iter()
+            .map(|d| TextBlock {
+                text: d.text,
+                confidence: d.confidence,
+                bounding_box: BoundingBox {
+                    x: d.x,
+                    y: d.y,
+                    width: d.width,
+                    height: d.height,
+                },
+                font_info: None,
+                semantic_type: TextType::Unknown,
+                language: self.languages.first().cloned(),
+            })
+            .collect())
+    }
+
+    fn get_supported_languages(&self) -> Vec<String> {
+        // EasyOCR's commonly supported language codes
+        vec![
+            "en".to_string(),
+            "es".to_string(),
+            "fr".to_string(),
+            "de".to_string(),
+            "it".to_string(),
+            "pt".to_string(),
+            "ru".to_string(),
+            "ja".to_string(),
+            "ko".to_string(),
+            "ch_sim".to_string(),
+            "ch_tra".to_string(),
+        ]
+    }
+
+    fn set_language(&mut self, languages: &[String]) -> Result<()> {
+        self.languages = languages.to_vec();
+        Ok(())
+    }
+
+    fn clone_engine(&self) -> Result<Box<dyn OCREngine>> {
+        Ok(Box::new(EasyOcrEngine {
+            languages: self.languages.clone(),
+            binary_path: self.binary_path.clone(),
+        }))
+    }
+}
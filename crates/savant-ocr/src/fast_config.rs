@@ -205,6 +205,11 @@ impl FastOCRProcessor {
                 .sum::<f32>() / result.text_blocks.len() as f32;
         }
 
+        let (confidence_distribution, low_confidence_ratio) =
+            OCRResult::confidence_stats(&result.text_blocks);
+        result.confidence_distribution = confidence_distribution;
+        result.low_confidence_ratio = low_confidence_ratio;
+
         result
     }
 
@@ -226,6 +231,8 @@ impl FastOCRProcessor {
                 file_size: None,
                 timestamp: Utc::now(),
             },
+            confidence_distribution: vec![],
+            low_confidence_ratio: 0.0,
         }
     }
 
@@ -234,17 +241,20 @@ impl FastOCRProcessor {
         use crate::{ImageMetadata, StructuredContent, TextBlock, BoundingBox, TextType};
         use chrono::Utc;
 
+        let text_blocks = vec![
+            TextBlock {
+                text: "Coding Problem".to_string(),
+                confidence: 0.5,
+                bounding_box: BoundingBox { x: 0, y: 0, width: 200, height: 30 },
+                font_info: None,
+                semantic_type: TextType::DocumentContent,
+                language: Some("en".to_string()),
+            }
+        ];
+        let (confidence_distribution, low_confidence_ratio) = OCRResult::confidence_stats(&text_blocks);
+
         OCRResult {
-            text_blocks: vec![
-                TextBlock {
-                    text: "Coding Problem".to_string(),
-                    confidence: 0.5,
-                    bounding_box: BoundingBox { x: 0, y: 0, width: 200, height: 30 },
-                    font_info: None,
-                    semantic_type: TextType::DocumentContent,
-                    language: Some("en".to_string()),
-                }
-            ],
+            text_blocks,
             structured_content: StructuredContent::default(),
             overall_confidence: 0.5,
             processing_time_ms: processing_time.as_millis() as u64,
@@ -256,6 +266,8 @@ impl FastOCRProcessor {
                 file_size: None,
                 timestamp: Utc::now(),
             },
+            confidence_distribution,
+            low_confidence_ratio,
         }
     }
 }
@@ -298,6 +310,80 @@ impl OCRPresets {
     }
 }
 
+/// Speed/accuracy tradeoff selector for the CLI, mapping to a full [`OCRConfig`] rather
+/// than the [`FastOCRConfig`] used by [`FastOCRProcessor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OCRPreset {
+    /// Preprocessing disabled, low confidence threshold, classification and structure
+    /// analysis off. Lowest latency, least accurate.
+    Fast,
+    /// Default preprocessing and confidence threshold with text classification enabled.
+    /// A reasonable tradeoff for most use.
+    Balanced,
+    /// Full preprocessing at target DPI, highest confidence threshold, classification,
+    /// structure analysis, and parallel processing all enabled. Highest latency, most
+    /// accurate.
+    Accurate,
+}
+
+impl OCRPreset {
+    /// Build the [`OCRConfig`] this preset tunes preprocessing, confidence,
+    /// classification, and parallelism for.
+    pub fn to_ocr_config(self) -> OCRConfig {
+        match self {
+            OCRPreset::Fast => OCRConfig {
+                preprocessing: PreprocessingConfig {
+                    enabled: false,
+                    ..Default::default()
+                },
+                min_confidence: 0.3,
+                enable_text_classification: false,
+                enable_structure_analysis: false,
+                parallel_processing: false,
+                ..Default::default()
+            },
+            OCRPreset::Balanced => OCRConfig {
+                min_confidence: 0.5,
+                enable_text_classification: true,
+                enable_structure_analysis: false,
+                parallel_processing: false,
+                ..Default::default()
+            },
+            OCRPreset::Accurate => OCRConfig {
+                preprocessing: PreprocessingConfig {
+                    enabled: true,
+                    denoise: true,
+                    enhance_contrast: true,
+                    adaptive_threshold: true,
+                    gaussian_blur: Some(0.5),
+                    scale_factor: None,
+                    dpi_target: Some(300),
+                },
+                min_confidence: 0.6,
+                enable_text_classification: true,
+                enable_structure_analysis: true,
+                parallel_processing: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl std::str::FromStr for OCRPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "fast" => Ok(OCRPreset::Fast),
+            "balanced" => Ok(OCRPreset::Balanced),
+            "accurate" => Ok(OCRPreset::Accurate),
+            other => Err(format!(
+                "unknown OCR preset '{other}' (expected fast, balanced, or accurate)"
+            )),
+        }
+    }
+}
+
 /// Performance monitoring for OCR operations
 #[derive(Debug, Clone)]
 pub struct OCRPerformanceMetrics {
@@ -348,4 +434,49 @@ impl OCRPerformanceMetrics {
         println!("   Avg Confidence: {:.2}", self.avg_confidence);
         println!("   Success Rate: {:.1}%", (self.successful_detections as f64 / self.total_processed as f64) * 100.0);
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_fast_preset_disables_preprocessing_and_extras() {
+        let config = OCRPreset::from_str("fast").unwrap().to_ocr_config();
+
+        assert!(!config.preprocessing.enabled);
+        assert_eq!(config.min_confidence, 0.3);
+        assert!(!config.enable_text_classification);
+        assert!(!config.enable_structure_analysis);
+        assert!(!config.parallel_processing);
+    }
+
+    #[test]
+    fn test_balanced_preset_enables_classification_with_default_preprocessing() {
+        let config = OCRPreset::from_str("balanced").unwrap().to_ocr_config();
+
+        assert!(config.preprocessing.enabled);
+        assert_eq!(config.min_confidence, 0.5);
+        assert!(config.enable_text_classification);
+        assert!(!config.enable_structure_analysis);
+        assert!(!config.parallel_processing);
+    }
+
+    #[test]
+    fn test_accurate_preset_enables_everything_at_target_dpi() {
+        let config = OCRPreset::from_str("accurate").unwrap().to_ocr_config();
+
+        assert!(config.preprocessing.enabled);
+        assert_eq!(config.preprocessing.dpi_target, Some(300));
+        assert_eq!(config.min_confidence, 0.6);
+        assert!(config.enable_text_classification);
+        assert!(config.enable_structure_analysis);
+        assert!(config.parallel_processing);
+    }
+
+    #[test]
+    fn test_ocr_preset_from_str_rejects_unknown_names() {
+        assert!(OCRPreset::from_str("turbo").is_err());
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
-use image::DynamicImage;
+use image::{DynamicImage, GenericImageView};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -10,13 +10,20 @@ pub mod classifier;
 pub mod analyzer;
 pub mod simple_extractor;
 pub mod fast_config;
+pub mod batch;
+pub mod diff;
+pub mod gpu;
+pub mod cache;
 
-pub use engine::{OCREngine, TesseractEngine};
+pub use engine::{EasyOcrEngine, OCREngine, TesseractEngine};
 pub use preprocessor::{ImagePreprocessor, PreprocessingConfig};
 pub use classifier::{TextClassifier, TextType};
 pub use analyzer::{StructuredContentAnalyzer, StructuredContent};
 pub use simple_extractor::{ComprehensiveOCRProcessor, ComprehensiveOCRResult, WordData, LineData, ParagraphData};
-pub use fast_config::{FastOCRConfig, FastOCRProcessor, OCRPresets, OCRPerformanceMetrics};
+pub use fast_config::{FastOCRConfig, FastOCRProcessor, OCRPreset, OCRPresets, OCRPerformanceMetrics};
+pub use batch::BatchAnalyzer;
+pub use diff::{diff_results, OcrDiff, MovedBlock};
+pub use cache::OCRCache;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoundingBox {
@@ -52,6 +59,50 @@ pub struct OCRResult {
     pub processing_time_ms: u64,
     pub detected_language: String,
     pub image_metadata: ImageMetadata,
+    /// Histogram of block confidences bucketed into tenths (`0.0..0.1`, `0.1..0.2`,
+    /// ..., `0.9..=1.0`), as `(bucket_lower_bound, block_count)` pairs covering every
+    /// bucket from 0.0 to 0.9 in order. Lets downstream consumers (e.g. the
+    /// coding-problem detector) reject frames where a single low average hides a
+    /// bimodal split between confidently- and poorly-read text.
+    pub confidence_distribution: Vec<(f32, usize)>,
+    /// Fraction of `text_blocks` with confidence below [`LOW_CONFIDENCE_THRESHOLD`].
+    pub low_confidence_ratio: f32,
+}
+
+/// Confidence below this is considered unreliable enough to flag for quality gating.
+pub const LOW_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+impl OCRResult {
+    /// Buckets `blocks`' confidences into tenths and computes the ratio below
+    /// [`LOW_CONFIDENCE_THRESHOLD`]. Shared by every `OCRResult` construction site so
+    /// the two stay consistent with `text_blocks`.
+    pub fn confidence_stats(blocks: &[TextBlock]) -> (Vec<(f32, usize)>, f32) {
+        const BUCKET_COUNT: usize = 10;
+        let mut histogram = vec![0usize; BUCKET_COUNT];
+        let mut low_confidence_count = 0usize;
+
+        for block in blocks {
+            let bucket = ((block.confidence * BUCKET_COUNT as f32) as usize).min(BUCKET_COUNT - 1);
+            histogram[bucket] += 1;
+            if block.confidence < LOW_CONFIDENCE_THRESHOLD {
+                low_confidence_count += 1;
+            }
+        }
+
+        let distribution = histogram
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (i as f32 / BUCKET_COUNT as f32, count))
+            .collect();
+
+        let low_confidence_ratio = if blocks.is_empty() {
+            0.0
+        } else {
+            low_confidence_count as f32 / blocks.len() as f32
+        };
+
+        (distribution, low_confidence_ratio)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -94,12 +145,16 @@ pub struct OCRProcessor {
     pub text_classifier: TextClassifier,
     pub content_analyzer: StructuredContentAnalyzer,
     pub config: OCRConfig,
+    /// Optional persistent result cache, set via [`Self::with_cache`]. `None` by
+    /// default, so plain `new` callers pay no caching overhead.
+    pub cache: Option<OCRCache>,
 }
 
 impl OCRProcessor {
     pub fn new(config: OCRConfig) -> Result<Self> {
         let engine: Box<dyn OCREngine> = match config.engine.as_str() {
             "tesseract" => Box::new(TesseractEngine::new(&config.languages)?),
+            "easyocr" => Box::new(EasyOcrEngine::new(&config.languages)?),
             _ => anyhow::bail!("Unsupported OCR engine: {}", config.engine),
         };
 
@@ -109,12 +164,23 @@ impl OCRProcessor {
             text_classifier: TextClassifier::new(),
             content_analyzer: StructuredContentAnalyzer::new(),
             config,
+            cache: None,
         })
     }
 
+    /// Like [`Self::new`], but caches [`OCRResult`]s under `cache_dir`, keyed by a
+    /// hash of the preprocessed image plus the config (see [`OCRCache::key`]), so
+    /// re-processing an unchanged image under an unchanged config is free. A
+    /// config change naturally invalidates old entries since it changes the key.
+    pub fn with_cache(config: OCRConfig, cache_dir: impl Into<std::path::PathBuf>) -> Result<Self> {
+        let mut processor = Self::new(config)?;
+        processor.cache = Some(OCRCache::new(cache_dir)?);
+        Ok(processor)
+    }
+
     pub async fn process_image(&self, image: &DynamicImage) -> Result<OCRResult> {
         let start_time = std::time::Instant::now();
-        
+
         // Preprocess image
         let processed_image = if self.config.preprocessing.enabled {
             self.preprocessor.process(image)?
@@ -122,8 +188,20 @@ impl OCRProcessor {
             image.clone()
         };
 
-        // Extract text
-        let text_blocks = self.engine.extract_text(&processed_image).await?;
+        let cache_key = self.cache.as_ref().map(|_| OCRCache::key(&processed_image, &self.config));
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(cached) = cache.get(key) {
+                return Ok(cached);
+            }
+        }
+
+        // Extract text, splitting into a grid and running regions concurrently
+        // when the config allows it
+        let text_blocks = if self.config.parallel_processing {
+            self.extract_text_parallel(&processed_image).await?
+        } else {
+            self.engine.extract_text(&processed_image).await?
+        };
 
         // Filter by confidence
         let filtered_blocks: Vec<TextBlock> = text_blocks
@@ -152,6 +230,166 @@ impl OCRProcessor {
             .sum::<f32>() / classified_blocks.len().max(1) as f32;
 
         let detected_language = self.detect_primary_language(&classified_blocks);
+        let (confidence_distribution, low_confidence_ratio) =
+            OCRResult::confidence_stats(&classified_blocks);
+
+        let result = OCRResult {
+            text_blocks: classified_blocks,
+            structured_content,
+            overall_confidence,
+            processing_time_ms: processing_time,
+            detected_language,
+            image_metadata: ImageMetadata {
+                width: image.width(),
+                height: image.height(),
+                format: "DynamicImage".to_string(),
+                file_size: None,
+                timestamp: Utc::now(),
+            },
+            confidence_distribution,
+            low_confidence_ratio,
+        };
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            cache.put(key, &result)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Split the image into a fixed grid and run the configured OCR engine on each cell
+    /// concurrently via `spawn_blocking`, bounded by `MAX_CONCURRENT_REGIONS`.
+    async fn extract_text_parallel(&self, image: &DynamicImage) -> Result<Vec<TextBlock>> {
+        const GRID_COLS: u32 = 2;
+        const GRID_ROWS: u32 = 2;
+        const MAX_CONCURRENT_REGIONS: usize = 4;
+
+        let regions = Self::grid_regions(image, GRID_COLS, GRID_ROWS);
+        let mut join_set = tokio::task::JoinSet::new();
+        let mut all_blocks = Vec::new();
+        let mut pending = regions.into_iter();
+
+        // Seed the pool up to the concurrency limit, then top it off as tasks finish
+        for region in pending.by_ref().take(MAX_CONCURRENT_REGIONS) {
+            Self::spawn_region_task(&mut join_set, image, region, self.engine.clone_engine()?);
+        }
+
+        while let Some(result) = join_set.join_next().await {
+            all_blocks.extend(result.map_err(|e| anyhow::anyhow!("OCR region task panicked: {}", e))??);
+
+            if let Some(region) = pending.next() {
+                Self::spawn_region_task(&mut join_set, image, region, self.engine.clone_engine()?);
+            }
+        }
+
+        Ok(all_blocks)
+    }
+
+    fn spawn_region_task(
+        join_set: &mut tokio::task::JoinSet<Result<Vec<TextBlock>>>,
+        image: &DynamicImage,
+        region: BoundingBox,
+        engine: Box<dyn OCREngine>,
+    ) {
+        let cropped = image.crop_imm(region.x, region.y, region.width, region.height);
+
+        join_set.spawn_blocking(move || {
+            let handle = tokio::runtime::Handle::current();
+            let mut blocks = handle.block_on(engine.extract_text(&cropped))?;
+
+            for block in &mut blocks {
+                block.bounding_box.x += region.x;
+                block.bounding_box.y += region.y;
+            }
+
+            Ok(blocks)
+        });
+    }
+
+    /// Divide an image into `cols` x `rows` non-overlapping cells, in absolute coordinates
+    fn grid_regions(image: &DynamicImage, cols: u32, rows: u32) -> Vec<BoundingBox> {
+        let (width, height) = image.dimensions();
+        let cell_width = width / cols;
+        let cell_height = height / rows;
+
+        let mut regions = Vec::with_capacity((cols * rows) as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                let x = col * cell_width;
+                let y = row * cell_height;
+                // Last column/row absorbs any remainder from integer division
+                let w = if col == cols - 1 { width - x } else { cell_width };
+                let h = if row == rows - 1 { height - y } else { cell_height };
+                regions.push(BoundingBox { x, y, width: w, height: h });
+            }
+        }
+
+        regions
+    }
+
+    /// Crop to `roi` before running OCR, then translate resulting bounding
+    /// boxes back into full-image coordinates.
+    pub async fn process_region(&self, image: &DynamicImage, roi: BoundingBox) -> Result<OCRResult> {
+        let cropped = image.crop_imm(roi.x, roi.y, roi.width, roi.height);
+        let mut result = self.process_image(&cropped).await?;
+
+        for block in &mut result.text_blocks {
+            block.bounding_box.x += roi.x;
+            block.bounding_box.y += roi.y;
+        }
+
+        result.image_metadata.width = image.width();
+        result.image_metadata.height = image.height();
+
+        Ok(result)
+    }
+
+    /// Run OCR once per preprocessing variant and merge results, keeping the
+    /// highest-confidence block wherever two variants' blocks overlap.
+    pub async fn process_image_ensemble(
+        &self,
+        image: &DynamicImage,
+        variants: &[PreprocessingConfig],
+    ) -> Result<OCRResult> {
+        let start_time = std::time::Instant::now();
+
+        let mut all_blocks: Vec<TextBlock> = Vec::new();
+        for variant in variants {
+            let preprocessor = ImagePreprocessor::new(variant.clone());
+            let processed_image = if variant.enabled {
+                preprocessor.process(image)?
+            } else {
+                image.clone()
+            };
+
+            let blocks = self.engine.extract_text(&processed_image).await?;
+            all_blocks.extend(blocks.into_iter().filter(|b| b.confidence >= self.config.min_confidence));
+        }
+
+        let merged_blocks = Self::merge_overlapping_blocks(all_blocks, 0.5);
+
+        let classified_blocks = if self.config.enable_text_classification {
+            self.classify_text_blocks(merged_blocks)?
+        } else {
+            merged_blocks
+        };
+
+        let structured_content = if self.config.enable_structure_analysis {
+            self.content_analyzer.analyze(&classified_blocks)?
+        } else {
+            StructuredContent::default()
+        };
+
+        let processing_time = start_time.elapsed().as_millis() as u64;
+        let overall_confidence = classified_blocks
+            .iter()
+            .map(|b| b.confidence)
+            .sum::<f32>()
+            / classified_blocks.len().max(1) as f32;
+
+        let detected_language = self.detect_primary_language(&classified_blocks);
+        let (confidence_distribution, low_confidence_ratio) =
+            OCRResult::confidence_stats(&classified_blocks);
 
         Ok(OCRResult {
             text_blocks: classified_blocks,
@@ -166,9 +404,59 @@ impl OCRProcessor {
                 file_size: None,
                 timestamp: Utc::now(),
             },
+            confidence_distribution,
+            low_confidence_ratio,
         })
     }
 
+    /// Deduplicate blocks whose bounding boxes overlap above `iou_threshold`,
+    /// keeping the highest-confidence block from each overlapping cluster.
+    fn merge_overlapping_blocks(mut blocks: Vec<TextBlock>, iou_threshold: f32) -> Vec<TextBlock> {
+        // Highest confidence first so the first block we see in a cluster wins
+        blocks.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut kept: Vec<TextBlock> = Vec::new();
+        for block in blocks {
+            let overlaps_kept = kept
+                .iter()
+                .any(|k| Self::iou(&k.bounding_box, &block.bounding_box) > iou_threshold);
+
+            if !overlaps_kept {
+                kept.push(block);
+            }
+        }
+
+        kept
+    }
+
+    /// Intersection-over-union of two bounding boxes, in [0, 1]
+    fn iou(a: &BoundingBox, b: &BoundingBox) -> f32 {
+        let ax2 = a.x + a.width;
+        let ay2 = a.y + a.height;
+        let bx2 = b.x + b.width;
+        let by2 = b.y + b.height;
+
+        let ix1 = a.x.max(b.x);
+        let iy1 = a.y.max(b.y);
+        let ix2 = ax2.min(bx2);
+        let iy2 = ay2.min(by2);
+
+        if ix2 <= ix1 || iy2 <= iy1 {
+            return 0.0;
+        }
+
+        let intersection = ((ix2 - ix1) * (iy2 - iy1)) as f32;
+        let area_a = (a.width * a.height) as f32;
+        let area_b = (b.width * b.height) as f32;
+        let union = area_a + area_b - intersection;
+
+        if union <= 0.0 {
+            0.0
+        } else {
+            intersection / union
+        }
+    }
+
     fn classify_text_blocks(&self, blocks: Vec<TextBlock>) -> Result<Vec<TextBlock>> {
         let mut classified_blocks = Vec::new();
         
@@ -196,4 +484,48 @@ impl OCRProcessor {
             .map(|(lang, _)| lang)
             .unwrap_or_else(|| "eng".to_string())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_with_confidence(confidence: f32) -> TextBlock {
+        TextBlock {
+            text: "text".to_string(),
+            confidence,
+            bounding_box: BoundingBox { x: 0, y: 0, width: 10, height: 10 },
+            font_info: None,
+            semantic_type: TextType::Unknown,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_confidence_stats_buckets_and_ratio_with_mixed_confidences() {
+        // 3 high-confidence blocks (>= 0.5), 2 low-confidence blocks (< 0.5)
+        let blocks = vec![
+            block_with_confidence(0.95),
+            block_with_confidence(0.92),
+            block_with_confidence(0.55),
+            block_with_confidence(0.3),
+            block_with_confidence(0.1),
+        ];
+
+        let (distribution, low_confidence_ratio) = OCRResult::confidence_stats(&blocks);
+
+        assert_eq!(distribution.len(), 10);
+        assert_eq!(distribution[1], (0.1, 1)); // 0.1
+        assert_eq!(distribution[3], (0.3, 1)); // 0.3
+        assert_eq!(distribution[5], (0.5, 1)); // 0.55
+        assert_eq!(distribution[9], (0.9, 2)); // 0.95, 0.92
+        assert_eq!(low_confidence_ratio, 2.0 / 5.0);
+    }
+
+    #[test]
+    fn test_confidence_stats_on_empty_blocks_is_zero_everywhere() {
+        let (distribution, low_confidence_ratio) = OCRResult::confidence_stats(&[]);
+
+        assert!(distribution.iter().all(|(_, count)| *count == 0));
+        assert_eq!(low_confidence_ratio, 0.0);
+    }
+}
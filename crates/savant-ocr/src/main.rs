@@ -1,7 +1,8 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use image::ImageReader;
-use savant_ocr::{OCRConfig, OCRProcessor};
+use savant_ocr::{OCRConfig, OCRPerformanceMetrics, OCRPreset, OCRProcessor};
+use std::str::FromStr;
 use serde_json;
 use std::path::PathBuf;
 use tokio;
@@ -16,6 +17,12 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+
     /// Extract text from an image file
     Extract {
         /// Path to the image file
@@ -33,20 +40,29 @@ enum Commands {
         /// Languages to detect (comma-separated)
         #[arg(short, long, default_value = "eng")]
         languages: String,
-        
-        /// Minimum confidence threshold
-        #[arg(short, long, default_value = "0.5")]
-        confidence: f32,
-        
-        /// Enable text classification
+
+        /// Minimum confidence threshold (overrides the preset's default)
+        #[arg(short, long)]
+        confidence: Option<f32>,
+
+        /// Enable text classification (on top of whatever the preset already enables)
         #[arg(long)]
         classify: bool,
-        
-        /// Enable structure analysis
+
+        /// Enable structure analysis (on top of whatever the preset already enables)
         #[arg(long)]
         analyze: bool,
-        
-        /// Use fast processing mode (less accurate but much faster)
+
+        /// Speed/accuracy tradeoff: "fast" skips preprocessing and classification for
+        /// low latency; "balanced" (default) runs standard preprocessing with
+        /// classification; "accurate" runs full preprocessing at target DPI with
+        /// classification, structure analysis, and parallel processing, at the cost of
+        /// latency.
+        #[arg(long, default_value = "balanced")]
+        preset: String,
+
+        /// Use fast processing mode (less accurate but much faster). Alias for
+        /// `--preset fast`.
         #[arg(long)]
         fast: bool,
     },
@@ -68,6 +84,21 @@ enum Commands {
         #[arg(short, long)]
         input: Option<PathBuf>,
     },
+
+    /// Benchmark OCR throughput and accuracy over repeated runs
+    Benchmark {
+        /// Path to the image to benchmark against
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Number of times to run OCR on the image
+        #[arg(short = 'n', long, default_value = "10")]
+        iterations: u32,
+
+        /// Plain-text file with the expected extracted text, for character error rate
+        #[arg(short, long)]
+        ground_truth: Option<PathBuf>,
+    },
 }
 
 #[tokio::main]
@@ -75,8 +106,14 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     
     let cli = Cli::parse();
-    
+
+    if let Commands::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return Ok(());
+    }
+
     match cli.command {
+        Commands::Completions { .. } => unreachable!("handled above"),
         Commands::Extract {
             input,
             format,
@@ -85,9 +122,10 @@ async fn main() -> Result<()> {
             confidence,
             classify,
             analyze,
+            preset,
             fast,
         } => {
-            extract_text(input, format, engine, languages, confidence, classify, analyze, fast).await?;
+            extract_text(input, format, engine, languages, confidence, classify, analyze, preset, fast).await?;
         }
         Commands::Process { format, config } => {
             process_from_stdin(format, config).await?;
@@ -95,6 +133,9 @@ async fn main() -> Result<()> {
         Commands::Test { input } => {
             test_ocr(input).await?;
         }
+        Commands::Benchmark { input, iterations, ground_truth } => {
+            benchmark_ocr(input, iterations, ground_truth).await?;
+        }
     }
     
     Ok(())
@@ -105,36 +146,40 @@ async fn extract_text(
     format: String,
     engine: String,
     languages: String,
-    confidence: f32,
+    confidence: Option<f32>,
     classify: bool,
     analyze: bool,
+    preset: String,
     fast: bool,
 ) -> Result<()> {
     // Load image
     let image = ImageReader::open(&input)?
         .decode()
         .map_err(|e| anyhow::anyhow!("Failed to decode image {}: {}", input.display(), e))?;
-    
+
     println!("Loaded image: {}x{} pixels", image.width(), image.height());
-    
-    // Configure OCR
+
+    // `--fast` is an alias for `--preset fast`
+    let preset_name = if fast { "fast" } else { preset.as_str() };
+    let preset = OCRPreset::from_str(preset_name)
+        .map_err(|e| anyhow::anyhow!(e))?;
+    println!("Using preset: {preset_name}");
+
+    // Configure OCR: start from the preset's tuned preprocessing/confidence/
+    // classification/parallelism, then layer on the always-explicit engine/languages
+    // and any additional overrides.
     let languages: Vec<String> = languages.split(',').map(|s| s.trim().to_string()).collect();
     let mut config = OCRConfig {
         engine,
         languages,
-        min_confidence: confidence,
-        enable_text_classification: classify,
-        enable_structure_analysis: analyze,
-        ..Default::default()
+        ..preset.to_ocr_config()
     };
-    
-    // Apply fast mode optimizations
-    if fast {
-        config.preprocessing.enabled = false; // Skip preprocessing for speed
-        config.min_confidence = 0.3; // Lower confidence threshold
-        println!("Fast mode enabled: preprocessing disabled, lower confidence threshold");
+    config.enable_text_classification = config.enable_text_classification || classify;
+    config.enable_structure_analysis = config.enable_structure_analysis || analyze;
+    if let Some(confidence) = confidence {
+        config.min_confidence = confidence;
     }
-    
+
     // Create processor and extract text
     let processor = OCRProcessor::new(config)?;
     let result = processor.process_image(&image).await?;
@@ -222,6 +267,116 @@ async fn test_ocr(input: Option<PathBuf>) -> Result<()> {
             println!("  {}: {} (confidence: {:.2})", i + 1, block.text, block.confidence);
         }
     }
-    
+
     Ok(())
+}
+
+async fn benchmark_ocr(input: PathBuf, iterations: u32, ground_truth: Option<PathBuf>) -> Result<()> {
+    println!("Benchmarking OCR performance on {}...", input.display());
+
+    let image = ImageReader::open(&input)?.decode()?;
+    let processor = OCRProcessor::new(OCRConfig::default())?;
+
+    let mut metrics = OCRPerformanceMetrics::new();
+    let mut durations = Vec::with_capacity(iterations as usize);
+    let mut last_result = None;
+
+    for i in 1..=iterations {
+        let start = std::time::Instant::now();
+        let result = processor.process_image(&image).await?;
+        let elapsed = start.elapsed();
+
+        println!("  iteration {}/{}: {:?}", i, iterations, elapsed);
+        durations.push(elapsed);
+        metrics.record_result(&result, false);
+        last_result = Some(result);
+    }
+
+    durations.sort();
+    let mean_ms =
+        durations.iter().map(|d| d.as_secs_f64() * 1000.0).sum::<f64>() / durations.len() as f64;
+    let p95_index = (((durations.len() as f64) * 0.95).ceil() as usize)
+        .saturating_sub(1)
+        .min(durations.len() - 1);
+    let p95_ms = durations[p95_index].as_secs_f64() * 1000.0;
+
+    println!("\n=== Benchmark Results ===");
+    println!("Iterations: {}", iterations);
+    println!("Mean latency: {:.1}ms", mean_ms);
+    println!("P95 latency: {:.1}ms", p95_ms);
+    println!("Throughput: {:.2} images/sec", 1000.0 / mean_ms);
+    metrics.print_summary();
+
+    if let Some(ground_truth_path) = ground_truth {
+        let expected = std::fs::read_to_string(&ground_truth_path)?;
+        let actual = last_result
+            .expect("at least one iteration runs when iterations > 0")
+            .text_blocks
+            .iter()
+            .map(|block| block.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let cer = character_error_rate(&expected, &actual);
+        println!("Character error rate: {:.2}%", cer * 100.0);
+    }
+
+    Ok(())
+}
+
+/// Character error rate: Levenshtein distance between `actual` and `expected`, divided by
+/// the character count of `expected`. An empty `expected` is a perfect match only if
+/// `actual` is also empty.
+fn character_error_rate(expected: &str, actual: &str) -> f64 {
+    let expected_len = expected.chars().count();
+    if expected_len == 0 {
+        return if actual.is_empty() { 0.0 } else { 1.0 };
+    }
+
+    levenshtein_distance(expected, actual) as f64 / expected_len as f64
+}
+
+/// Minimum-edit-distance (insert/delete/substitute) between two strings, counted in chars.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut prev_diagonal = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let prev_above = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev_diagonal
+            } else {
+                1 + prev_diagonal.min(row[j]).min(row[j - 1])
+            };
+            prev_diagonal = prev_above;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_character_error_rate_is_zero_on_exact_match() {
+        assert_eq!(character_error_rate("hello world", "hello world"), 0.0);
+    }
+
+    #[test]
+    fn test_character_error_rate_counts_edits_against_expected_length() {
+        // "hallo" -> "hello" is one substitution, over 5 expected chars
+        assert_eq!(character_error_rate("hello", "hallo"), 1.0 / 5.0);
+    }
+
+    #[test]
+    fn test_levenshtein_distance_counts_insertions_and_deletions() {
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "abc"), 3);
+        assert_eq!(levenshtein_distance("abc", ""), 3);
+    }
 }
\ No newline at end of file
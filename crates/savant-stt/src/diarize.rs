@@ -0,0 +1,206 @@
+//! Lightweight speaker diarization for transcription segments
+//!
+//! Whisper does not identify speakers, so each [`TranscriptionSegment`] normally
+//! inherits a single speaker from session metadata even when a recording contains
+//! multiple voices. This module clusters segments by simple acoustic features (RMS
+//! energy and zero-crossing rate) as a stand-in for a trained speaker embedding, and
+//! stamps each segment with a `speaker_label` such as `"SPEAKER_00"`. It is a coarse
+//! approximation, not ML-based diarization, but is enough to separate distinct voices
+//! in a short conversation.
+
+use crate::TranscriptionSegment;
+
+/// Acoustic feature vector used in place of a trained speaker embedding.
+#[derive(Debug, Clone)]
+struct SegmentFeatures {
+    rms: f32,
+    zero_crossing_rate: f32,
+}
+
+fn extract_features(samples: &[f32]) -> SegmentFeatures {
+    if samples.is_empty() {
+        return SegmentFeatures {
+            rms: 0.0,
+            zero_crossing_rate: 0.0,
+        };
+    }
+
+    let rms = (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
+
+    let crossings = samples
+        .windows(2)
+        .filter(|w| (w[0] >= 0.0) != (w[1] >= 0.0))
+        .count();
+    let zero_crossing_rate = crossings as f32 / samples.len() as f32;
+
+    SegmentFeatures {
+        rms,
+        zero_crossing_rate,
+    }
+}
+
+fn distance(a: &SegmentFeatures, b: &SegmentFeatures) -> f32 {
+    let d_rms = a.rms - b.rms;
+    let d_zcr = a.zero_crossing_rate - b.zero_crossing_rate;
+    (d_rms * d_rms + d_zcr * d_zcr).sqrt()
+}
+
+/// Cluster `segments` into at most `num_speakers` groups based on the corresponding
+/// slice of `audio_data` (sampled at `sample_rate`), and set each segment's
+/// `speaker_label` to `"SPEAKER_NN"`.
+///
+/// See the module-level docs for the caveat that this uses simple acoustic features,
+/// not a trained speaker embedding.
+pub fn diarize_segments(
+    audio_data: &[f32],
+    sample_rate: u32,
+    num_speakers: usize,
+    segments: &mut [TranscriptionSegment],
+) {
+    if segments.is_empty() || num_speakers == 0 {
+        return;
+    }
+
+    let features: Vec<SegmentFeatures> = segments
+        .iter()
+        .map(|segment| {
+            let start = (segment.start_time * sample_rate as f64) as usize;
+            let end = ((segment.end_time * sample_rate as f64) as usize).min(audio_data.len());
+            if start >= end {
+                extract_features(&[])
+            } else {
+                extract_features(&audio_data[start..end])
+            }
+        })
+        .collect();
+
+    let k = num_speakers.min(segments.len());
+    let assignments = kmeans_assign(&features, k);
+
+    for (segment, cluster) in segments.iter_mut().zip(assignments) {
+        segment.speaker_label = Some(format!("SPEAKER_{:02}", cluster));
+    }
+}
+
+/// Minimal, deterministic k-means over [`SegmentFeatures`]. Initial centroids are
+/// spread evenly across the features sorted by RMS energy rather than chosen randomly,
+/// so the same input always produces the same clustering.
+fn kmeans_assign(features: &[SegmentFeatures], k: usize) -> Vec<usize> {
+    if k <= 1 || features.len() <= 1 {
+        return vec![0; features.len()];
+    }
+
+    let mut order: Vec<usize> = (0..features.len()).collect();
+    order.sort_by(|&a, &b| features[a].rms.partial_cmp(&features[b].rms).unwrap());
+
+    let mut centroids: Vec<SegmentFeatures> = (0..k)
+        .map(|i| {
+            let idx = order[i * (order.len() - 1) / (k - 1)];
+            features[idx].clone()
+        })
+        .collect();
+
+    let mut assignments = vec![0usize; features.len()];
+
+    for _ in 0..10 {
+        for (i, feature) in features.iter().enumerate() {
+            let (best, _) = centroids
+                .iter()
+                .enumerate()
+                .map(|(c, centroid)| (c, distance(feature, centroid)))
+                .fold((0, f32::MAX), |acc, cur| if cur.1 < acc.1 { cur } else { acc });
+            assignments[i] = best;
+        }
+
+        for (c, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<&SegmentFeatures> = features
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, &a)| a == c)
+                .map(|(f, _)| f)
+                .collect();
+            if !members.is_empty() {
+                let rms = members.iter().map(|f| f.rms).sum::<f32>() / members.len() as f32;
+                let zcr = members.iter().map(|f| f.zero_crossing_rate).sum::<f32>()
+                    / members.len() as f32;
+                *centroid = SegmentFeatures {
+                    rms,
+                    zero_crossing_rate: zcr,
+                };
+            }
+        }
+    }
+
+    assignments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Low-amplitude, slowly-oscillating tone standing in for one voice.
+    fn voice_a(num_samples: usize) -> Vec<f32> {
+        (0..num_samples)
+            .map(|i| 0.05 * (i as f32 * 0.05).sin())
+            .collect()
+    }
+
+    /// Higher-amplitude, rapidly-oscillating tone standing in for a second voice.
+    fn voice_b(num_samples: usize) -> Vec<f32> {
+        (0..num_samples)
+            .map(|i| 0.8 * (i as f32 * 0.9).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_diarize_segments_assigns_at_least_two_labels_for_alternating_voices() {
+        let sample_rate = 16000u32;
+        let segment_samples = sample_rate as usize; // 1 second per segment
+
+        let mut audio_data = Vec::new();
+        let mut segments = Vec::new();
+        for i in 0..4 {
+            let chunk = if i % 2 == 0 {
+                voice_a(segment_samples)
+            } else {
+                voice_b(segment_samples)
+            };
+            let start_time = audio_data.len() as f64 / sample_rate as f64;
+            audio_data.extend(chunk);
+            let end_time = audio_data.len() as f64 / sample_rate as f64;
+
+            segments.push(TranscriptionSegment {
+                text: format!("segment {}", i),
+                start_time,
+                end_time,
+                confidence: None,
+                words: None,
+                speaker_label: None,
+                audio_source: None,
+            });
+        }
+
+        diarize_segments(&audio_data, sample_rate, 2, &mut segments);
+
+        let labels: std::collections::HashSet<_> =
+            segments.iter().map(|s| s.speaker_label.clone().unwrap()).collect();
+        assert!(labels.len() >= 2, "expected at least 2 distinct speaker labels, got {:?}", labels);
+    }
+
+    #[test]
+    fn test_diarize_segments_is_noop_with_zero_speakers() {
+        let mut segments = vec![TranscriptionSegment {
+            text: "hi".to_string(),
+            start_time: 0.0,
+            end_time: 1.0,
+            confidence: None,
+            words: None,
+            speaker_label: None,
+            audio_source: None,
+        }];
+
+        diarize_segments(&[0.0; 16000], 16000, 0, &mut segments);
+
+        assert!(segments[0].speaker_label.is_none());
+    }
+}
@@ -9,9 +9,11 @@ use async_trait::async_trait;
 
 pub mod whisper;
 pub mod models;
+pub mod diarize;
 
 pub use whisper::*;
 pub use models::*;
+pub use diarize::*;
 
 /// Speech-to-text configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +26,11 @@ pub struct SttConfig {
     pub enable_timestamps: bool,
     pub enable_word_timestamps: bool,
     pub max_tokens: u32,
+    /// Cluster segments by speaker after transcription and stamp each with a
+    /// `speaker_label` (see [`diarize`]).
+    pub enable_diarization: bool,
+    /// Number of speakers to assume when `enable_diarization` is set.
+    pub diarization_speaker_count: usize,
 }
 
 impl Default for SttConfig {
@@ -37,6 +44,8 @@ impl Default for SttConfig {
             enable_timestamps: true,
             enable_word_timestamps: false,
             max_tokens: 0, // No limit
+            enable_diarization: false,
+            diarization_speaker_count: 2,
         }
     }
 }
@@ -79,6 +88,15 @@ pub struct TranscriptionSegment {
     pub end_time: f64,
     pub confidence: Option<f32>,
     pub words: Option<Vec<WordTimestamp>>,
+    /// Diarized speaker label, e.g. `"SPEAKER_00"`. Set by [`diarize::diarize_segments`]
+    /// when diarization is enabled; `None` otherwise.
+    #[serde(default)]
+    pub speaker_label: Option<String>,
+    /// Per-segment audio source, for dual-capture recordings where mic and system
+    /// audio are transcribed together. Takes priority over [`SessionMetadata::audio_source`]
+    /// when set; `None` falls back to the session-wide value.
+    #[serde(default)]
+    pub audio_source: Option<AudioSource>,
 }
 
 /// Word-level timestamp information
@@ -102,6 +120,16 @@ pub trait SpeechToText {
     /// Transcribe audio file
     async fn transcribe_file(&self, file_path: &str) -> Result<TranscriptionResult>;
 
+    /// Transcribe audio, invoking `on_segment` once per segment in order, then return
+    /// the full result. The Whisper implementation transcribes the whole input first,
+    /// so no segment reaches `on_segment` before transcription of all of it finishes.
+    async fn transcribe_streaming(
+        &self,
+        audio_data: &[f32],
+        sample_rate: u32,
+        on_segment: &mut dyn FnMut(&TranscriptionSegment),
+    ) -> Result<TranscriptionResult>;
+
     /// Get available languages
     fn get_supported_languages(&self) -> Vec<String>;
 
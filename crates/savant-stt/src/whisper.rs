@@ -104,7 +104,18 @@ impl SpeechToText for WhisperProcessor {
         let processing_time = start_time.elapsed().as_millis() as u64;
 
         // Extract results using the state
-        self.extract_results_from_state(&state, processing_time)
+        let mut result = self.extract_results_from_state(&state, processing_time)?;
+
+        if self.config.enable_diarization {
+            crate::diarize::diarize_segments(
+                &prepared_audio,
+                16000,
+                self.config.diarization_speaker_count,
+                &mut result.segments,
+            );
+        }
+
+        Ok(result)
     }
 
     async fn transcribe_file(&self, file_path: &str) -> Result<TranscriptionResult> {
@@ -112,11 +123,24 @@ impl SpeechToText for WhisperProcessor {
 
         // Load audio file
         let audio_sample = load_audio_file(file_path)?;
-        
+
         // Transcribe
         self.transcribe(&audio_sample.data, audio_sample.sample_rate).await
     }
 
+    async fn transcribe_streaming(
+        &self,
+        audio_data: &[f32],
+        sample_rate: u32,
+        on_segment: &mut dyn FnMut(&TranscriptionSegment),
+    ) -> Result<TranscriptionResult> {
+        let result = self.transcribe(audio_data, sample_rate).await?;
+        for segment in &result.segments {
+            on_segment(segment);
+        }
+        Ok(result)
+    }
+
     fn get_supported_languages(&self) -> Vec<String> {
         // Whisper supports many languages
         vec![
@@ -174,6 +198,8 @@ impl WhisperProcessor {
                 end_time,
                 confidence: None,
                 words,
+                speaker_label: None,
+                audio_source: None,
             });
 
             full_text.push_str(&text);
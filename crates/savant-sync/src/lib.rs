@@ -299,20 +299,40 @@ impl MultimodalSyncManager {
         self.synchronize_window(window).await.map(Some)
     }
 
+    /// Walks `[start, end)` in overlapping windows (sized and overlapped per
+    /// [`SyncManagerConfig`]) so correlations near a window's edges also land
+    /// inside the interior of the next window instead of being consistently
+    /// missed. Because the same event pair can then be re-correlated in more
+    /// than one window, correlations are deduplicated across the whole
+    /// timeline by `(video_event_id, audio_event_id, correlation_type)`
+    /// identity before being returned - the first window to find a given
+    /// correlation keeps it.
     pub async fn get_context_timeline(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<SynchronizedContext>> {
         let mut contexts = Vec::new();
         let mut current_time = start;
+        let mut seen_correlations = std::collections::HashSet::new();
+
+        let window_size = self.config.default_window_size_seconds;
+        let overlap = self.config.window_overlap_seconds.min(window_size.saturating_sub(1));
+        let hop = (window_size - overlap).max(1);
 
         while current_time < end {
-            let window = SyncWindow::new(current_time, self.config.default_window_size_seconds, 0);
+            let window = SyncWindow::new(current_time, window_size, overlap);
             if window.end_time > end {
                 break;
             }
 
-            let context = self.synchronize_window(window).await?;
+            let mut context = self.synchronize_window(window).await?;
+            context.correlations.retain(|correlation| {
+                seen_correlations.insert((
+                    correlation.video_event_id.clone(),
+                    correlation.audio_event_id.clone(),
+                    format!("{:?}", correlation.correlation_type),
+                ))
+            });
             contexts.push(context);
 
-            current_time += Duration::seconds(self.config.default_window_size_seconds as i64);
+            current_time += Duration::seconds(hop as i64);
         }
 
         Ok(contexts)
@@ -443,7 +463,86 @@ impl MultimodalSyncManager {
                 suggested_actions: vec![],
             });
         }
-        
+
         Ok(insights)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video_event(id: &str, timestamp: DateTime<Utc>) -> VideoEvent {
+        VideoEvent {
+            event_id: id.to_string(),
+            timestamp,
+            event_type: VideoEventType::FrameCaptured,
+            frame_id: None,
+            metadata: VideoEventMetadata {
+                application_name: None,
+                activity_type: None,
+                text_content: None,
+                ui_elements: vec![],
+                change_score: None,
+            },
+            confidence: 1.0,
+        }
+    }
+
+    fn audio_event(id: &str, timestamp: DateTime<Utc>) -> AudioEvent {
+        AudioEvent {
+            event_id: id.to_string(),
+            timestamp,
+            event_type: AudioEventType::SpeechStarted,
+            segment_id: None,
+            metadata: AudioEventMetadata {
+                speaker_id: None,
+                transcription: None,
+                audio_source: None,
+                volume_level: None,
+                audio_quality_score: None,
+                language: None,
+            },
+            confidence: 1.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_context_timeline_overlaps_windows_and_dedups_correlations() {
+        let config = SyncManagerConfig {
+            default_window_size_seconds: 10,
+            window_overlap_seconds: 4,
+            max_time_offset_ms: 3000,
+            min_correlation_strength: 0.1,
+            enable_predictive_sync: false,
+            max_events_per_window: 100,
+            correlation_algorithms: vec![CorrelationAlgorithm::TemporalProximity],
+        };
+        let manager = MultimodalSyncManager::new(config);
+        let start = Utc::now();
+
+        // Straddles the old non-overlapping window boundary at +10s (would be
+        // split across window [0,10] and [10,20] and never correlated).
+        manager.add_video_event(video_event("v1", start + Duration::seconds(9))).await.unwrap();
+        manager.add_audio_event(audio_event("a1", start + Duration::seconds(11))).await.unwrap();
+
+        // Falls inside two overlapping windows ([12,22] and [18,28]) and must
+        // only be counted once in the deduplicated result.
+        manager.add_video_event(video_event("v2", start + Duration::seconds(20))).await.unwrap();
+        manager.add_audio_event(audio_event("a2", start + Duration::seconds(21))).await.unwrap();
+
+        let contexts = manager
+            .get_context_timeline(start, start + Duration::seconds(28))
+            .await
+            .unwrap();
+
+        let all_correlations: Vec<_> = contexts.iter().flat_map(|c| c.correlations.iter()).collect();
+
+        let v1_a1 = all_correlations.iter().filter(|c| c.video_event_id == "v1" && c.audio_event_id == "a1").count();
+        let v2_a2 = all_correlations.iter().filter(|c| c.video_event_id == "v2" && c.audio_event_id == "a2").count();
+
+        assert_eq!(v1_a1, 1, "boundary-straddling correlation should be found exactly once thanks to overlap");
+        assert_eq!(v2_a2, 1, "correlation present in multiple overlapping windows should be deduplicated to one");
+        assert_eq!(all_correlations.len(), 2);
+    }
 }
\ No newline at end of file
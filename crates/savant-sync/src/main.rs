@@ -20,6 +20,12 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+
     /// Correlate audio and video events from stdin
     Correlate {
         /// Time window size in seconds
@@ -67,9 +73,15 @@ async fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
     
     let cli = Cli::parse();
-    
+
+    if let Commands::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return Ok(());
+    }
+
     match cli.command {
-        Commands::Correlate { 
+        Commands::Completions { .. } => unreachable!("handled above"),
+        Commands::Correlate {
             window_size, 
             min_strength, 
             max_offset, 
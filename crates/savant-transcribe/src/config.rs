@@ -0,0 +1,122 @@
+//! Config file support for `savant-transcribe`
+//!
+//! Lets repeated flags (`--model`, `--language`, `--speaker`, ...) live in a
+//! `savant-transcribe.toml` instead of being passed on every invocation. Command-line
+//! flags always win over values from the file.
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// File-provided defaults for `savant-transcribe`'s CLI options. Every field is
+/// optional; an absent field simply leaves the CLI's own default in place.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TranscribeConfig {
+    pub duration: Option<u32>,
+    pub system: Option<bool>,
+    pub device: Option<String>,
+    pub model: Option<String>,
+    pub language: Option<String>,
+    pub output: Option<PathBuf>,
+    pub format: Option<String>,
+    pub speaker: Option<String>,
+    pub session_id: Option<String>,
+    pub save_audio: Option<PathBuf>,
+}
+
+/// Default location for the config file: `<config dir>/savant-transcribe.toml`.
+pub fn default_config_path() -> Result<PathBuf> {
+    Ok(savant_core::config::get_config_dir()?.join("savant-transcribe.toml"))
+}
+
+/// Load `TranscribeConfig` from `explicit_path` if given, otherwise from the default
+/// config path if it exists. Returns an all-`None` config if neither is present.
+///
+/// If the file specifies a `model` path, it is validated to exist on disk here, so a
+/// typo'd model path is reported immediately rather than after recording audio.
+pub fn load_config(explicit_path: Option<&Path>) -> Result<TranscribeConfig> {
+    let path = match explicit_path {
+        Some(path) => {
+            if !path.exists() {
+                return Err(anyhow!("Config file not found: {}", path.display()));
+            }
+            path.to_path_buf()
+        }
+        None => {
+            let default_path = default_config_path()?;
+            if !default_path.exists() {
+                return Ok(TranscribeConfig::default());
+            }
+            default_path
+        }
+    };
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow!("Failed to read config file {}: {}", path.display(), e))?;
+    let config: TranscribeConfig = toml::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse config file {}: {}", path.display(), e))?;
+
+    if let Some(model) = &config.model {
+        if !Path::new(model).exists() {
+            return Err(anyhow!(
+                "Model path in config file {} does not exist: {}",
+                path.display(),
+                model
+            ));
+        }
+    }
+
+    Ok(config)
+}
+
+/// Resolve a CLI flag against the file config and a hard-coded default, with the CLI
+/// value taking precedence.
+pub fn resolve<T: Clone>(cli_value: Option<T>, file_value: Option<T>, default: T) -> T {
+    cli_value.or(file_value).unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_flag_overrides_config_file_value() {
+        let dir = std::env::temp_dir().join(format!("savant-transcribe-config-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("savant-transcribe.toml");
+        std::fs::write(&config_path, "language = \"es\"\nformat = \"markdown\"\n").unwrap();
+
+        let config = load_config(Some(&config_path)).unwrap();
+        assert_eq!(config.language.as_deref(), Some("es"));
+
+        // CLI explicitly passed --language en: CLI wins over the file's "es".
+        let cli_language = Some("en".to_string());
+        let effective_language = resolve(cli_language, config.language.clone(), "auto".to_string());
+        assert_eq!(effective_language, "en");
+
+        // CLI did not pass --format: file value is used.
+        let effective_format = resolve(None, config.format.clone(), "json".to_string());
+        assert_eq!(effective_format, "markdown");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_config_rejects_missing_model_path() {
+        let dir = std::env::temp_dir().join(format!("savant-transcribe-config-test-badmodel-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("savant-transcribe.toml");
+        std::fs::write(&config_path, "model = \"/nonexistent/ggml-base.en.bin\"\n").unwrap();
+
+        let result = load_config(Some(&config_path));
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_load_config_errors_on_missing_explicit_path() {
+        let missing = PathBuf::from("/nonexistent/path/savant-transcribe.toml");
+        assert!(load_config(Some(&missing)).is_err());
+    }
+}
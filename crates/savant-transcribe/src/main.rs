@@ -6,36 +6,129 @@ use savant_stt::{create_speech_to_text_with_config, SttConfig, markdown, Session
 use anyhow::Result;
 use tracing_subscriber::FmtSubscriber;
 
+mod config;
+
 #[derive(Parser, Debug)]
 #[command(name = "savant-transcribe", about = "Record audio and output a markdown transcript", long_about = None)]
 struct Cli {
-    /// Duration to record in seconds
-    #[arg(short, long, default_value = "10")]
-    duration: u32,
+    /// Duration to record in seconds [default: 10, or the config file's value]
+    #[arg(short, long)]
+    duration: Option<u32>,
     /// Capture system audio instead of microphone
     #[arg(long)]
     system: bool,
     /// Audio device name
     #[arg(long)]
     device: Option<String>,
-    /// Path to Whisper model
-    #[arg(long, default_value = "models/ggml-base.en.bin")]
-    model: String,
+    /// Path to Whisper model [default: models/ggml-base.en.bin, or the config file's value]
+    #[arg(long)]
+    model: Option<String>,
     /// Language to transcribe in (e.g., "en", "zh"). Auto-detects if not specified.
     #[arg(long)]
     language: Option<String>,
     /// Output file. If not provided, prints to stdout
     #[arg(short, long)]
     output: Option<PathBuf>,
-    /// Output format: json or markdown
-    #[arg(long, default_value = "json")]
-    format: String,
+    /// Output format: json, markdown, or ndjson (one segment per line) [default: json, or the config file's value]
+    #[arg(long)]
+    format: Option<String>,
     /// Speaker identifier for this audio source
     #[arg(long)]
     speaker: Option<String>,
     /// Session ID to group related recordings
     #[arg(long)]
     session_id: Option<String>,
+    /// Save the captured audio to this path before transcribing (.wav, or .flac for FLAC)
+    #[arg(long)]
+    save_audio: Option<PathBuf>,
+    /// Path to a savant-transcribe.toml config file providing defaults for the options
+    /// above. Defaults to <config dir>/savant-transcribe.toml if present. CLI flags
+    /// always override values from the file.
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Suppress the recording progress indicator on stderr
+    #[arg(long)]
+    quiet: bool,
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+}
+
+/// Format the periodic stderr line shown while recording, e.g.
+/// `Recording... 3/10s (buffered 3.0s)`.
+fn format_progress_line(elapsed_secs: u64, total_secs: u32, buffered_samples: usize, sample_rate: u32) -> String {
+    let buffered_secs = buffered_samples as f32 / sample_rate.max(1) as f32;
+    format!("\rRecording... {}/{}s (buffered {:.1}s)", elapsed_secs, total_secs, buffered_secs)
+}
+
+/// Write a progress line to `out` unless `quiet` is set. Kept generic over the writer
+/// so the quiet-gating logic is testable without a real terminal; `main` always calls
+/// this with `std::io::stderr()` so stdout (the pipeable transcript output) never sees
+/// progress lines.
+fn write_progress(out: &mut dyn std::io::Write, quiet: bool, line: &str) {
+    if quiet {
+        return;
+    }
+    let _ = write!(out, "{}", line);
+    let _ = out.flush();
+}
+
+/// Write `sample` to `path` as 16kHz mono audio, choosing WAV or FLAC by file extension.
+///
+/// Writing happens before transcription is attempted, so a transcription failure never
+/// loses the recording.
+fn save_audio(sample: &savant_audio::AudioSample, path: &std::path::Path) -> Result<()> {
+    let prepared = savant_audio::AudioConverter::prepare_for_whisper(sample);
+
+    let is_flac = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("flac"))
+        .unwrap_or(false);
+
+    if is_flac {
+        save_flac(&prepared, path)
+    } else {
+        let path_str = path
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("save-audio path is not valid UTF-8: {}", path.display()))?;
+        savant_audio::WavUtils::save_wav(&prepared, path_str)
+    }
+}
+
+/// Serialize `segment` as a single NDJSON line (no trailing newline).
+fn segment_to_ndjson_line(segment: &savant_stt::TranscriptionSegment) -> Option<String> {
+    serde_json::to_string(segment).ok()
+}
+
+/// Encode `sample` as FLAC and write it to `path`.
+fn save_flac(sample: &savant_audio::AudioSample, path: &std::path::Path) -> Result<()> {
+    let pcm: Vec<i32> = sample
+        .data
+        .iter()
+        .map(|&s| (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i32)
+        .collect();
+
+    let source = flacenc::source::MemSource::from_samples(&pcm, sample.channels as usize, 16, sample.sample_rate as usize);
+    let config = flacenc::config::Encoder::default();
+    let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+        .map_err(|e| anyhow::anyhow!("FLAC encoding failed: {:?}", e))?;
+
+    let mut sink = flacenc::bitsink::ByteSink::new();
+    stream
+        .write(&mut sink)
+        .map_err(|e| anyhow::anyhow!("FLAC bitstream write failed: {:?}", e))?;
+
+    std::fs::write(path, sink.as_slice())?;
+    Ok(())
 }
 
 #[tokio::main]
@@ -46,14 +139,36 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    if let Some(Commands::Completions { shell }) = cli.command {
+        savant_core::completions::print_completions::<Cli>(shell);
+        return Ok(());
+    }
+
+    let file_config = config::load_config(cli.config.as_deref())?;
+
+    let duration = config::resolve(cli.duration, file_config.duration, 10);
+    let system = cli.system || file_config.system.unwrap_or(false);
+    let device = cli.device.clone().or_else(|| file_config.device.clone());
+    let model = config::resolve(
+        cli.model.clone(),
+        file_config.model.clone(),
+        "models/ggml-base.en.bin".to_string(),
+    );
+    let language = cli.language.clone().or_else(|| file_config.language.clone());
+    let output = cli.output.clone().or_else(|| file_config.output.clone());
+    let format = config::resolve(cli.format.clone(), file_config.format.clone(), "json".to_string());
+    let speaker = cli.speaker.clone().or_else(|| file_config.speaker.clone());
+    let session_id = cli.session_id.clone().or_else(|| file_config.session_id.clone());
+    let save_audio_path = cli.save_audio.clone().or_else(|| file_config.save_audio.clone());
+
     let mut audio_config = AudioConfig::default();
-    audio_config.capture_system_audio = cli.system;
-    if let Some(dev) = cli.device.clone() {
+    audio_config.capture_system_audio = system;
+    if let Some(dev) = device {
         audio_config.device_id = Some(dev);
     }
 
     let capture = create_audio_capture()?;
-    let mut stream = if cli.system {
+    let mut stream = if system {
         capture.start_system_capture(audio_config.clone()).await?
     } else {
         capture.start_capture(audio_config.clone()).await?
@@ -62,47 +177,85 @@ async fn main() -> Result<()> {
     let mut buffer = AudioBuffer::new(AudioBufferConfig {
         sample_rate: audio_config.sample_rate,
         channels: audio_config.channels,
-        max_duration_seconds: cli.duration as f32,
+        max_duration_seconds: duration as f32,
+        ..Default::default()
     });
 
     let start = Instant::now();
-    while start.elapsed() < Duration::from_secs(cli.duration as u64) {
+    let mut last_progress = Instant::now();
+    while start.elapsed() < Duration::from_secs(duration as u64) {
         if let Some(sample) = stream.receiver.recv().await {
             buffer.push(&sample);
         }
+        if last_progress.elapsed() >= Duration::from_secs(1) {
+            let line = format_progress_line(start.elapsed().as_secs(), duration, buffer.len(), audio_config.sample_rate);
+            write_progress(&mut std::io::stderr(), cli.quiet, &line);
+            last_progress = Instant::now();
+        }
     }
+    write_progress(&mut std::io::stderr(), cli.quiet, "\n");
     stream.stop().await?;
 
     let audio_sample = buffer.get_sample();
 
+    if let Some(save_path) = &save_audio_path {
+        save_audio(&audio_sample, save_path)?;
+    }
+
     let mut stt_cfg = SttConfig::default();
-    stt_cfg.model_path = cli.model.clone();
-    stt_cfg.language = cli.language.clone();
+    stt_cfg.model_path = model;
+    stt_cfg.language = language;
     let mut stt = create_speech_to_text_with_config(stt_cfg.clone())?;
     stt.load_model(&stt_cfg.model_path).await?;
 
-    let mut result = stt
-        .transcribe(&audio_sample.data, audio_sample.sample_rate)
-        .await?;
-
     // Add session metadata
     let session_metadata = SessionMetadata {
-        session_id: cli.session_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+        session_id: session_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
         timestamp: chrono::Utc::now(),
-        audio_source: if cli.system { AudioSource::SystemAudio } else { AudioSource::Microphone },
-        speaker: cli.speaker,
+        audio_source: if system { AudioSource::SystemAudio } else { AudioSource::Microphone },
+        speaker,
         device_info: Some(format!("savant-transcribe-{}", env!("CARGO_PKG_VERSION"))),
     };
-    
+
+    if format == "ndjson" {
+        let mut out_file = match &output {
+            Some(path) => Some(std::fs::File::create(path)?),
+            None => None,
+        };
+
+        stt.transcribe_streaming(&audio_sample.data, audio_sample.sample_rate, &mut |segment| {
+            if let Some(line) = segment_to_ndjson_line(segment) {
+                match out_file.as_mut() {
+                    Some(file) => {
+                        use std::io::Write;
+                        let _ = writeln!(file, "{}", line);
+                    }
+                    None => println!("{}", line),
+                }
+            }
+        })
+        .await?;
+
+        if let Some(path) = &output {
+            println!("Saved transcript to {}", path.display());
+        }
+
+        return Ok(());
+    }
+
+    let mut result = stt
+        .transcribe(&audio_sample.data, audio_sample.sample_rate)
+        .await?;
+
     result.session_metadata = Some(session_metadata);
 
-    let output_content = match cli.format.as_str() {
+    let output_content = match format.as_str() {
         "json" => serde_json::to_string_pretty(&result)?,
         "markdown" | "md" => markdown::format_transcription_markdown(&result, None, chrono::Utc::now()),
-        _ => return Err(anyhow::anyhow!("Unsupported format: {}", cli.format)),
+        _ => return Err(anyhow::anyhow!("Unsupported format: {}", format)),
     };
 
-    if let Some(path) = cli.output {
+    if let Some(path) = output {
         std::fs::write(&path, output_content)?;
         println!("Saved transcript to {}", path.display());
     } else {
@@ -111,3 +264,88 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_progress_line_shows_elapsed_total_and_buffer_fill() {
+        let line = format_progress_line(3, 10, 48000, 16000);
+        assert!(line.contains("3/10s"));
+        assert!(line.contains("3.0s"));
+    }
+
+    #[test]
+    fn test_write_progress_suppressed_when_quiet() {
+        let mut buf: Vec<u8> = Vec::new();
+        write_progress(&mut buf, true, "Recording... 1/10s");
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_write_progress_writes_when_not_quiet() {
+        let mut buf: Vec<u8> = Vec::new();
+        write_progress(&mut buf, false, "Recording... 1/10s");
+        assert_eq!(String::from_utf8(buf).unwrap(), "Recording... 1/10s");
+    }
+
+    #[test]
+    fn test_save_audio_writes_16khz_mono_wav_with_correct_sample_count() {
+        let synthetic = savant_audio::AudioSample {
+            data: vec![0.1, -0.2, 0.3, -0.4, 0.5, -0.1],
+            timestamp: chrono::Utc::now(),
+            sample_rate: 44100,
+            channels: 2,
+            source: None,
+        };
+
+        let path = std::env::temp_dir().join(format!("savant-transcribe-test-{}.wav", std::process::id()));
+
+        save_audio(&synthetic, &path).unwrap();
+
+        let loaded = savant_audio::WavUtils::load_wav(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.sample_rate, 16000);
+        assert_eq!(loaded.channels, 1);
+        assert!(!loaded.data.is_empty());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_segment_to_ndjson_line_produces_two_independently_parseable_lines_in_order() {
+        let segments = vec![
+            savant_stt::TranscriptionSegment {
+                text: "hello".to_string(),
+                start_time: 0.0,
+                end_time: 1.0,
+                confidence: None,
+                words: None,
+                speaker_label: None,
+                audio_source: None,
+            },
+            savant_stt::TranscriptionSegment {
+                text: "world".to_string(),
+                start_time: 1.0,
+                end_time: 2.0,
+                confidence: None,
+                words: None,
+                speaker_label: None,
+                audio_source: None,
+            },
+        ];
+
+        let lines: Vec<String> = segments
+            .iter()
+            .map(|s| segment_to_ndjson_line(s).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        let parsed: Vec<savant_stt::TranscriptionSegment> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed[0].text, "hello");
+        assert_eq!(parsed[1].text, "world");
+    }
+}
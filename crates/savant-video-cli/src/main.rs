@@ -1,16 +1,21 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use savant_db::visual_data::{HighFrequencyFrame, TextExtraction, VisualDataManager};
+use savant_db::TranscriptDatabase;
+use savant_ocr::{OCRConfig, OCRProcessor};
 use savant_video::{
-    create_video_capture, CaptureConfig, FrameMetadata, PrivacyController,
-    PrivacySettings, StorageManager, StorageSettings, VideoFrame, VideoSession,
+    create_video_capture, CaptureConfig, FrameFormat, FrameMetadata, PrivacyController,
+    PrivacySettings, RecordingSchedule, StorageManager, StorageSettings, VideoFrame, VideoSession,
 };
+use savant_vision::{BoundingBox, ElementProperties, ElementType, VisualElement};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio::time::{interval, Duration};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 #[derive(Parser)]
@@ -25,6 +30,12 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+
     /// Start video capture
     Start {
         /// Capture interval in seconds
@@ -128,7 +139,13 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    if let Commands::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return Ok(());
+    }
+
     match cli.command {
+        Commands::Completions { .. } => unreachable!("handled above"),
         Commands::Start {
             interval,
             duration,
@@ -181,6 +198,7 @@ async fn start_capture(
     stealth_mode: bool,
 ) -> Result<()> {
     info!("Starting video capture with interval {}s", interval_seconds);
+    write_pid_file().await?;
 
     // Create capture instance
     let capture = create_video_capture()?;
@@ -192,9 +210,21 @@ async fn start_capture(
     storage.initialize().await?;
 
     // Initialize privacy controller
-    let privacy_settings = PrivacySettings::default();
+    let privacy_settings = PrivacySettings::load().await?;
     let privacy = Arc::new(Mutex::new(PrivacyController::new(privacy_settings)));
 
+    // Initialize OCR and its storage. A missing OCR engine shouldn't stop
+    // capture - frames are just saved without extracted text.
+    let ocr_processor = match OCRProcessor::new(OCRConfig::default()) {
+        Ok(processor) => Some(processor),
+        Err(e) => {
+            warn!("OCR unavailable, captured frames won't have extracted text: {}", e);
+            None
+        }
+    };
+    let visual_db = Arc::new(VisualDataManager::new(TranscriptDatabase::new(None).await?.pool));
+    visual_db.initialize_schema().await?;
+
     // Create session
     let session_id = Uuid::new_v4().to_string();
     let session = VideoSession {
@@ -218,6 +248,7 @@ async fn start_capture(
             enable_full_text_extraction: true,
             enable_real_time_analysis: true,
             buffer_size: 10,
+            frame_format: FrameFormat::Png,
         },
     };
 
@@ -257,13 +288,68 @@ async fn start_capture(
         // Capture screenshot
         match capture.capture_screen().await {
             Ok(screen_capture) => {
+                frame_count += 1;
+                let change_detected = true; // TODO: Implement change detection
+
+                // Run OCR on every persisted frame with detected change, so
+                // sensitive regions can actually be located and blacked out
+                // before the frame touches disk. `processing_interval` only
+                // throttles how often the extracted text itself is stored
+                // (see `should_store_text` below) -- it must never gate this
+                // pass, or frames outside the sampling cadence would be
+                // persisted with nothing to redact against.
+                let ocr_blocks = if session.config.enable_full_text_extraction && change_detected {
+                    match &ocr_processor {
+                        Some(processor) => match processor.process_image(&screen_capture.image).await {
+                            Ok(result) => result.text_blocks,
+                            Err(e) => {
+                                warn!("OCR failed for a captured frame: {}", e);
+                                Vec::new()
+                            }
+                        },
+                        None => Vec::new(),
+                    }
+                } else {
+                    Vec::new()
+                };
+
+                // Flag each detected text block as sensitive or not, then
+                // redact the sensitive ones before the frame ever touches
+                // disk.
+                let detected_elements: Vec<VisualElement> = {
+                    let controller = privacy.lock().await;
+                    ocr_blocks
+                        .iter()
+                        .map(|block| VisualElement {
+                            element_type: ElementType::TextField,
+                            bounding_box: BoundingBox {
+                                x: block.bounding_box.x,
+                                y: block.bounding_box.y,
+                                width: block.bounding_box.width,
+                                height: block.bounding_box.height,
+                                confidence: block.confidence,
+                            },
+                            properties: ElementProperties {
+                                color_scheme: None,
+                                text_content: Some(block.text.clone()),
+                                is_interactive: false,
+                                state: None,
+                                app_context: None,
+                                is_sensitive: controller.is_sensitive_content(Some(&block.text)),
+                            },
+                            confidence: block.confidence,
+                        })
+                        .collect()
+                };
+
+                let mut redacted_image = screen_capture.image.clone();
+                privacy.lock().await.redact(&mut redacted_image, &detected_elements);
+
                 // Convert to PNG bytes
                 let mut png_bytes = Vec::new();
                 {
                     let mut cursor = std::io::Cursor::new(&mut png_bytes);
-                    screen_capture
-                        .image
-                        .write_to(&mut cursor, image::ImageFormat::Png)?;
+                    redacted_image.write_to(&mut cursor, image::ImageFormat::Png)?;
                 }
 
                 // Calculate image hash
@@ -271,19 +357,80 @@ async fn start_capture(
                 hasher.update(&png_bytes);
                 let hash = hex::encode(hasher.finalize());
 
-                // Save frame
-                let file_path = storage.save_frame(&session_id, &png_bytes).await?;
-                frame_count += 1;
+                // Save frame. Full-text extraction implies frames matter for OCR
+                // accuracy (e.g. code), so keep them lossless regardless of
+                // `frame_format` in that case.
+                let file_path = storage
+                    .save_frame_with_context(
+                        &session_id,
+                        &png_bytes,
+                        session.config.frame_format,
+                        session.config.enable_full_text_extraction,
+                        app_name,
+                        None,
+                    )
+                    .await?;
+
+                let hf_frame = HighFrequencyFrame {
+                    timestamp_ms: screen_capture.timestamp.timestamp_millis(),
+                    session_id: session_id.clone(),
+                    frame_hash: hash.clone(),
+                    change_score: 1.0,
+                    file_path: Some(file_path.to_string_lossy().to_string()),
+                    screen_resolution: Some(format!(
+                        "{}x{}",
+                        redacted_image.width(),
+                        redacted_image.height()
+                    )),
+                    active_app: app_info.as_ref().map(|a| a.name.clone()),
+                    processing_flags: 0,
+                };
+                if let Err(e) = visual_db.store_hf_frame(&hf_frame).await {
+                    error!("Failed to store high-frequency frame: {}", e);
+                }
+
+                // Persist the non-sensitive text blocks only -- sensitive ones
+                // were just blacked out of the saved frame, so storing their
+                // plaintext would leak exactly what the redaction hides.
+                // Storage itself is still sampled every `processing_interval`
+                // frames to bound how much text metadata accumulates; the OCR
+                // pass above that drives redaction is not.
+                let should_store_text = frame_count % session.config.processing_interval.max(1) == 0;
+                let mut ocr_text_parts = Vec::new();
+                for (line_id, (block, element)) in ocr_blocks.iter().zip(detected_elements.iter()).enumerate() {
+                    if element.properties.is_sensitive || !should_store_text {
+                        continue;
+                    }
+                    let extraction = TextExtraction {
+                        frame_id: hash.clone(),
+                        word_text: block.text.clone(),
+                        confidence: block.confidence as f64,
+                        bbox_x: block.bounding_box.x as i32,
+                        bbox_y: block.bounding_box.y as i32,
+                        bbox_width: block.bounding_box.width as i32,
+                        bbox_height: block.bounding_box.height as i32,
+                        font_size_estimate: block.font_info.as_ref().and_then(|f| f.size).map(|s| s as f64),
+                        text_type: Some(format!("{:?}", block.semantic_type)),
+                        line_id: line_id as i32,
+                        paragraph_id: 0,
+                    };
+                    if let Err(e) = visual_db.store_text_extraction(&extraction).await {
+                        error!("Failed to store text extraction: {}", e);
+                    }
+                    ocr_text_parts.push(block.text.as_str());
+                }
+                let ocr_text = if ocr_text_parts.is_empty() {
+                    None
+                } else {
+                    Some(ocr_text_parts.join("\n"))
+                };
 
                 // Create frame metadata
                 let frame = VideoFrame {
                     id: Uuid::new_v4().to_string(),
                     timestamp: screen_capture.timestamp,
                     file_path: file_path.clone(),
-                    resolution: (
-                        screen_capture.image.width(),
-                        screen_capture.image.height(),
-                    ),
+                    resolution: (redacted_image.width(), redacted_image.height()),
                     file_size: png_bytes.len() as u64,
                     image_hash: hash,
                     metadata: FrameMetadata {
@@ -291,12 +438,13 @@ async fn start_capture(
                         display_id: screen_capture.display_id,
                         active_application: app_info.as_ref().map(|a| a.name.clone()),
                         window_title: app_info.and_then(|a| a.window_title),
-                        change_detected: true, // TODO: Implement change detection
-                        ocr_text: None,        // TODO: Implement OCR
+                        change_detected,
+                        ocr_text,
                         enhanced_analysis: None,
                         detected_applications: Vec::new(),
                         activity_classification: None,
                         visual_context: None,
+                        frame_format: session.config.frame_format,
                     },
                 };
 
@@ -332,42 +480,222 @@ async fn start_capture(
     storage
         .save_metadata(&session_id, &serde_json::to_value(final_session)?)
         .await?;
+    remove_pid_file().await?;
 
     info!("Video capture stopped. {} frames captured", frame_count);
     Ok(())
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DaemonPid {
+    pid: u32,
+    started_at: chrono::DateTime<chrono::Utc>,
+}
+
+fn pid_file_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("~/.config"))
+        .join("savant-ai")
+        .join("video-capture.pid")
+}
+
+async fn write_pid_file() -> Result<()> {
+    let path = pid_file_path();
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let daemon_pid = DaemonPid {
+        pid: std::process::id(),
+        started_at: chrono::Utc::now(),
+    };
+    tokio::fs::write(&path, serde_json::to_string(&daemon_pid)?).await?;
+    Ok(())
+}
+
+async fn read_pid_file() -> Result<Option<DaemonPid>> {
+    let path = pid_file_path();
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = tokio::fs::read_to_string(&path).await?;
+    Ok(Some(serde_json::from_str(&contents)?))
+}
+
+async fn remove_pid_file() -> Result<()> {
+    let path = pid_file_path();
+    if path.exists() {
+        tokio::fs::remove_file(&path).await?;
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn is_process_alive(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn is_process_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid)])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn terminate_process(pid: u32) -> Result<()> {
+    let status = std::process::Command::new("kill")
+        .args(["-TERM", &pid.to_string()])
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("kill -TERM {} exited with {}", pid, status);
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn terminate_process(pid: u32) -> Result<()> {
+    let status = std::process::Command::new("taskkill")
+        .args(["/PID", &pid.to_string(), "/F"])
+        .status()?;
+    if !status.success() {
+        anyhow::bail!("taskkill /PID {} exited with {}", pid, status);
+    }
+    Ok(())
+}
+
 async fn stop_capture() -> Result<()> {
-    // TODO: Implement daemon stop via PID file
-    println!("Stopping video capture daemon...");
+    match read_pid_file().await? {
+        Some(daemon_pid) if is_process_alive(daemon_pid.pid) => {
+            terminate_process(daemon_pid.pid)?;
+            remove_pid_file().await?;
+            println!("Stopped video capture daemon (pid {})", daemon_pid.pid);
+        }
+        Some(_) => {
+            remove_pid_file().await?;
+            println!("Video capture daemon not running (removed stale PID file)");
+        }
+        None => {
+            println!("Video capture daemon not running");
+        }
+    }
     Ok(())
 }
 
 async fn get_status() -> Result<()> {
-    // TODO: Check daemon status via PID file
-    let status = json!({
-        "running": false,
-        "message": "Video capture daemon not running"
-    });
+    let status = match read_pid_file().await? {
+        Some(daemon_pid) if is_process_alive(daemon_pid.pid) => {
+            let uptime_seconds = (chrono::Utc::now() - daemon_pid.started_at).num_seconds().max(0);
+            json!({
+                "running": true,
+                "pid": daemon_pid.pid,
+                "started_at": daemon_pid.started_at,
+                "uptime_seconds": uptime_seconds,
+            })
+        }
+        Some(_) => {
+            remove_pid_file().await?;
+            json!({
+                "running": false,
+                "message": "Video capture daemon not running (removed stale PID file)"
+            })
+        }
+        None => json!({
+            "running": false,
+            "message": "Video capture daemon not running"
+        }),
+    };
     println!("{}", serde_json::to_string_pretty(&status)?);
     Ok(())
 }
 
-async fn extract_text(_since: Option<String>, _limit: Option<usize>) -> Result<()> {
-    // TODO: Implement OCR extraction
-    println!("OCR extraction not yet implemented");
+/// Resolve a `--since` phrase (e.g. `"yesterday"`, `"1 hour ago"`) via the
+/// same entity extractor the natural-language query parser uses, defaulting
+/// to "since the beginning of time" when no phrase is given.
+fn resolve_since(since: Option<&str>) -> (i64, i64) {
+    let now = chrono::Utc::now();
+    let range = since.and_then(|phrase| {
+        savant_db::natural_query::EntityExtractor::new().resolve_date_entity(phrase, now)
+    });
+    match range {
+        Some((start, end)) => (start.timestamp_millis(), end.timestamp_millis()),
+        None => (0, now.timestamp_millis()),
+    }
+}
+
+async fn extract_text(since: Option<String>, limit: Option<usize>) -> Result<()> {
+    let visual_db = VisualDataManager::new(TranscriptDatabase::new(None).await?.pool);
+    visual_db.initialize_schema().await?;
+
+    let (start_ms, end_ms) = resolve_since(since.as_deref());
+    let mut extractions = visual_db.search_text_content("", start_ms, end_ms).await?;
+    if let Some(limit) = limit {
+        extractions.truncate(limit);
+    }
+
+    if extractions.is_empty() {
+        println!("No extracted text found in that time range.");
+        return Ok(());
+    }
+
+    for extraction in extractions {
+        println!("[{}] {}", extraction.frame_id, extraction.word_text);
+    }
     Ok(())
 }
 
 async fn list_captures(limit: usize) -> Result<()> {
-    // TODO: List captures from storage
-    println!("Listing {} most recent captures:", limit);
+    let visual_db = VisualDataManager::new(TranscriptDatabase::new(None).await?.pool);
+    visual_db.initialize_schema().await?;
+
+    let frames = visual_db
+        .get_frames_in_range(0, chrono::Utc::now().timestamp_millis(), limit as i64)
+        .await?;
+
+    if frames.is_empty() {
+        println!("No captures found.");
+        return Ok(());
+    }
+
+    println!("{:<24} {:<20} {:>10}  {}", "Timestamp", "Application", "Size", "Path");
+    for frame in frames {
+        let timestamp_ms = frame["timestamp_ms"].as_i64().unwrap_or(0);
+        let timestamp = chrono::DateTime::from_timestamp_millis(timestamp_ms)
+            .map(|ts| ts.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let app = frame["active_app"].as_str().unwrap_or("unknown");
+        let path = frame["file_path"].as_str().unwrap_or("");
+        let size = match tokio::fs::metadata(path).await {
+            Ok(metadata) => format!("{} KB", metadata.len() / 1024),
+            Err(_) => "-".to_string(),
+        };
+        println!("{:<24} {:<20} {:>10}  {}", timestamp, app, size, path);
+    }
     Ok(())
 }
 
 async fn search_captures(query: &str, limit: usize) -> Result<()> {
-    // TODO: Search captures using database
-    println!("Searching for '{}' (limit: {})", query, limit);
+    let visual_db = VisualDataManager::new(TranscriptDatabase::new(None).await?.pool);
+    visual_db.initialize_schema().await?;
+
+    let mut extractions = visual_db
+        .search_text_content(query, 0, chrono::Utc::now().timestamp_millis())
+        .await?;
+    extractions.truncate(limit);
+
+    if extractions.is_empty() {
+        println!("No matches for '{}'.", query);
+        return Ok(());
+    }
+
+    for extraction in extractions {
+        println!("[{}] {}", extraction.frame_id, extraction.word_text);
+    }
     Ok(())
 }
 
@@ -376,16 +704,22 @@ async fn configure_privacy(
     block_app: Option<String>,
     unblock_app: Option<String>,
 ) -> Result<()> {
-    // TODO: Update privacy configuration
+    let mut settings = PrivacySettings::load().await?;
+
     if let Some(schedule) = schedule {
+        settings.recording_schedule = Some(RecordingSchedule::parse(&schedule)?);
         println!("Setting recording schedule: {}", schedule);
     }
     if let Some(app) = block_app {
+        settings.blocked_applications.insert(app.clone());
         println!("Blocking application: {}", app);
     }
     if let Some(app) = unblock_app {
+        settings.blocked_applications.remove(&app);
         println!("Unblocking application: {}", app);
     }
+
+    settings.save().await?;
     Ok(())
 }
 
@@ -402,13 +736,77 @@ async fn cleanup_old_captures(days: u32) -> Result<()> {
 }
 
 async fn export_session(session_id: &str, format: &str, output: Option<PathBuf>) -> Result<()> {
-    // TODO: Export session data
-    println!(
-        "Exporting session {} in {} format",
-        session_id, format
-    );
-    if let Some(output_path) = output {
-        println!("Output directory: {}", output_path.display());
+    let visual_db = VisualDataManager::new(TranscriptDatabase::new(None).await?.pool);
+    visual_db.initialize_schema().await?;
+
+    let frames = visual_db.get_frames_by_session(session_id).await?;
+    if frames.is_empty() {
+        anyhow::bail!("no frames found for session '{}'", session_id);
     }
+
+    let output_dir = output.unwrap_or_else(|| PathBuf::from(format!("session-{}-export", session_id)));
+    tokio::fs::create_dir_all(&output_dir).await?;
+
+    match format {
+        "json" => {
+            let mut frame_records = Vec::with_capacity(frames.len());
+            for frame in frames {
+                let frame_hash = frame["frame_hash"].as_str().unwrap_or_default();
+                let text_extractions = visual_db.get_text_extractions_for_frame(frame_hash).await?;
+                frame_records.push(json!({
+                    "frame": frame,
+                    "ocr_text": text_extractions,
+                }));
+            }
+
+            let export = json!({
+                "session_id": session_id,
+                "frame_count": frame_records.len(),
+                "frames": frame_records,
+            });
+
+            let export_path = output_dir.join(format!("session-{}.json", session_id));
+            tokio::fs::write(&export_path, serde_json::to_string_pretty(&export)?).await?;
+            println!("Exported session {} ({} frames) to {}", session_id, frame_records.len(), export_path.display());
+        }
+        "frames" => {
+            let mut manifest = Vec::with_capacity(frames.len());
+            for frame in frames {
+                let frame_hash = frame["frame_hash"].as_str().unwrap_or_default();
+                let src_path = frame["file_path"].as_str();
+
+                let copied_as = match src_path {
+                    Some(src) => {
+                        let file_name = PathBuf::from(src)
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_else(|| format!("{}.png", frame_hash));
+                        let dest = output_dir.join(&file_name);
+                        match tokio::fs::copy(src, &dest).await {
+                            Ok(_) => Some(file_name),
+                            Err(e) => {
+                                warn!("Failed to copy frame {} from {}: {}", frame_hash, src, e);
+                                None
+                            }
+                        }
+                    }
+                    None => None,
+                };
+
+                manifest.push(json!({
+                    "frame_hash": frame_hash,
+                    "timestamp_ms": frame["timestamp_ms"],
+                    "active_app": frame["active_app"],
+                    "file": copied_as,
+                }));
+            }
+
+            let manifest_path = output_dir.join("manifest.json");
+            tokio::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?).await?;
+            println!("Exported session {} ({} frames) to {}", session_id, manifest.len(), output_dir.display());
+        }
+        other => anyhow::bail!("unsupported export format '{}' (expected 'json' or 'frames')", other),
+    }
+
     Ok(())
 }
@@ -1,16 +1,20 @@
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand};
+use serde::Serialize;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::time::{interval, Duration};
-use tracing::{error, info, warn};
+use tokio::time::Duration;
+use tracing::{debug, error, info, warn};
+
+mod watcher;
 
 use savant_core::config::Config;
-use savant_db::{TranscriptDatabase, visual_data::{VisualDataManager, VideoQuery}};
+use savant_db::{TranscriptDatabase, visual_data::{VisualDataManager, VideoQuery, HighFrequencyFrame}};
 use savant_video::{
-    CaptureConfig, VideoProcessor, ProcessingCommand, ProcessingEvent,
-    processor::{batch_process_existing_files, create_processing_pipeline}
+    CaptureConfig, VideoProcessor, ProcessingCommand, ProcessingEvent, ChangeDetector, ChangeDetectorConfig,
+    CompressedFrame,
+    processor::{batch_process_existing_files, create_processing_pipeline, BatchProgress}
 };
 
 #[derive(Parser)]
@@ -44,8 +48,13 @@ enum Commands {
         /// Database path
         #[arg(short, long)]
         db_path: Option<PathBuf>,
+
+        /// Emit a compact JSON line to stdout for each processed frame, so the
+        /// daemon can be composed with other UNIX tools (logs stay on stderr)
+        #[arg(long)]
+        emit_json: bool,
     },
-    
+
     /// Batch process existing PNG files
     Batch {
         /// Directory containing PNG files
@@ -136,14 +145,15 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Daemon { 
-            input_dir, 
-            interval: interval_secs, 
-            enable_ocr, 
-            enable_vision, 
-            db_path 
+        Commands::Daemon {
+            input_dir,
+            interval: interval_secs,
+            enable_ocr,
+            enable_vision,
+            db_path,
+            emit_json,
         } => {
-            run_daemon(input_dir, interval_secs, enable_ocr, enable_vision, db_path).await
+            run_daemon(input_dir, interval_secs, enable_ocr, enable_vision, db_path, emit_json).await
         }
         Commands::Batch { 
             input_dir, 
@@ -181,6 +191,7 @@ async fn run_daemon(
     enable_ocr: bool,
     enable_vision: bool,
     db_path: Option<PathBuf>,
+    emit_json: bool,
 ) -> Result<()> {
     info!("Starting video processing daemon");
     info!("  Input directory: {}", input_dir.display());
@@ -194,7 +205,7 @@ async fn run_daemon(
 
     // Create session
     let config = CaptureConfig {
-        interval_seconds: interval_secs as u32,
+        interval_milliseconds: interval_secs as u32 * 1000,
         continuous_mode: true,
         auto_compress: true,
         max_resolution: Some((1400, 1050)),
@@ -210,40 +221,85 @@ async fn run_daemon(
     let (cmd_sender, mut event_receiver, _handle) = create_processing_pipeline(config.clone())?;
 
     // Start file monitoring
+    let visual_db = Arc::new(visual_db);
     let input_dir_clone = input_dir.clone();
     let cmd_sender_clone = cmd_sender.clone();
+    let change_detection_threshold = config.change_detection_threshold;
+    let visual_db_for_monitoring = visual_db.clone();
+    let monitoring_session_id = session_id.clone();
+    let mut new_frame_rx = watcher::watch_for_new_frames(input_dir_clone, Duration::from_secs(interval_secs));
     let monitoring_task = tokio::spawn(async move {
-        let mut interval = interval(Duration::from_secs(interval_secs));
-        let mut processed_files = std::collections::HashSet::new();
+        let mut change_detector = ChangeDetector::new(ChangeDetectorConfig::default());
 
-        loop {
-            interval.tick().await;
-            
-            match scan_for_new_files(&input_dir_clone, &mut processed_files).await {
-                Ok(new_files) => {
-                    for (file_path, image_data) in new_files {
-                        let frame = create_frame_from_file(&file_path, &session_id, &image_data).await;
-                        let _ = cmd_sender_clone.send(ProcessingCommand::ProcessFrame {
-                            frame,
-                            image_data,
-                        }).await;
-                    }
+        while let Some(file_path) = new_frame_rx.recv().await {
+            let image_data = match tokio::fs::read(&file_path).await {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!("Failed to read new frame {}: {}", file_path.display(), e);
+                    continue;
+                }
+            };
+
+            let mut frame = create_frame_from_file(&file_path, &monitoring_session_id, &image_data).await;
+
+            match visual_db_for_monitoring.find_frame_by_hash(&frame.image_hash).await {
+                Ok(Some(_)) => {
+                    debug!("Skipping byte-identical frame {}", file_path.display());
+                    continue;
                 }
+                Ok(None) => {}
                 Err(e) => {
-                    error!("Error scanning for files: {}", e);
+                    warn!("Dedup lookup failed for {}: {}", file_path.display(), e);
                 }
             }
+
+            let change_score = match change_detector
+                .detect_changes(frame.clone(), image_data.clone(), None)
+                .await
+            {
+                Ok(result) => result.change_score,
+                Err(e) => {
+                    warn!("Change detection failed for {}: {}, treating as changed", file_path.display(), e);
+                    1.0
+                }
+            };
+
+            let hf_frame = HighFrequencyFrame {
+                timestamp_ms: frame.timestamp.timestamp_millis(),
+                session_id: monitoring_session_id.clone(),
+                frame_hash: frame.image_hash.clone(),
+                change_score: change_score as f64,
+                file_path: Some(file_path.to_string_lossy().to_string()),
+                screen_resolution: Some(format!("{}x{}", frame.resolution.0, frame.resolution.1)),
+                active_app: None,
+                processing_flags: 0,
+            };
+            if let Err(e) = visual_db_for_monitoring.store_hf_frame(&hf_frame).await {
+                error!("Failed to store high-frequency frame: {}", e);
+            }
+
+            if change_score <= change_detection_threshold {
+                continue;
+            }
+
+            frame.metadata.change_detected = true;
+            let _ = cmd_sender_clone.send(ProcessingCommand::ProcessFrame {
+                frame,
+                image_data,
+            }).await;
         }
     });
 
     // Handle processing events
-    let visual_db = Arc::new(visual_db);
     let event_handling_task = {
         let visual_db = visual_db.clone();
         tokio::spawn(async move {
             while let Some(event) = event_receiver.recv().await {
                 match event {
                     ProcessingEvent::FrameProcessed(compressed_frame) => {
+                        if emit_json {
+                            println!("{}", serde_json::to_string(&frame_json_record(&compressed_frame)).unwrap());
+                        }
                         if let Err(e) = visual_db.store_compressed_frame(&compressed_frame).await {
                             error!("Failed to store frame: {}", e);
                         } else {
@@ -302,9 +358,25 @@ async fn run_batch_processing(
     };
 
     let progress_callback = if verbose {
-        Some(Box::new(move |current: usize, total: usize| {
-            println!("Processing: {}/{} ({:.1}%)", current, total, (current as f32 / total as f32) * 100.0);
-        }) as Box<dyn Fn(usize, usize) + Send + Sync>)
+        Some(Box::new(move |event: BatchProgress| match event {
+            BatchProgress::Started { total } => {
+                println!("Processing {} files...", total);
+            }
+            BatchProgress::FileDone { path, ocr_chars, tasks_detected } => {
+                println!(
+                    "  done: {} ({} OCR chars, {} tasks detected)",
+                    path.display(),
+                    ocr_chars,
+                    tasks_detected
+                );
+            }
+            BatchProgress::FileError { path, error } => {
+                println!("  failed: {} ({})", path.display(), error);
+            }
+            BatchProgress::Finished { succeeded, failed } => {
+                println!("Processing complete: {} succeeded, {} failed", succeeded, failed);
+            }
+        }) as Box<dyn Fn(BatchProgress) + Send + Sync>)
     } else {
         None
     };
@@ -453,69 +525,88 @@ async fn show_stats(db_path: Option<PathBuf>) -> Result<()> {
 
 async fn run_cleanup(db_path: Option<PathBuf>, keep_days: i64, dry_run: bool) -> Result<()> {
     let db = TranscriptDatabase::new(db_path).await?;
-    
+
     let cutoff_date = Utc::now() - chrono::Duration::days(keep_days);
-    
+
     info!("Cleaning up data older than {} days (before {})", keep_days, cutoff_date);
-    
+
     if dry_run {
         info!("DRY RUN - no data will be deleted");
     }
 
-    // For now, just show what would be deleted
-    let query = VideoQuery {
-        end_time: Some(cutoff_date),
-        limit: Some(1000),
-        ..Default::default()
-    };
-
     let visual_db = VisualDataManager::new(db.pool.clone());
-    let old_frames = visual_db.query_frames(&query).await?;
-    
-    println!("Found {} frames to clean up", old_frames.len());
-    
-    if !dry_run && !old_frames.is_empty() {
-        // TODO: Implement actual cleanup logic
-        warn!("Cleanup implementation not yet complete");
+    let stats = visual_db.cleanup_old_frames(cutoff_date.timestamp_millis(), dry_run).await?;
+
+    if dry_run {
+        println!(
+            "Found {} frames to clean up ({} bytes)",
+            stats.frames_deleted, stats.bytes_reclaimed
+        );
+    } else {
+        println!(
+            "Cleaned up {} frames, reclaimed {} bytes",
+            stats.frames_deleted, stats.bytes_reclaimed
+        );
     }
 
     Ok(())
 }
 
-async fn scan_for_new_files(
-    input_dir: &PathBuf,
-    processed_files: &mut std::collections::HashSet<PathBuf>,
-) -> Result<Vec<(PathBuf, Vec<u8>)>> {
-    let mut new_files = Vec::new();
-    
-    if !input_dir.exists() {
-        return Ok(new_files);
-    }
 
-    let mut dir_reader = tokio::fs::read_dir(input_dir).await?;
-    
-    while let Some(entry) = dir_reader.next_entry().await? {
-        let path = entry.path();
-        
-        if path.is_file() 
-            && path.extension().and_then(|s| s.to_str()) == Some("png")
-            && !path.file_name().unwrap_or_default().to_string_lossy().contains("_compressed")
-            && !processed_files.contains(&path) {
-            
-            // Read file data
-            match tokio::fs::read(&path).await {
-                Ok(data) => {
-                    new_files.push((path.clone(), data));
-                    processed_files.insert(path);
-                }
-                Err(e) => {
-                    warn!("Failed to read file {}: {}", path.display(), e);
-                }
-            }
-        }
+/// Compact per-frame summary emitted as a single JSON line to stdout when
+/// `--emit-json` is passed to `daemon`, so the daemon's output can be piped
+/// into other UNIX tools (`jq`, etc.) without scraping log text.
+#[derive(Debug, Serialize)]
+struct FrameJsonRecord {
+    frame_id: String,
+    timestamp: DateTime<Utc>,
+    app: Option<String>,
+    ocr_text_summary: Option<String>,
+    detected_tasks: Vec<String>,
+}
+
+const OCR_SUMMARY_MAX_CHARS: usize = 200;
+
+fn frame_json_record(frame: &CompressedFrame) -> FrameJsonRecord {
+    let processing_result = frame.processing_result.as_ref();
+
+    let app = processing_result
+        .and_then(|r| r.application_context.primary_application.as_ref())
+        .and_then(|app| app.app_name.clone())
+        .or_else(|| frame.original_frame.metadata.active_application.clone());
+
+    let ocr_text_summary = processing_result
+        .and_then(|r| r.ocr_result.as_ref())
+        .map(|ocr| {
+            let text = ocr
+                .text_blocks
+                .iter()
+                .map(|block| block.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            truncate_chars(&text, OCR_SUMMARY_MAX_CHARS)
+        })
+        .filter(|summary| !summary.is_empty());
+
+    let detected_tasks = processing_result
+        .map(|r| r.interaction_opportunities.iter().map(|o| o.description.clone()).collect())
+        .unwrap_or_default();
+
+    FrameJsonRecord {
+        frame_id: frame.original_frame.id.clone(),
+        timestamp: frame.original_frame.timestamp,
+        app,
+        ocr_text_summary,
+        detected_tasks,
     }
+}
 
-    Ok(new_files)
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        text.chars().take(max_chars).collect()
+    }
 }
 
 async fn create_frame_from_file(
@@ -533,18 +624,163 @@ async fn create_frame_from_file(
         file_path: file_path.clone(),
         resolution: (image.width(), image.height()),
         file_size: image_data.len() as u64,
-        image_hash: format!("{:x}", md5::compute(image_data)),
+        image_hash: savant_video::calculate_sha256_hash(image_data),
         metadata: savant_video::FrameMetadata {
             session_id: session_id.to_string(),
             display_id: None,
             active_application: None,
             window_title: None,
-            change_detected: true,
+            change_detected: false,
             ocr_text: None,
             enhanced_analysis: None,
             detected_applications: Vec::new(),
             activity_classification: None,
             visual_context: None,
+            frame_format: savant_video::FrameFormat::Png,
         },
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use savant_ocr::{BoundingBox as OcrBoundingBox, OCRResult, StructuredContent, TextBlock};
+    use savant_vision::{AppType, BoundingBox as VisionBoundingBox, DetectedApp, WindowState};
+    use savant_video::{InteractionOpportunity, VideoAnalysisResult};
+    use savant_video::analyzer::{ApplicationContext, OpportunityType, ProcessingStats, TextSummary, Urgency};
+
+    fn text_block(text: &str) -> TextBlock {
+        TextBlock {
+            text: text.to_string(),
+            confidence: 0.95,
+            bounding_box: OcrBoundingBox { x: 0, y: 0, width: 10, height: 10 },
+            font_info: None,
+            semantic_type: savant_ocr::TextType::DocumentContent,
+            language: None,
+        }
+    }
+
+    fn detected_app(name: &str) -> DetectedApp {
+        DetectedApp {
+            app_type: AppType::Unknown,
+            app_name: Some(name.to_string()),
+            confidence: 0.9,
+            visual_indicators: Vec::new(),
+            screen_region: VisionBoundingBox { x: 0, y: 0, width: 100, height: 100, confidence: 0.9 },
+            window_state: WindowState::Focused,
+        }
+    }
+
+    fn sample_video_frame() -> savant_video::VideoFrame {
+        savant_video::VideoFrame {
+            id: "frame-123".to_string(),
+            timestamp: Utc::now(),
+            file_path: PathBuf::from("/tmp/frame.png"),
+            resolution: (100, 100),
+            file_size: 1000,
+            image_hash: "deadbeef".to_string(),
+            metadata: savant_video::FrameMetadata::default(),
+        }
+    }
+
+    fn sample_compressed_frame() -> CompressedFrame {
+        let frame = sample_video_frame();
+
+        let ocr_text_blocks = vec![text_block("fn main() {"), text_block("println!(\"hi\");")];
+        let (ocr_confidence_distribution, ocr_low_confidence_ratio) =
+            OCRResult::confidence_stats(&ocr_text_blocks);
+        let processing_result = VideoAnalysisResult {
+            ocr_result: Some(OCRResult {
+                text_blocks: ocr_text_blocks,
+                structured_content: StructuredContent {
+                    code_blocks: Vec::new(),
+                    ui_elements: Vec::new(),
+                    chat_messages: Vec::new(),
+                    document_structure: None,
+                    browser_content: None,
+                    ide_context: None,
+                    meeting_context: None,
+                },
+                overall_confidence: 0.9,
+                processing_time_ms: 12,
+                detected_language: "en".to_string(),
+                image_metadata: savant_ocr::ImageMetadata {
+                    width: 100,
+                    height: 100,
+                    format: "png".to_string(),
+                    file_size: None,
+                    timestamp: Utc::now(),
+                },
+                confidence_distribution: ocr_confidence_distribution,
+                low_confidence_ratio: ocr_low_confidence_ratio,
+            }),
+            screen_analysis: None,
+            application_context: ApplicationContext {
+                primary_application: Some(detected_app("VSCode")),
+                secondary_applications: Vec::new(),
+                browser_context: None,
+                ide_context: None,
+                meeting_context: None,
+                productivity_context: None,
+            },
+            text_summary: TextSummary {
+                total_text_blocks: 2,
+                code_blocks: 1,
+                ui_elements: 0,
+                document_content: 1,
+                chat_messages: 0,
+                email_content: 0,
+                dominant_language: "en".to_string(),
+                key_phrases: Vec::new(),
+                technical_terms: Vec::new(),
+            },
+            interaction_opportunities: vec![InteractionOpportunity {
+                opportunity_type: OpportunityType::CodingAssistance,
+                description: "Fix the missing semicolon".to_string(),
+                confidence: 0.8,
+                suggested_action: "Suggest a fix".to_string(),
+                context: "main.rs".to_string(),
+                urgency: Urgency::Medium,
+            }],
+            processing_stats: ProcessingStats {
+                total_processing_time_ms: 12,
+                ocr_time_ms: Some(12),
+                vision_analysis_time_ms: None,
+                context_analysis_time_ms: 0,
+                opportunity_detection_time_ms: 0,
+            },
+        };
+
+        CompressedFrame {
+            original_frame: frame,
+            compressed_path: PathBuf::from("/tmp/frame_compressed.png"),
+            compression_ratio: 2.0,
+            original_size_bytes: 1000,
+            compressed_size_bytes: 500,
+            processing_result: Some(processing_result),
+        }
+    }
+
+    #[test]
+    fn test_frame_json_record_emits_valid_json_line_with_expected_fields() {
+        let frame = sample_compressed_frame();
+        let record = frame_json_record(&frame);
+        let line = serde_json::to_string(&record).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&line).expect("emitted line must be valid JSON");
+        assert_eq!(parsed["frame_id"], "frame-123");
+        assert_eq!(parsed["app"], "VSCode");
+        assert_eq!(parsed["ocr_text_summary"], "fn main() { println!(\"hi\");");
+        assert_eq!(parsed["detected_tasks"][0], "Fix the missing semicolon");
+    }
+
+    #[test]
+    fn test_frame_json_record_handles_missing_processing_result() {
+        let mut frame = sample_compressed_frame();
+        frame.processing_result = None;
+
+        let record = frame_json_record(&frame);
+        assert!(record.ocr_text_summary.is_none());
+        assert!(record.detected_tasks.is_empty());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,127 @@
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, error, warn};
+
+fn is_new_frame(path: &Path) -> bool {
+    path.extension().and_then(|s| s.to_str()) == Some("png")
+        && !path.file_name().unwrap_or_default().to_string_lossy().contains("_compressed")
+}
+
+/// Watch `input_dir` for newly created PNG frames, sending each path exactly
+/// once on the returned channel as it appears.
+///
+/// Prefers a native filesystem watcher (inotify/FSEvents/etc.) and falls back
+/// to polling `input_dir` every `poll_interval` if the watcher fails to start -
+/// some platforms and filesystems (network shares, certain containers) don't
+/// deliver create events reliably. In polling mode, PNGs already in `input_dir`
+/// when polling starts are also reported, on the first poll.
+pub fn watch_for_new_frames(input_dir: PathBuf, poll_interval: Duration) -> mpsc::Receiver<PathBuf> {
+    let (tx, rx) = mpsc::channel(256);
+
+    tokio::spawn(async move {
+        match start_notify_watcher(&input_dir, tx.clone()) {
+            Ok(watcher) => {
+                // Keep the watcher alive for as long as anyone is still
+                // receiving; its drop guard stops the background thread.
+                tx.closed().await;
+                drop(watcher);
+            }
+            Err(e) => {
+                warn!("Native filesystem watcher unavailable ({}), falling back to polling", e);
+                poll_for_new_frames(input_dir, poll_interval, tx).await;
+            }
+        }
+    });
+
+    rx
+}
+
+fn start_notify_watcher(input_dir: &Path, tx: mpsc::Sender<PathBuf>) -> Result<RecommendedWatcher> {
+    std::fs::create_dir_all(input_dir).context("creating input directory")?;
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        let event = match res {
+            Ok(event) => event,
+            Err(e) => {
+                error!("Filesystem watch error: {}", e);
+                return;
+            }
+        };
+
+        if !matches!(event.kind, EventKind::Create(_)) {
+            return;
+        }
+
+        for path in event.paths {
+            if is_new_frame(&path) && tx.blocking_send(path.clone()).is_err() {
+                debug!("Frame watcher channel closed, dropping event for {}", path.display());
+            }
+        }
+    })?;
+
+    watcher.watch(input_dir, RecursiveMode::NonRecursive)?;
+
+    Ok(watcher)
+}
+
+async fn poll_for_new_frames(input_dir: PathBuf, poll_interval: Duration, tx: mpsc::Sender<PathBuf>) {
+    let mut processed = HashSet::new();
+    let mut interval = tokio::time::interval(poll_interval);
+
+    loop {
+        interval.tick().await;
+
+        let Ok(mut dir_reader) = tokio::fs::read_dir(&input_dir).await else {
+            continue;
+        };
+
+        while let Ok(Some(entry)) = dir_reader.next_entry().await {
+            let path = entry.path();
+            if path.is_file() && is_new_frame(&path) && processed.insert(path.clone()) && tx.send(path).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn test_watcher_reports_new_frame_exactly_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let input_dir = temp_dir.path().to_path_buf();
+
+        let mut rx = watch_for_new_frames(input_dir.clone(), Duration::from_millis(50));
+
+        // Give the watcher a moment to start before creating the file, mirroring
+        // real daemon startup where monitoring begins before any new frame lands.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let frame_path = input_dir.join("frame_1.png");
+        tokio::fs::write(&frame_path, b"fake png bytes").await.unwrap();
+
+        let received = timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for watcher event")
+            .expect("channel closed unexpectedly");
+        assert_eq!(received, frame_path);
+
+        // No duplicate event for the same file should follow.
+        let second = timeout(Duration::from_millis(300), rx.recv()).await;
+        assert!(second.is_err(), "frame was reported more than once: {:?}", second);
+    }
+
+    #[test]
+    fn test_is_new_frame_filters_non_png_and_compressed_files() {
+        assert!(is_new_frame(Path::new("/tmp/frame_1.png")));
+        assert!(!is_new_frame(Path::new("/tmp/frame_1.jpg")));
+        assert!(!is_new_frame(Path::new("/tmp/frame_1_compressed.png")));
+    }
+}
@@ -601,4 +601,55 @@ mod tests {
         assert_eq!(detector.frame_buffer.len(), 0);
         assert_eq!(detector.hash_cache.len(), 0);
     }
+
+    fn test_frame(id: &str) -> VideoFrame {
+        VideoFrame {
+            id: id.to_string(),
+            timestamp: Utc::now(),
+            file_path: std::path::PathBuf::from(format!("{}.png", id)),
+            resolution: (1920, 1080),
+            file_size: 0,
+            image_hash: String::new(),
+            metadata: crate::FrameMetadata::default(),
+        }
+    }
+
+    /// Mirrors the daemon's gating decision: two identical frames should
+    /// only cause expensive processing to be triggered once.
+    #[tokio::test]
+    async fn test_identical_frames_then_a_changed_frame() {
+        let mut detector = ChangeDetector::new(ChangeDetectorConfig::default());
+        let threshold = detector.config.significant_change_threshold;
+
+        let same_image = vec![0u8; 4096];
+        let different_image = vec![255u8; 4096];
+
+        // The first frame has nothing to compare against, so it's always
+        // reported as a full change.
+        let first = detector
+            .detect_changes(test_frame("frame-1"), same_image.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(first.change_score, 1.0);
+
+        let second = detector
+            .detect_changes(test_frame("frame-2"), same_image.clone(), None)
+            .await
+            .unwrap();
+        assert!(
+            second.change_score <= threshold,
+            "identical frame should not trigger processing: score {}",
+            second.change_score
+        );
+
+        let third = detector
+            .detect_changes(test_frame("frame-3"), different_image, None)
+            .await
+            .unwrap();
+        assert!(
+            third.change_score > threshold,
+            "different frame should trigger processing: score {}",
+            third.change_score
+        );
+    }
 }
\ No newline at end of file
@@ -98,6 +98,7 @@ pub enum CodingPlatform {
     CodeSignal,
     TopCoder,
     Codeforces,
+    AdventOfCode,
     ProjectEuler,
     LocalIDE,
     Terminal,
@@ -261,6 +262,46 @@ impl CodingProblemDetector {
                 ],
                 confidence_boost: 0.4,
             },
+            // Codeforces patterns - contest problems are labeled "1234A" and use
+            // "standard input/output" rather than named function signatures
+            ProblemPatternMatcher {
+                pattern_type: PatternType::PlatformSpecific,
+                keywords: vec![
+                    "codeforces".to_string(),
+                    "time limit".to_string(),
+                    "memory limit".to_string(),
+                    "standard input".to_string(),
+                    "standard output".to_string(),
+                ],
+                regex_pattern_strings: vec![
+                    r"(?i)time limit per test".to_string(),
+                    r"(?i)memory limit per test".to_string(),
+                    r"\b\d{3,4}[A-Z]\b".to_string(),
+                ],
+                confidence_boost: 0.4,
+            },
+            // CodeSignal patterns
+            ProblemPatternMatcher {
+                pattern_type: PatternType::PlatformSpecific,
+                keywords: vec![
+                    "codesignal".to_string(),
+                    "arcade".to_string(),
+                    "certified assessment".to_string(),
+                ],
+                regex_pattern_strings: vec![r"(?i)codesignal".to_string()],
+                confidence_boost: 0.4,
+            },
+            // Advent of Code patterns - puzzles are titled "--- Day N: ... ---"
+            ProblemPatternMatcher {
+                pattern_type: PatternType::PlatformSpecific,
+                keywords: vec![
+                    "advent of code".to_string(),
+                    "puzzle input".to_string(),
+                    "your puzzle answer".to_string(),
+                ],
+                regex_pattern_strings: vec![r"(?i)---\s*day\s*\d+".to_string()],
+                confidence_boost: 0.4,
+            },
         ]
     }
 
@@ -380,7 +421,7 @@ impl CodingProblemDetector {
     async fn detect_algorithm_challenge(
         &self,
         ocr_result: &ComprehensiveOCRResult,
-        _vision_analysis: &ScreenAnalysis,
+        vision_analysis: &ScreenAnalysis,
     ) -> Result<Option<DetectedCodingProblem>> {
         let mut platform_confidence = HashMap::new();
         let mut problem_elements = ProblemElements::default();
@@ -437,10 +478,19 @@ impl CodingProblemDetector {
                 let language = self.detect_programming_language(&code_context.visible_code);
                 let test_cases = self.extract_test_cases(&problem_elements);
 
-                Ok(Some(DetectedCodingProblem {
+                let title = if platform == CodingPlatform::Codeforces {
+                    match self.extract_codeforces_problem_id(ocr_result) {
+                        Some(problem_id) => format!("{}. {}", problem_id, self.extract_problem_title(ocr_result)),
+                        None => self.extract_problem_title(ocr_result),
+                    }
+                } else {
+                    self.extract_problem_title(ocr_result)
+                };
+
+                return Ok(Some(DetectedCodingProblem {
                     id: uuid::Uuid::new_v4().to_string(),
                     problem_type: CodingProblemType::AlgorithmChallenge,
-                    title: self.extract_problem_title(ocr_result),
+                    title,
                     description: problem_elements.description.trim().to_string(),
                     code_context,
                     error_details: None,
@@ -452,13 +502,17 @@ impl CodingProblemDetector {
                     confidence: confidence.min(1.0),
                     detected_at: Utc::now(),
                     screen_region: self.calculate_problem_region(ocr_result),
-                }))
-            } else {
-                Ok(None)
+                }));
             }
-        } else {
-            Ok(None)
         }
+
+        // None of the known platforms matched strongly enough. Rather than give
+        // up, look for the structural shape that any algorithm problem shares
+        // regardless of site: a prose problem statement, constraint lines
+        // ("1 <= n <= 10^4"), paired input/output examples, and a code editor
+        // region to solve it in. This lets arbitrary/unbranded problems be
+        // detected without hardcoding a platform's vocabulary.
+        self.detect_structural_algorithm_problem(ocr_result, vision_analysis)
     }
 
     async fn detect_test_failure(
@@ -581,6 +635,10 @@ impl CodingProblemDetector {
                     return Some(CodingPlatform::LeetCode);
                 } else if name.contains("codesignal") {
                     return Some(CodingPlatform::CodeSignal);
+                } else if name.contains("codeforces") {
+                    return Some(CodingPlatform::Codeforces);
+                } else if name.contains("adventofcode") || name.contains("advent of code") {
+                    return Some(CodingPlatform::AdventOfCode);
                 } else if name.contains("vscode") || name.contains("visual studio") {
                     return Some(CodingPlatform::LocalIDE);
                 } else if name.contains("terminal") || name.contains("iterm") {
@@ -779,11 +837,115 @@ impl CodingProblemDetector {
             CodingPlatform::HackerRank
         } else if matcher.keywords.iter().any(|k| k.contains("leetcode")) {
             CodingPlatform::LeetCode
+        } else if matcher.keywords.iter().any(|k| k.contains("codeforces")) {
+            CodingPlatform::Codeforces
+        } else if matcher.keywords.iter().any(|k| k.contains("codesignal")) {
+            CodingPlatform::CodeSignal
+        } else if matcher.keywords.iter().any(|k| k.contains("advent of code")) {
+            CodingPlatform::AdventOfCode
         } else {
             CodingPlatform::Unknown
         }
     }
 
+    /// Codeforces problems are labeled with a contest number and problem
+    /// letter, e.g. "1234A", which makes a more reliable title than the
+    /// generic top-of-screen heuristic.
+    fn extract_codeforces_problem_id(&self, ocr_result: &ComprehensiveOCRResult) -> Option<String> {
+        let problem_id_regex = regex::Regex::new(r"\b(\d{3,4}[A-Z])\b").ok()?;
+
+        for paragraph in &ocr_result.paragraphs {
+            if let Some(captures) = problem_id_regex.captures(&paragraph.text) {
+                return Some(captures[1].to_string());
+            }
+        }
+
+        None
+    }
+
+    /// Structural fallback for `detect_algorithm_challenge`: scores a screen
+    /// on the shape a problem statement takes (prose description, constraint
+    /// lines, paired input/output examples, a code editor) instead of
+    /// requiring one of the known platforms' vocabulary to match.
+    fn detect_structural_algorithm_problem(
+        &self,
+        ocr_result: &ComprehensiveOCRResult,
+        vision_analysis: &ScreenAnalysis,
+    ) -> Result<Option<DetectedCodingProblem>> {
+        let constraint_regex = regex::Regex::new(
+            r"\d+\s*(?:<=|>=|<|>|≤|≥)\s*\w+\s*(?:<=|>=|<|>|≤|≥)\s*[\d^]+",
+        )?;
+
+        let mut elements = ProblemElements::default();
+        let mut has_statement = false;
+        let mut awaiting_output = false;
+        let mut has_example_pair = false;
+        let mut confidence = 0.0;
+
+        for paragraph in &ocr_result.paragraphs {
+            let text = paragraph.text.trim();
+            let lower = text.to_lowercase();
+
+            if !has_statement
+                && text.split_whitespace().count() >= 15
+                && !self.looks_like_code(text)
+            {
+                elements.description.push_str(text);
+                elements.description.push('\n');
+                has_statement = true;
+                confidence += 0.3;
+            }
+
+            if constraint_regex.is_match(text) {
+                elements.constraints.push(text.to_string());
+                confidence += 0.2;
+            }
+
+            if lower.starts_with("input:") || lower.contains("\ninput:") {
+                elements.sample_inputs.push(text.to_string());
+                awaiting_output = true;
+            }
+
+            if awaiting_output && (lower.starts_with("output:") || lower.contains("\noutput:")) {
+                elements.sample_outputs.push(text.to_string());
+                if !has_example_pair {
+                    confidence += 0.3;
+                    has_example_pair = true;
+                }
+                awaiting_output = false;
+            }
+        }
+
+        let code_context = self.extract_code_context(ocr_result)?;
+        if !code_context.visible_code.trim().is_empty() {
+            confidence += 0.2;
+        }
+
+        if confidence < self.detection_config.min_confidence_threshold {
+            return Ok(None);
+        }
+
+        let language = self.detect_programming_language(&code_context.visible_code);
+        let test_cases = self.extract_test_cases(&elements);
+
+        Ok(Some(DetectedCodingProblem {
+            id: uuid::Uuid::new_v4().to_string(),
+            problem_type: CodingProblemType::AlgorithmChallenge,
+            title: self.extract_problem_title(ocr_result),
+            description: elements.description.trim().to_string(),
+            code_context,
+            error_details: None,
+            platform: self.detect_platform(vision_analysis),
+            language,
+            starter_code: self.extract_starter_code(ocr_result),
+            test_cases,
+            constraints: elements.constraints,
+            confidence: confidence.min(1.0),
+            detected_at: Utc::now(),
+            screen_region: self.calculate_problem_region(ocr_result),
+        }))
+    }
+
     pub fn update_context_buffer(&mut self, ocr_result: ComprehensiveOCRResult, vision_analysis: ScreenAnalysis) {
         let context = ScreenContext {
             timestamp: Utc::now(),
@@ -33,6 +33,7 @@ pub struct CaptureConfig {
     pub enable_full_text_extraction: bool, // Extract ALL text with positions
     pub enable_real_time_analysis: bool, // Real-time task/question detection
     pub buffer_size: usize, // Number of frames to buffer for change detection
+    pub frame_format: FrameFormat, // Encoding used when storing captured frames
 }
 
 impl Default for CaptureConfig {
@@ -52,6 +53,34 @@ impl Default for CaptureConfig {
             enable_full_text_extraction: true, // Extract ALL text with positions
             enable_real_time_analysis: true, // Real-time task/question detection
             buffer_size: 10, // Keep 10 frames for change detection
+            frame_format: FrameFormat::Png, // Lossless by default, matching prior behavior
+        }
+    }
+}
+
+/// On-disk encoding for a captured frame. `Jpeg`/`WebP` trade storage for fidelity;
+/// callers that need lossless frames (e.g. code screenshots flagged for OCR) should
+/// pass `lossless = true` to [`crate::StorageManager::save_frame`] regardless of this
+/// setting, which forces `Png` for that one frame.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum FrameFormat {
+    Png,
+    Jpeg { quality: u8 },
+    WebP { quality: u8 },
+}
+
+impl Default for FrameFormat {
+    fn default() -> Self {
+        FrameFormat::Png
+    }
+}
+
+impl FrameFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FrameFormat::Png => "png",
+            FrameFormat::Jpeg { .. } => "jpg",
+            FrameFormat::WebP { .. } => "webp",
         }
     }
 }
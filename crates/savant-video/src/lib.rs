@@ -18,16 +18,18 @@ pub mod integrated_processor;
 pub mod llm_provider;
 
 pub use capture::VideoCapture;
-pub use config::{CaptureConfig, ImageQuality, VideoConfig};
-pub use privacy::{PrivacyController, PrivacySettings};
-pub use storage::{StorageManager, StorageSettings};
+pub use config::{CaptureConfig, FrameFormat, ImageQuality, VideoConfig};
+pub use privacy::{
+    PrivacyController, PrivacySettings, RecordingSchedule, RedactionRule, RedactionStyle,
+};
+pub use storage::{StorageManager, StorageSettings, StorageStats};
 pub use analyzer::{EnhancedVideoAnalyzer, VideoAnalysisResult, CodeSnippet, InteractionOpportunity};
 pub use multimodal::{MultimodalFrame, MultimodalAnalyzer};
-pub use processor::{VideoProcessor, ProcessingCommand, ProcessingEvent as VideoProcessingEvent, CompressedFrame};
+pub use processor::{VideoProcessor, ProcessingCommand, ProcessingEvent as VideoProcessingEvent, CompressedFrame, BatchProgress, calculate_sha256_hash};
 pub use real_time_analyzer::{RealTimeAnalyzer, TaskDetectionResult, DetectedTask, DetectedQuestion, AssistanceOpportunity};
 pub use change_detector::{ChangeDetector, ChangeDetectionResult, ChangedRegion, ChangeDetectorConfig};
 pub use coding_problem_detector::{CodingProblemDetector, DetectedCodingProblem, CodingProblemType, DetectionConfig};
-pub use solution_generator::{SolutionGenerator, GeneratedSolution, SolutionConfig};
+pub use solution_generator::{SolutionGenerator, GeneratedSolution, SolutionConfig, SolutionValidator};
 pub use integrated_processor::{IntegratedProcessor, ProcessorConfig, ProcessingEvent, ProcessingResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +55,7 @@ pub struct FrameMetadata {
     pub detected_applications: Vec<savant_vision::DetectedApp>,
     pub activity_classification: Option<savant_vision::ActivityClassification>,
     pub visual_context: Option<savant_vision::VisualContext>,
+    pub frame_format: FrameFormat, // Encoding the frame was actually saved with
 }
 
 impl Default for FrameMetadata {
@@ -68,6 +71,7 @@ impl Default for FrameMetadata {
             detected_applications: Vec::new(),
             activity_classification: None,
             visual_context: None,
+            frame_format: FrameFormat::Png,
         }
     }
 }
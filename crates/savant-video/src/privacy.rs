@@ -1,14 +1,166 @@
-use chrono::Timelike;
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{Datelike, Timelike, Weekday};
+use image::DynamicImage;
+use regex::Regex;
+use savant_vision::{BoundingBox, ElementType, VisualElement};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::config::TimeRange;
+
+/// Regexes backing [`PrivacyController::is_sensitive_content`].
+fn sensitive_content_patterns() -> &'static [Regex] {
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    PATTERNS.get_or_init(|| {
+        vec![
+            // Labelled secrets, e.g. "password: hunter2", "API Key=sk-...".
+            Regex::new(r"(?i)\b(password|passwd|pwd|secret|api[_ -]?key|access[_ -]?token)\b\s*[:=]\s*\S+")
+                .unwrap(),
+            // Credit card numbers: 13-19 digits, optionally grouped.
+            Regex::new(r"\b(?:\d[ -]?){12,18}\d\b").unwrap(),
+            // US social security numbers.
+            Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap(),
+        ]
+    })
+}
+
+/// A recording window enforced by [`PrivacyController::should_capture`]: a
+/// local time-of-day range, with an optional subset of weekdays it applies
+/// to. `weekdays: None` means every day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingSchedule {
+    pub hours: TimeRange,
+    pub weekdays: Option<HashSet<Weekday>>,
+}
+
+impl RecordingSchedule {
+    /// Parse a `"HH:MM-HH:MM"` spec (e.g. `"09:00-17:00"`) into a schedule
+    /// with no weekday restriction.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let (start, end) = spec
+            .split_once('-')
+            .ok_or_else(|| anyhow!("expected \"HH:MM-HH:MM\", got \"{}\"", spec))?;
+
+        Ok(Self {
+            hours: TimeRange::new(Self::parse_time(start)?, Self::parse_time(end)?),
+            weekdays: None,
+        })
+    }
+
+    fn parse_time(s: &str) -> Result<(u8, u8)> {
+        let (hour, minute) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| anyhow!("expected \"HH:MM\", got \"{}\"", s))?;
+        let hour: u8 = hour.parse().with_context(|| format!("invalid hour in \"{}\"", s))?;
+        let minute: u8 = minute.parse().with_context(|| format!("invalid minute in \"{}\"", s))?;
+        if hour > 23 || minute > 59 {
+            bail!("time out of range: \"{}\"", s);
+        }
+        Ok((hour, minute))
+    }
+
+    /// Whether `now` (a local time) falls within this schedule.
+    pub fn allows(&self, now: chrono::DateTime<chrono::Local>) -> bool {
+        if let Some(weekdays) = &self.weekdays {
+            if !weekdays.contains(&now.weekday()) {
+                return false;
+            }
+        }
+        // Minutes since midnight in u32; `TimeRange::is_within_range` uses u8
+        // arithmetic, which overflows for any time past 04:15.
+        let current = now.hour() * 60 + now.minute();
+        let start = self.hours.start_hour as u32 * 60 + self.hours.start_minute as u32;
+        let end = self.hours.end_hour as u32 * 60 + self.hours.end_minute as u32;
+        if start <= end {
+            current >= start && current <= end
+        } else {
+            // Overnight range, e.g. 22:00-06:00
+            current >= start || current <= end
+        }
+    }
+}
+
+/// Policy applied by [`PrivacyController::should_capture`] when the active
+/// application can't be determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnknownAppPolicy {
+    Allow,
+    Block,
+}
+
+/// A region [`PrivacyController::redact`] should obscure before a frame is
+/// persisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RedactionRule {
+    /// Redact any detected [`ElementType::TextField`] whose
+    /// [`ElementProperties::is_sensitive`](savant_vision::ElementProperties::is_sensitive) is set.
+    SensitiveTextFields,
+    /// Redact a fixed region of the frame, regardless of detected elements.
+    Region(BoundingBox),
+}
+
+/// How [`PrivacyController::redact`] obscures a matched region.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum RedactionStyle {
+    BlackBox,
+    Blur { sigma: f32 },
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrivacySettings {
     pub enabled: bool,
-    pub recording_schedule: Option<crate::config::TimeRange>,
+    pub recording_schedule: Option<RecordingSchedule>,
     pub notification_interval: u32, // seconds
+    /// Blocked application names, matched against the active app
+    /// case-insensitively and supporting `*` wildcards (e.g. `"1Password*"`).
     pub blocked_applications: HashSet<String>,
+    pub unknown_app_policy: UnknownAppPolicy,
     pub require_explicit_start: bool,
+    /// Regions to obscure in each frame before it's saved to storage.
+    pub redaction_rules: Vec<RedactionRule>,
+    pub redaction_style: RedactionStyle,
+}
+
+impl PrivacySettings {
+    fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("~/.config"))
+            .join("savant-ai")
+            .join("privacy.json")
+    }
+
+    /// Load settings from the config file, falling back to [`Default`] if it
+    /// doesn't exist yet.
+    pub async fn load() -> Result<Self> {
+        let path = Self::config_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("failed to read privacy config at {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed to parse privacy config at {}", path.display()))
+    }
+
+    /// Persist settings to the config file, creating its parent directory if needed.
+    pub async fn save(&self) -> Result<()> {
+        let path = Self::config_path();
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)?;
+        tokio::fs::write(&path, contents)
+            .await
+            .with_context(|| format!("failed to write privacy config at {}", path.display()))
+    }
 }
 
 impl Default for PrivacySettings {
@@ -24,11 +176,31 @@ impl Default for PrivacySettings {
             recording_schedule: None,
             notification_interval: 1800, // 30 minutes
             blocked_applications: blocked_apps,
+            unknown_app_policy: UnknownAppPolicy::Allow, // Preserve prior behavior: an untracked app isn't blocked
             require_explicit_start: false, // Allow immediate capture
+            redaction_rules: vec![RedactionRule::SensitiveTextFields],
+            redaction_style: RedactionStyle::BlackBox,
         }
     }
 }
 
+/// Case-insensitive match of `app` against a blocklist `pattern` that may
+/// contain `*` wildcards (e.g. `"1Password*"`).
+fn glob_match_ci(pattern: &str, app: &str) -> bool {
+    fn matches(pattern: &[u8], text: &[u8]) -> bool {
+        match (pattern.first(), text.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => {
+                matches(&pattern[1..], text) || (!text.is_empty() && matches(pattern, &text[1..]))
+            }
+            (Some(p), Some(t)) if p == t => matches(&pattern[1..], &text[1..]),
+            _ => false,
+        }
+    }
+
+    matches(pattern.to_lowercase().as_bytes(), app.to_lowercase().as_bytes())
+}
+
 pub struct PrivacyController {
     settings: PrivacySettings,
     last_notification: Option<std::time::Instant>,
@@ -48,18 +220,22 @@ impl PrivacyController {
         }
 
         // Check if app is blocked
-        if let Some(app) = active_app {
-            if self.settings.blocked_applications.contains(app) {
-                return false;
+        match active_app {
+            Some(app) => {
+                if self.settings.blocked_applications.iter().any(|pattern| glob_match_ci(pattern, app)) {
+                    return false;
+                }
+            }
+            None => {
+                if self.settings.unknown_app_policy == UnknownAppPolicy::Block {
+                    return false;
+                }
             }
         }
 
         // Check schedule
         if let Some(schedule) = &self.settings.recording_schedule {
-            let now = chrono::Local::now();
-            let hour = now.hour() as u8;
-            let minute = now.minute() as u8;
-            if !schedule.is_within_range(hour, minute) {
+            if !schedule.allows(chrono::Local::now()) {
                 return false;
             }
         }
@@ -89,9 +265,235 @@ impl PrivacyController {
         }
     }
 
-    pub fn is_sensitive_content(&self, _text: Option<&str>) -> bool {
-        // TODO: Implement basic PII detection
-        // For now, just return false
-        false
+    /// Basic heuristic PII/secret detection, used to flag OCR'd text regions
+    /// for [`RedactionRule::SensitiveTextFields`]: credit card numbers,
+    /// social security numbers, and values that follow a `password`/`secret`/
+    /// `api key`-style label. Not exhaustive -- a defense-in-depth heuristic,
+    /// not a guarantee that everything sensitive is caught.
+    pub fn is_sensitive_content(&self, text: Option<&str>) -> bool {
+        let Some(text) = text else {
+            return false;
+        };
+
+        sensitive_content_patterns().iter().any(|re| re.is_match(text))
+    }
+
+    /// Obscures every region matched by `self.settings.redaction_rules`
+    /// in-place, before the frame is written to storage.
+    pub fn redact(&self, image: &mut DynamicImage, elements: &[VisualElement]) {
+        for rule in &self.settings.redaction_rules {
+            match rule {
+                RedactionRule::SensitiveTextFields => {
+                    for element in elements {
+                        if matches!(element.element_type, ElementType::TextField)
+                            && element.properties.is_sensitive
+                        {
+                            apply_redaction(image, &element.bounding_box, self.settings.redaction_style);
+                        }
+                    }
+                }
+                RedactionRule::Region(bbox) => {
+                    apply_redaction(image, bbox, self.settings.redaction_style);
+                }
+            }
+        }
+    }
+}
+
+/// Obscures `bbox` within `image`, clamped to the image bounds.
+fn apply_redaction(image: &mut DynamicImage, bbox: &BoundingBox, style: RedactionStyle) {
+    let mut buf = image.to_rgba8();
+    let (img_w, img_h) = (buf.width(), buf.height());
+    let x = bbox.x.min(img_w);
+    let y = bbox.y.min(img_h);
+    let w = bbox.width.min(img_w.saturating_sub(x));
+    let h = bbox.height.min(img_h.saturating_sub(y));
+
+    if w == 0 || h == 0 {
+        return;
+    }
+
+    match style {
+        RedactionStyle::BlackBox => {
+            for yy in y..y + h {
+                for xx in x..x + w {
+                    buf.put_pixel(xx, yy, image::Rgba([0, 0, 0, 255]));
+                }
+            }
+        }
+        RedactionStyle::Blur { sigma } => {
+            let region = image::imageops::crop_imm(&buf, x, y, w, h).to_image();
+            let blurred = image::imageops::blur(&region, sigma);
+            image::imageops::replace(&mut buf, &blurred, x as i64, y as i64);
+        }
+    }
+
+    *image = DynamicImage::ImageRgba8(buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn local(hour: u32, minute: u32) -> chrono::DateTime<chrono::Local> {
+        chrono::Local.with_ymd_and_hms(2026, 8, 10, hour, minute, 0).unwrap() // a Monday
+    }
+
+    #[test]
+    fn test_recording_schedule_parse() {
+        let schedule = RecordingSchedule::parse("09:00-17:00").unwrap();
+        assert_eq!(schedule.hours.start_hour, 9);
+        assert_eq!(schedule.hours.end_hour, 17);
+        assert!(schedule.weekdays.is_none());
+    }
+
+    #[test]
+    fn test_recording_schedule_in_window() {
+        let schedule = RecordingSchedule::parse("09:00-17:00").unwrap();
+        assert!(schedule.allows(local(12, 0)));
+    }
+
+    #[test]
+    fn test_recording_schedule_out_of_window() {
+        let schedule = RecordingSchedule::parse("09:00-17:00").unwrap();
+        assert!(!schedule.allows(local(20, 0)));
+    }
+
+    #[test]
+    fn test_recording_schedule_spanning_midnight() {
+        let schedule = RecordingSchedule::parse("22:00-06:00").unwrap();
+        assert!(schedule.allows(local(23, 30)));
+        assert!(schedule.allows(local(2, 0)));
+        assert!(!schedule.allows(local(12, 0)));
+    }
+
+    #[test]
+    fn test_recording_schedule_weekday_mask_excludes_other_days() {
+        let mut schedule = RecordingSchedule::parse("09:00-17:00").unwrap();
+        schedule.weekdays = Some([Weekday::Sat, Weekday::Sun].into_iter().collect());
+
+        assert!(!schedule.allows(local(12, 0))); // Monday, not in the mask
+    }
+
+    #[test]
+    fn test_glob_match_ci_wildcard() {
+        assert!(glob_match_ci("1Password*", "1Password 8"));
+        assert!(!glob_match_ci("1Password*", "Dashlane"));
+    }
+
+    #[test]
+    fn test_glob_match_ci_case_insensitive() {
+        assert!(glob_match_ci("1password*", "1PASSWORD 8"));
+    }
+
+    #[test]
+    fn test_should_capture_blocks_glob_matched_app() {
+        let mut settings = PrivacySettings::default();
+        settings.blocked_applications = ["1Password*".to_string()].into_iter().collect();
+        let controller = PrivacyController::new(settings);
+
+        assert!(!controller.should_capture(Some("1Password 8")));
+        assert!(controller.should_capture(Some("Notes")));
+    }
+
+    #[test]
+    fn test_should_capture_unknown_app_default_policy_allows() {
+        let controller = PrivacyController::new(PrivacySettings::default());
+        assert!(controller.should_capture(None));
+    }
+
+    #[test]
+    fn test_should_capture_unknown_app_blocked_by_policy() {
+        let mut settings = PrivacySettings::default();
+        settings.unknown_app_policy = UnknownAppPolicy::Block;
+        let controller = PrivacyController::new(settings);
+
+        assert!(!controller.should_capture(None));
+    }
+
+    #[test]
+    fn test_redact_alters_only_pixels_inside_region() {
+        let mut settings = PrivacySettings::default();
+        settings.redaction_rules = vec![RedactionRule::Region(BoundingBox {
+            x: 2,
+            y: 2,
+            width: 4,
+            height: 4,
+            confidence: 1.0,
+        })];
+        settings.redaction_style = RedactionStyle::BlackBox;
+        let controller = PrivacyController::new(settings);
+
+        let original = image::RgbaImage::from_pixel(10, 10, image::Rgba([200, 100, 50, 255]));
+        let mut image = DynamicImage::ImageRgba8(original.clone());
+
+        controller.redact(&mut image, &[]);
+
+        let redacted = image.to_rgba8();
+        for y in 2..6 {
+            for x in 2..6 {
+                assert_eq!(*redacted.get_pixel(x, y), image::Rgba([0, 0, 0, 255]));
+            }
+        }
+        assert_eq!(*redacted.get_pixel(0, 0), *original.get_pixel(0, 0));
+        assert_eq!(*redacted.get_pixel(9, 9), *original.get_pixel(9, 9));
+        assert_eq!(*redacted.get_pixel(1, 1), *original.get_pixel(1, 1));
+        assert_eq!(*redacted.get_pixel(6, 6), *original.get_pixel(6, 6));
+    }
+
+    #[test]
+    fn test_is_sensitive_content_detects_labelled_secrets() {
+        let controller = PrivacyController::new(PrivacySettings::default());
+        assert!(controller.is_sensitive_content(Some("password: hunter2")));
+        assert!(controller.is_sensitive_content(Some("API Key=sk-abc123")));
+    }
+
+    #[test]
+    fn test_is_sensitive_content_detects_card_and_ssn_numbers() {
+        let controller = PrivacyController::new(PrivacySettings::default());
+        assert!(controller.is_sensitive_content(Some("4111 1111 1111 1111")));
+        assert!(controller.is_sensitive_content(Some("SSN 123-45-6789")));
+    }
+
+    #[test]
+    fn test_is_sensitive_content_ignores_ordinary_text() {
+        let controller = PrivacyController::new(PrivacySettings::default());
+        assert!(!controller.is_sensitive_content(Some("Hello, world!")));
+        assert!(!controller.is_sensitive_content(None));
+    }
+
+    #[test]
+    fn test_redact_sensitive_text_field_elements() {
+        let controller = PrivacyController::new(PrivacySettings::default());
+
+        let original = image::RgbaImage::from_pixel(10, 10, image::Rgba([200, 100, 50, 255]));
+        let mut image = DynamicImage::ImageRgba8(original.clone());
+
+        let elements = vec![VisualElement {
+            element_type: ElementType::TextField,
+            bounding_box: BoundingBox {
+                x: 2,
+                y: 2,
+                width: 4,
+                height: 4,
+                confidence: 0.9,
+            },
+            properties: savant_vision::ElementProperties {
+                color_scheme: None,
+                text_content: Some("password: hunter2".to_string()),
+                is_interactive: true,
+                state: None,
+                app_context: None,
+                is_sensitive: true,
+            },
+            confidence: 0.9,
+        }];
+
+        controller.redact(&mut image, &elements);
+
+        let redacted = image.to_rgba8();
+        assert_eq!(*redacted.get_pixel(2, 2), image::Rgba([0, 0, 0, 255]));
+        assert_eq!(*redacted.get_pixel(0, 0), *original.get_pixel(0, 0));
     }
 }
\ No newline at end of file
@@ -2,15 +2,26 @@ use anyhow::Result;
 use chrono::Utc;
 use image::{DynamicImage, ImageFormat};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
 use crate::analyzer::{EnhancedVideoAnalyzer, VideoAnalysisResult};
-use crate::config::CaptureConfig;
+use crate::config::{CaptureConfig, FrameFormat};
 use crate::{FrameMetadata, VideoFrame};
 
+/// Compute the SHA256 hash of raw image bytes, hex-encoded.
+///
+/// Used consistently across capture and batch-processing entry points so
+/// `image_hash`/`frame_hash` values can be compared for deduplication.
+pub fn calculate_sha256_hash(image_data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(image_data);
+    hex::encode(hasher.finalize())
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessingStats {
     pub frames_processed: u64,
@@ -332,15 +343,64 @@ pub fn create_processing_pipeline(
     Ok((cmd_sender, event_receiver, handle))
 }
 
+/// Structured progress events emitted by [`batch_process_existing_files`], so GUIs and
+/// the CLI can show more than a bare "N of M" count.
+#[derive(Debug, Clone)]
+pub enum BatchProgress {
+    /// Emitted once, before any files are processed.
+    Started { total: usize },
+    /// Emitted after a file is processed successfully.
+    FileDone {
+        path: PathBuf,
+        ocr_chars: usize,
+        tasks_detected: usize,
+    },
+    /// Emitted when a file fails to load or process; processing continues with the
+    /// next file.
+    FileError { path: PathBuf, error: String },
+    /// Emitted once, after every file has been attempted.
+    Finished { succeeded: usize, failed: usize },
+}
+
+/// Adapts a plain `(current, total)` count callback (the progress API before
+/// [`BatchProgress`] existed) into the richer event stream, for callers that only care
+/// about a running count.
+pub fn count_progress_adapter(
+    callback: impl Fn(usize, usize) + Send + Sync + 'static,
+) -> Box<dyn Fn(BatchProgress) + Send + Sync> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let total = Arc::new(AtomicUsize::new(0));
+    let done = Arc::new(AtomicUsize::new(0));
+
+    Box::new(move |event| match event {
+        BatchProgress::Started { total: new_total } => {
+            total.store(new_total, Ordering::SeqCst);
+        }
+        BatchProgress::FileDone { .. } | BatchProgress::FileError { .. } => {
+            let current = done.fetch_add(1, Ordering::SeqCst) + 1;
+            callback(current, total.load(Ordering::SeqCst));
+        }
+        BatchProgress::Finished { .. } => {}
+    })
+}
+
 /// Batch processing function for existing PNG files
 pub async fn batch_process_existing_files(
     input_dir: impl AsRef<Path>,
     config: CaptureConfig,
-    progress_callback: Option<Box<dyn Fn(usize, usize) + Send + Sync>>,
+    progress_callback: Option<Box<dyn Fn(BatchProgress) + Send + Sync>>,
 ) -> Result<Vec<CompressedFrame>> {
     let input_path = input_dir.as_ref();
     info!("Starting batch processing of directory: {}", input_path.display());
 
+    let emit = |event: BatchProgress| {
+        if let Some(callback) = &progress_callback {
+            callback(event);
+        }
+    };
+
     // Find all PNG files
     let mut png_files = Vec::new();
     let mut dir_reader = tokio::fs::read_dir(input_path).await?;
@@ -357,60 +417,102 @@ pub async fn batch_process_existing_files(
 
     png_files.sort();
     info!("Found {} PNG files to process", png_files.len());
+    emit(BatchProgress::Started { total: png_files.len() });
 
     let processor = VideoProcessor::new(config.clone())?;
     let mut results = Vec::new();
+    let mut succeeded = 0usize;
+    let mut failed = 0usize;
 
     for (i, png_path) in png_files.iter().enumerate() {
-        if let Some(callback) = &progress_callback {
-            callback(i + 1, png_files.len());
-        }
-
-        // Load image data
-        let image_data = tokio::fs::read(png_path).await?;
-        let image = image::load_from_memory(&image_data)?;
-
-        // Create minimal frame metadata
-        let frame = VideoFrame {
-            id: uuid::Uuid::new_v4().to_string(),
-            timestamp: Utc::now(),
-            file_path: png_path.clone(),
-            resolution: (image.width(), image.height()),
-            file_size: image_data.len() as u64,
-            image_hash: format!("{:x}", md5::compute(&image_data)),
-            metadata: FrameMetadata {
-                session_id: "batch_processing".to_string(),
-                display_id: None,
-                active_application: None,
-                window_title: None,
-                change_detected: true,
-                ocr_text: None,
-                enhanced_analysis: None,
-                detected_applications: Vec::new(),
-                activity_classification: None,
-                visual_context: None,
-            },
-        };
-
-        // Process frame
-        let compressed_frame = processor.compress_frame(&frame, &image, &image_data).await?;
-
-        // Optionally analyze frame
-        let mut final_frame = compressed_frame;
-        if config.enable_processing && (i + 1) % config.processing_interval as usize == 0 {
-            final_frame.processing_result = processor.analyze_frame(&image, &frame.metadata).await?;
+        match process_one_file(&processor, png_path, &config, i).await {
+            Ok(final_frame) => {
+                succeeded += 1;
+                let (ocr_chars, tasks_detected) = final_frame
+                    .processing_result
+                    .as_ref()
+                    .map(|result| {
+                        let ocr_chars = result
+                            .ocr_result
+                            .as_ref()
+                            .map(|ocr| ocr.text_blocks.iter().map(|b| b.text.chars().count()).sum())
+                            .unwrap_or(0);
+                        (ocr_chars, result.interaction_opportunities.len())
+                    })
+                    .unwrap_or((0, 0));
+
+                debug!("Processed file {}/{}: {}", i + 1, png_files.len(), png_path.display());
+                emit(BatchProgress::FileDone {
+                    path: png_path.clone(),
+                    ocr_chars,
+                    tasks_detected,
+                });
+                results.push(final_frame);
+            }
+            Err(e) => {
+                failed += 1;
+                warn!("Failed to process file {}: {}", png_path.display(), e);
+                emit(BatchProgress::FileError {
+                    path: png_path.clone(),
+                    error: e.to_string(),
+                });
+            }
         }
-
-        results.push(final_frame);
-
-        debug!("Processed file {}/{}: {}", i + 1, png_files.len(), png_path.display());
     }
 
+    emit(BatchProgress::Finished { succeeded, failed });
     info!(
-        "Batch processing complete: {} files processed, {:.1}MB saved",
-        results.len(),
+        "Batch processing complete: {} files processed, {} failed, {:.1}MB saved",
+        succeeded,
+        failed,
         processor.get_stats().storage_saved_bytes as f32 / 1024.0 / 1024.0
     );
 
     Ok(results)
 }
+
+/// Loads, compresses, and (if due) analyzes a single file for
+/// [`batch_process_existing_files`]. Factored out so a failure on one file can be
+/// reported as a [`BatchProgress::FileError`] without aborting the rest of the batch.
+async fn process_one_file(
+    processor: &VideoProcessor,
+    png_path: &Path,
+    config: &CaptureConfig,
+    index: usize,
+) -> Result<CompressedFrame> {
+    let image_data = tokio::fs::read(png_path).await?;
+    let image = image::load_from_memory(&image_data)?;
+
+    // Create minimal frame metadata
+    let frame = VideoFrame {
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: Utc::now(),
+        file_path: png_path.to_path_buf(),
+        resolution: (image.width(), image.height()),
+        file_size: image_data.len() as u64,
+        image_hash: calculate_sha256_hash(&image_data),
+        metadata: FrameMetadata {
+            session_id: "batch_processing".to_string(),
+            display_id: None,
+            active_application: None,
+            window_title: None,
+            change_detected: true,
+            ocr_text: None,
+            enhanced_analysis: None,
+            detected_applications: Vec::new(),
+            activity_classification: None,
+            visual_context: None,
+            frame_format: FrameFormat::Png,
+        },
+    };
+
+    // Process frame
+    let mut final_frame = processor.compress_frame(&frame, &image, &image_data).await?;
+
+    // Optionally analyze frame
+    if config.enable_processing && (index + 1) % config.processing_interval as usize == 0 {
+        final_frame.processing_result = processor.analyze_frame(&image, &frame.metadata).await?;
+    }
+
+    Ok(final_frame)
+}
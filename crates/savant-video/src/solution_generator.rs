@@ -3,7 +3,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{info, warn};
 
-use crate::coding_problem_detector::{DetectedCodingProblem, CodingProblemType, ProgrammingLanguage};
+use crate::coding_problem_detector::{DetectedCodingProblem, CodingProblemType, ProgrammingLanguage, TestCase};
 use crate::llm_provider::{LLMProvider, LLMProviderTrait, LLMRequest};
 
 #[derive(Debug, Clone)]
@@ -25,6 +25,18 @@ pub struct SolutionConfig {
     pub include_test_validation: bool,
     pub max_retry_attempts: usize,
     pub cache_solutions: bool,
+    /// Actually execute generated solutions against `test_cases` in a bare subprocess
+    /// with a memory `ulimit` and a timeout. **This is resource-limited execution, not
+    /// a security sandbox**: it has no filesystem, network, or syscall isolation, so
+    /// LLM-generated code runs with the full privileges of this process and can read
+    /// or write any file the user can and make arbitrary network calls. Off by default
+    /// for exactly this reason -- only enable it in an environment you're willing to
+    /// let untrusted code run in (e.g. a container or VM you'd throw away), never on a
+    /// host with access to anything sensitive. When disabled, `validate_solution`
+    /// reports each test case as unvalidated instead of fabricating a result.
+    pub enable_unsandboxed_test_execution: bool,
+    pub execution_timeout: std::time::Duration,
+    pub execution_memory_limit_mb: u64,
 }
 
 impl Default for SolutionConfig {
@@ -44,6 +56,9 @@ impl Default for SolutionConfig {
             include_test_validation: true,
             max_retry_attempts: 3,
             cache_solutions: true,
+            enable_unsandboxed_test_execution: false,
+            execution_timeout: std::time::Duration::from_secs(5),
+            execution_memory_limit_mb: 256,
         }
     }
 }
@@ -110,6 +125,176 @@ impl SolutionCache {
     }
 }
 
+/// Runs a `GeneratedSolution` against a problem's `test_cases` in a subprocess, rather
+/// than trusting the LLM's own claims about correctness.
+///
+/// **This provides resource limits (timeout, memory `ulimit`), not security
+/// isolation.** There is no container, seccomp profile, or network/filesystem
+/// restriction -- the subprocess runs with this process's full privileges. Only
+/// invoked when `SolutionConfig::enable_unsandboxed_test_execution` is explicitly set,
+/// since it executes model-generated code on this machine with no isolation from it.
+#[derive(Debug, Clone)]
+pub struct SolutionValidator {
+    timeout: std::time::Duration,
+    memory_limit_mb: u64,
+}
+
+impl SolutionValidator {
+    pub fn new(timeout: std::time::Duration, memory_limit_mb: u64) -> Self {
+        Self {
+            timeout,
+            memory_limit_mb,
+        }
+    }
+
+    pub async fn validate(
+        &self,
+        solution: &GeneratedSolution,
+        test_cases: &[TestCase],
+    ) -> Result<Vec<TestValidationResult>> {
+        let mut results = Vec::with_capacity(test_cases.len());
+        for (i, test_case) in test_cases.iter().enumerate() {
+            let result = match solution.language {
+                ProgrammingLanguage::Python => {
+                    self.run_python_test(i, &solution.solution_code, test_case).await
+                }
+                other => Err(anyhow::anyhow!(
+                    "Sandboxed validation is not yet implemented for {}",
+                    other.to_string()
+                )),
+            };
+
+            results.push(match result {
+                Ok(result) => result,
+                Err(e) => TestValidationResult {
+                    test_case_id: format!("test_{}", i),
+                    input: test_case.input.clone(),
+                    expected_output: test_case.expected_output.clone(),
+                    actual_output: String::new(),
+                    passed: false,
+                    execution_time_ms: None,
+                    error_message: Some(e.to_string()),
+                },
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Runs `solution_code` under `sh -c 'ulimit -v <limit>; exec python3 -'`. The
+    /// `ulimit` and timeout bound runaway resource use; they do **not** restrict
+    /// filesystem access or networking, so this must never run code the caller doesn't
+    /// already trust with this process's full privileges.
+    async fn run_python_test(
+        &self,
+        index: usize,
+        solution_code: &str,
+        test_case: &TestCase,
+    ) -> Result<TestValidationResult> {
+        let script = Self::build_python_script(solution_code, &test_case.input)?;
+        let memory_limit_kb = self.memory_limit_mb * 1024;
+
+        let mut child = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!("ulimit -v {} 2>/dev/null; exec python3 -", memory_limit_kb))
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+
+        {
+            use tokio::io::AsyncWriteExt;
+            let mut stdin = child.stdin.take().expect("stdin was piped");
+            stdin.write_all(script.as_bytes()).await?;
+        }
+
+        let start = std::time::Instant::now();
+        let output = match tokio::time::timeout(self.timeout, child.wait_with_output()).await {
+            Ok(result) => result?,
+            Err(_) => {
+                return Ok(TestValidationResult {
+                    test_case_id: format!("test_{}", index),
+                    input: test_case.input.clone(),
+                    expected_output: test_case.expected_output.clone(),
+                    actual_output: String::new(),
+                    passed: false,
+                    execution_time_ms: Some(self.timeout.as_millis() as u64),
+                    error_message: Some("Execution timed out".to_string()),
+                });
+            }
+        };
+        let execution_time_ms = start.elapsed().as_millis() as u64;
+
+        let actual_output = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let error_message = if output.status.success() {
+            None
+        } else {
+            Some(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        };
+
+        let passed = output.status.success()
+            && Self::normalize_output(&actual_output) == Self::normalize_output(&test_case.expected_output);
+
+        Ok(TestValidationResult {
+            test_case_id: format!("test_{}", index),
+            input: test_case.input.clone(),
+            expected_output: test_case.expected_output.clone(),
+            actual_output,
+            passed,
+            execution_time_ms: Some(execution_time_ms),
+            error_message,
+        })
+    }
+
+    /// Builds a standalone script: the solution's function definition(s),
+    /// followed by the test case's `name = value, ...` assignments and a
+    /// call to the solution's function with those names in assignment
+    /// order, so the script's only output is the function's return value.
+    fn build_python_script(solution_code: &str, input: &str) -> Result<String> {
+        let function_name = regex::Regex::new(r"def\s+(\w+)\s*\(")
+            .unwrap()
+            .captures(solution_code)
+            .map(|c| c[1].to_string())
+            .ok_or_else(|| anyhow::anyhow!("Could not find a function definition in the generated solution"))?;
+
+        // A comma followed by `name =` starts the next assignment; commas inside values
+        // (e.g. `[2,7,11,15]`) do not. The `regex` crate has no look-ahead, so split
+        // at each match's comma by hand.
+        let assignment_boundary = regex::Regex::new(r",\s*\w+\s*=").unwrap();
+        let mut parts = Vec::new();
+        let mut part_start = 0;
+        for boundary in assignment_boundary.find_iter(input) {
+            parts.push(&input[part_start..boundary.start()]);
+            part_start = boundary.start() + 1;
+        }
+        parts.push(&input[part_start..]);
+
+        let mut arg_names = Vec::new();
+        let mut assignments = String::new();
+        for part in parts {
+            let part = part.trim();
+            if let Some((name, _)) = part.split_once('=') {
+                arg_names.push(name.trim().to_string());
+            }
+            assignments.push_str(part);
+            assignments.push('\n');
+        }
+
+        Ok(format!(
+            "{}\n\n{}\nprint({}({}))\n",
+            solution_code,
+            assignments,
+            function_name,
+            arg_names.join(", "),
+        ))
+    }
+
+    fn normalize_output(s: &str) -> String {
+        s.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
 impl SolutionGenerator {
     pub fn new(config: SolutionConfig, llm_provider: LLMProvider) -> Self {
         Self {
@@ -451,23 +636,35 @@ impl SolutionGenerator {
         solution: &GeneratedSolution,
         problem: &DetectedCodingProblem,
     ) -> Result<Vec<TestValidationResult>> {
-        // This would integrate with a code execution service
-        // For now, return mock results
-        let mut results = Vec::new();
-
-        for (i, test_case) in problem.test_cases.iter().enumerate() {
-            results.push(TestValidationResult {
-                test_case_id: format!("test_{}", i),
-                input: test_case.input.clone(),
-                expected_output: test_case.expected_output.clone(),
-                actual_output: test_case.expected_output.clone(), // Mock: assume passing
-                passed: true,
-                execution_time_ms: Some(rand::random::<u64>() % 100),
-                error_message: None,
-            });
+        if !self.config.enable_unsandboxed_test_execution {
+            return Ok(problem
+                .test_cases
+                .iter()
+                .enumerate()
+                .map(|(i, test_case)| TestValidationResult {
+                    test_case_id: format!("test_{}", i),
+                    input: test_case.input.clone(),
+                    expected_output: test_case.expected_output.clone(),
+                    actual_output: String::new(),
+                    passed: false,
+                    execution_time_ms: None,
+                    error_message: Some("Test execution is disabled".to_string()),
+                })
+                .collect());
         }
 
-        Ok(results)
+        warn!(
+            "enable_unsandboxed_test_execution is set: running LLM-generated code for \
+             problem {} with this process's full filesystem/network privileges -- only \
+             resource limits (timeout, memory), no security isolation",
+            problem.id
+        );
+
+        let validator = SolutionValidator::new(
+            self.config.execution_timeout,
+            self.config.execution_memory_limit_mb,
+        );
+        validator.validate(solution, &problem.test_cases).await
     }
 
     fn generate_cache_key(&self, problem: &DetectedCodingProblem) -> String {
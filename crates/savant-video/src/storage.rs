@@ -1,7 +1,15 @@
+use crate::config::FrameFormat;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::fs;
+use tokio::sync::Mutex;
+
+/// How many recently stored frame hashes to keep per session for near-duplicate
+/// detection. Bounded so long-running sessions don't grow this unboundedly.
+const DEDUP_WINDOW_PER_SESSION: usize = 20;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StorageSettings {
@@ -9,6 +17,15 @@ pub struct StorageSettings {
     pub max_storage_gb: u32,
     pub retention_days: u32,
     pub cleanup_on_start: bool,
+    /// Maximum dHash Hamming distance (0-64) for two frames to be considered
+    /// near-duplicates. Lower is stricter. 0 disables dedup entirely.
+    pub dedup_hamming_threshold: u32,
+    /// Per-application retention overrides (application name -> max age in days),
+    /// consulted before `activity_retention_days`. The most specific match wins: an
+    /// app-specific rule beats an activity-specific rule, which beats `retention_days`.
+    pub app_retention_days: HashMap<String, u32>,
+    /// Per-activity-type retention overrides (activity type -> max age in days).
+    pub activity_retention_days: HashMap<String, u32>,
 }
 
 impl Default for StorageSettings {
@@ -23,17 +40,54 @@ impl Default for StorageSettings {
             max_storage_gb: 10,
             retention_days: 30,
             cleanup_on_start: true,
+            dedup_hamming_threshold: 5,
+            app_retention_days: HashMap::new(),
+            activity_retention_days: HashMap::new(),
         }
     }
 }
 
+/// Application/activity context recorded alongside a saved frame, so
+/// [`StorageManager::cleanup_old_files`] can apply
+/// [`StorageSettings::app_retention_days`]/[`StorageSettings::activity_retention_days`]
+/// instead of always falling back to the global [`StorageSettings::retention_days`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RetentionContext {
+    active_application: Option<String>,
+    activity_type: Option<String>,
+}
+
+/// Cumulative storage bookkeeping for a [`StorageManager`], notably how much
+/// writing was skipped by near-duplicate frame detection.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct StorageStats {
+    pub storage_saved_bytes: u64,
+    pub frames_deduplicated: u64,
+}
+
 pub struct StorageManager {
     settings: StorageSettings,
+    recent_frames: Mutex<HashMap<String, Vec<(u64, PathBuf)>>>,
+    storage_saved_bytes: AtomicU64,
+    frames_deduplicated: AtomicU64,
 }
 
 impl StorageManager {
     pub fn new(settings: StorageSettings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            recent_frames: Mutex::new(HashMap::new()),
+            storage_saved_bytes: AtomicU64::new(0),
+            frames_deduplicated: AtomicU64::new(0),
+        }
+    }
+
+    /// Snapshot of dedup savings accumulated since this manager was created.
+    pub fn stats(&self) -> StorageStats {
+        StorageStats {
+            storage_saved_bytes: self.storage_saved_bytes.load(Ordering::Relaxed),
+            frames_deduplicated: self.frames_deduplicated.load(Ordering::Relaxed),
+        }
     }
 
     pub async fn initialize(&self) -> Result<()> {
@@ -68,19 +122,169 @@ impl StorageManager {
         Ok(session_dir)
     }
 
-    pub async fn save_frame(&self, session_id: &str, frame_data: &[u8]) -> Result<PathBuf> {
+    /// Saves `frame_data` (decoded and re-encoded as needed) under `format`. When
+    /// `lossless` is `true`, `format` is overridden to [`FrameFormat::Png`] regardless
+    /// of what was requested, for frames where fidelity matters more than size (e.g.
+    /// code screenshots flagged for OCR).
+    ///
+    /// Before writing, the frame's dHash is compared against recently stored frames in
+    /// the same session; if one is within `dedup_hamming_threshold`, that frame's path
+    /// is returned instead of writing a near-duplicate copy (see [`Self::stats`]).
+    pub async fn save_frame(
+        &self,
+        session_id: &str,
+        frame_data: &[u8],
+        format: FrameFormat,
+        lossless: bool,
+    ) -> Result<PathBuf> {
+        let format = if lossless { FrameFormat::Png } else { format };
+        let decoded = image::load_from_memory(frame_data)
+            .context("Failed to decode frame for dedup hashing")?;
+        let hash = Self::perceptual_hash(&decoded);
+
+        if self.settings.dedup_hamming_threshold > 0 {
+            let recent_frames = self.recent_frames.lock().await;
+            if let Some(existing_path) = recent_frames.get(session_id).and_then(|entries| {
+                entries
+                    .iter()
+                    .rev()
+                    .find(|(h, _)| hamming_distance(*h, hash) <= self.settings.dedup_hamming_threshold)
+                    .map(|(_, path)| path.clone())
+            }) {
+                self.storage_saved_bytes
+                    .fetch_add(frame_data.len() as u64, Ordering::Relaxed);
+                self.frames_deduplicated.fetch_add(1, Ordering::Relaxed);
+                return Ok(existing_path);
+            }
+        }
+
         let session_dir = self.get_session_dir(session_id).await?;
         let timestamp = chrono::Utc::now().timestamp_millis();
-        let filename = format!("screenshot_{}_{}.png", timestamp, session_id);
+        let filename = format!(
+            "screenshot_{}_{}.{}",
+            timestamp,
+            session_id,
+            format.extension()
+        );
         let file_path = session_dir.join(filename);
+        let encoded = Self::encode_frame(&decoded, frame_data, format)?;
 
-        fs::write(&file_path, frame_data)
+        fs::write(&file_path, encoded)
             .await
             .context("Failed to save frame")?;
 
+        let mut recent_frames = self.recent_frames.lock().await;
+        let entries = recent_frames.entry(session_id.to_string()).or_default();
+        entries.push((hash, file_path.clone()));
+        if entries.len() > DEDUP_WINDOW_PER_SESSION {
+            entries.remove(0);
+        }
+
+        Ok(file_path)
+    }
+
+    /// Like [`Self::save_frame`], but records `active_application`/`activity_type` in a
+    /// sidecar file next to the frame so [`Self::cleanup_old_files`] can resolve a
+    /// per-category retention age for it instead of always falling back to the global
+    /// [`StorageSettings::retention_days`].
+    pub async fn save_frame_with_context(
+        &self,
+        session_id: &str,
+        frame_data: &[u8],
+        format: FrameFormat,
+        lossless: bool,
+        active_application: Option<&str>,
+        activity_type: Option<&str>,
+    ) -> Result<PathBuf> {
+        let file_path = self.save_frame(session_id, frame_data, format, lossless).await?;
+        let context = RetentionContext {
+            active_application: active_application.map(String::from),
+            activity_type: activity_type.map(String::from),
+        };
+        fs::write(Self::sidecar_path(&file_path), serde_json::to_string(&context)?)
+            .await
+            .context("Failed to save retention context")?;
         Ok(file_path)
     }
 
+    fn sidecar_path(frame_path: &Path) -> PathBuf {
+        let mut name = frame_path.file_name().unwrap_or_default().to_os_string();
+        name.push(".retention.json");
+        frame_path.with_file_name(name)
+    }
+
+    async fn read_retention_context(frame_path: &Path) -> RetentionContext {
+        fs::read_to_string(Self::sidecar_path(frame_path))
+            .await
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Resolves the retention age (in days) for a frame, preferring an app-specific
+    /// rule, then an activity-specific rule, then the global default.
+    fn retention_days_for(&self, context: &RetentionContext) -> u32 {
+        if let Some(app) = &context.active_application {
+            if let Some(days) = self.settings.app_retention_days.get(app) {
+                return *days;
+            }
+        }
+        if let Some(activity) = &context.activity_type {
+            if let Some(days) = self.settings.activity_retention_days.get(activity) {
+                return *days;
+            }
+        }
+        self.settings.retention_days
+    }
+
+    /// Computes a dHash (difference hash): shrink to 9x8 grayscale and record, for each
+    /// row, whether each pixel is darker than its right neighbor. Near-identical images
+    /// (including ones re-compressed at different quality) land within a small Hamming
+    /// distance of each other, unlike a cryptographic hash of the raw bytes.
+    fn perceptual_hash(image: &image::DynamicImage) -> u64 {
+        let small = image
+            .resize_exact(9, 8, image::imageops::FilterType::Triangle)
+            .to_luma8();
+        let mut hash = 0u64;
+        for y in 0..8 {
+            for x in 0..8 {
+                hash <<= 1;
+                if small.get_pixel(x, y)[0] < small.get_pixel(x + 1, y)[0] {
+                    hash |= 1;
+                }
+            }
+        }
+        hash
+    }
+
+    /// Re-encodes the already-decoded `image` into `format`. `frame_data` is reused
+    /// as-is for [`FrameFormat::Png`] to avoid a lossless round-trip that would just
+    /// reproduce the same bytes at extra cost.
+    fn encode_frame(image: &image::DynamicImage, frame_data: &[u8], format: FrameFormat) -> Result<Vec<u8>> {
+        match format {
+            FrameFormat::Png => Ok(frame_data.to_vec()),
+            FrameFormat::Jpeg { quality } => {
+                let mut encoded = Vec::new();
+                let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(
+                    &mut encoded,
+                    quality,
+                );
+                image.write_with_encoder(encoder)
+                    .context("Failed to encode frame as JPEG")?;
+                Ok(encoded)
+            }
+            // image's WebP encoder is lossless-only, so `quality` is accepted for
+            // forward compatibility but has no effect yet.
+            FrameFormat::WebP { quality: _ } => {
+                let mut encoded = Vec::new();
+                let encoder = image::codecs::webp::WebPEncoder::new_lossless(&mut encoded);
+                image.write_with_encoder(encoder)
+                    .context("Failed to encode frame as WebP")?;
+                Ok(encoded)
+            }
+        }
+    }
+
     pub async fn save_metadata(&self, session_id: &str, metadata: &serde_json::Value) -> Result<()> {
         let session_dir = self.get_session_dir(session_id).await?;
         let metadata_path = session_dir.join(format!("metadata_{}.json", session_id));
@@ -93,22 +297,49 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Removes frames (and other files) past their resolved retention age. A frame
+    /// saved via [`Self::save_frame_with_context`] is checked against
+    /// [`StorageSettings::app_retention_days`]/[`StorageSettings::activity_retention_days`]
+    /// (most specific wins); anything else falls back to the global
+    /// [`StorageSettings::retention_days`]. Directories left empty by the sweep are
+    /// removed too.
     pub async fn cleanup_old_files(&self) -> Result<()> {
-        let cutoff_date = chrono::Local::now() - chrono::Duration::days(self.settings.retention_days as i64);
-        
-        let mut entries = fs::read_dir(&self.settings.base_path).await?;
+        let now = chrono::Local::now();
+        self.cleanup_dir(&self.settings.base_path, now).await
+    }
+
+    async fn cleanup_dir(&self, dir: &Path, now: chrono::DateTime<chrono::Local>) -> Result<()> {
+        let mut entries = fs::read_dir(dir).await?;
+        let mut paths = Vec::new();
         while let Some(entry) = entries.next_entry().await? {
-            if let Ok(metadata) = entry.metadata().await {
-                if let Ok(modified) = metadata.modified() {
-                    let modified_date: chrono::DateTime<chrono::Local> = modified.into();
-                    if modified_date < cutoff_date {
-                        if entry.path().is_dir() {
-                            fs::remove_dir_all(entry.path()).await?;
-                        } else {
-                            fs::remove_file(entry.path()).await?;
-                        }
-                    }
-                }
+            paths.push(entry.path());
+        }
+
+        for path in &paths {
+            if path.is_dir() {
+                Box::pin(self.cleanup_dir(path, now)).await?;
+                continue;
+            }
+            // Sidecars are removed alongside their frame, not evaluated independently.
+            if path.to_string_lossy().ends_with(".retention.json") {
+                continue;
+            }
+
+            let metadata = fs::metadata(path).await?;
+            let modified: chrono::DateTime<chrono::Local> = metadata.modified()?.into();
+            let context = Self::read_retention_context(path).await;
+            let cutoff = now - chrono::Duration::days(self.retention_days_for(&context) as i64);
+
+            if modified < cutoff {
+                fs::remove_file(path).await?;
+                let _ = fs::remove_file(Self::sidecar_path(path)).await;
+            }
+        }
+
+        if dir != self.settings.base_path {
+            let mut remaining = fs::read_dir(dir).await?;
+            if remaining.next_entry().await?.is_none() {
+                fs::remove_dir(dir).await?;
             }
         }
 
@@ -133,4 +364,176 @@ impl StorageManager {
         }
         Ok(())
     }
+}
+
+fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, RgbImage};
+
+    fn sample_png_bytes() -> Vec<u8> {
+        png_bytes_from(|x, y| image::Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8]))
+    }
+
+    fn png_bytes_from(pixel: impl Fn(u32, u32) -> image::Rgb<u8>) -> Vec<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_fn(256, 256, pixel));
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_save_frame_as_jpeg_is_substantially_smaller_than_png() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let settings = StorageSettings {
+            base_path: temp_dir.path().to_path_buf(),
+            ..StorageSettings::default()
+        };
+        let manager = StorageManager::new(settings);
+        let png_bytes = sample_png_bytes();
+
+        let png_path = manager
+            .save_frame("session-png", &png_bytes, FrameFormat::Png, false)
+            .await
+            .unwrap();
+        let jpeg_path = manager
+            .save_frame("session-jpeg", &png_bytes, FrameFormat::Jpeg { quality: 75 }, false)
+            .await
+            .unwrap();
+
+        let png_size = fs::metadata(&png_path).await.unwrap().len();
+        let jpeg_size = fs::metadata(&jpeg_path).await.unwrap().len();
+
+        assert!(jpeg_path.extension().unwrap() == "jpg");
+        assert!(
+            jpeg_size < png_size / 2,
+            "expected JPEG ({jpeg_size} bytes) to be substantially smaller than PNG ({png_size} bytes)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_frame_forces_png_when_lossless_requested() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let settings = StorageSettings {
+            base_path: temp_dir.path().to_path_buf(),
+            ..StorageSettings::default()
+        };
+        let manager = StorageManager::new(settings);
+        let png_bytes = sample_png_bytes();
+
+        let path = manager
+            .save_frame("session", &png_bytes, FrameFormat::Jpeg { quality: 50 }, true)
+            .await
+            .unwrap();
+
+        assert_eq!(path.extension().unwrap(), "png");
+    }
+
+    fn count_files(dir: &Path) -> usize {
+        let mut count = 0;
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let entry = entry.unwrap();
+            if entry.path().is_dir() {
+                count += count_files(&entry.path());
+            } else {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[tokio::test]
+    async fn test_save_frame_dedups_near_identical_frames() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let settings = StorageSettings {
+            base_path: temp_dir.path().to_path_buf(),
+            ..StorageSettings::default()
+        };
+        let manager = StorageManager::new(settings);
+
+        let frame_a = png_bytes_from(|x, y| image::Rgb([(x % 256) as u8, (y % 256) as u8, 0]));
+        // Differs from frame_a by a single pixel - should hash within the dedup threshold.
+        let frame_b = png_bytes_from(|x, y| {
+            if x == 0 && y == 0 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([(x % 256) as u8, (y % 256) as u8, 0])
+            }
+        });
+        // Inverted gradient - should hash well outside the dedup threshold.
+        let frame_c = png_bytes_from(|x, y| {
+            image::Rgb([255 - (x % 256) as u8, 255 - (y % 256) as u8, 255])
+        });
+
+        let path_a = manager.save_frame("session", &frame_a, FrameFormat::Png, false).await.unwrap();
+        let path_b = manager.save_frame("session", &frame_b, FrameFormat::Png, false).await.unwrap();
+        let path_c = manager.save_frame("session", &frame_c, FrameFormat::Png, false).await.unwrap();
+
+        assert_eq!(path_a, path_b, "near-identical frame should reuse the existing file");
+        assert_ne!(path_a, path_c, "substantially different frame should get its own file");
+        assert_eq!(count_files(temp_dir.path()), 2);
+
+        let stats = manager.stats();
+        assert_eq!(stats.frames_deduplicated, 1);
+        assert_eq!(stats.storage_saved_bytes, frame_b.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_honors_per_app_retention_over_global_default() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut app_retention_days = HashMap::new();
+        app_retention_days.insert("VSCode".to_string(), 90); // keep coding frames much longer
+        let settings = StorageSettings {
+            base_path: temp_dir.path().to_path_buf(),
+            retention_days: 1, // global default: everything else expires fast
+            dedup_hamming_threshold: 0, // each save below must produce its own file
+            app_retention_days,
+            ..StorageSettings::default()
+        };
+        let manager = StorageManager::new(settings);
+
+        let coding_frame = manager
+            .save_frame_with_context(
+                "session",
+                &sample_png_bytes(),
+                FrameFormat::Png,
+                false,
+                Some("VSCode"),
+                None,
+            )
+            .await
+            .unwrap();
+        let idle_frame = manager
+            .save_frame_with_context(
+                "session",
+                &png_bytes_from(|x, y| image::Rgb([0, (x % 256) as u8, (y % 256) as u8])),
+                FrameFormat::Png,
+                false,
+                Some("Finder"),
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Backdate both frames (and their sidecars) past the global retention window
+        // but within the VSCode-specific one.
+        let old_time = filetime::FileTime::from_system_time(
+            std::time::SystemTime::now() - std::time::Duration::from_secs(3 * 24 * 60 * 60),
+        );
+        for path in [&coding_frame, &idle_frame] {
+            filetime::set_file_mtime(path, old_time).unwrap();
+            filetime::set_file_mtime(StorageManager::sidecar_path(path), old_time).unwrap();
+        }
+
+        manager.cleanup_old_files().await.unwrap();
+
+        assert!(coding_frame.exists(), "VSCode frame should survive its longer retention window");
+        assert!(!idle_frame.exists(), "Finder frame should be removed under the global retention window");
+    }
 }
\ No newline at end of file
@@ -56,6 +56,73 @@ fn create_test_ocr_result(text_content: &str) -> ComprehensiveOCRResult {
     }
 }
 
+/// Like `create_test_ocr_result`, but spreads the given texts across
+/// separate paragraphs (each on its own vertical band) instead of packing
+/// everything into one. Needed for structural-detection tests, which judge
+/// a screen by the title/statement/constraints/example/code split between
+/// paragraphs rather than by platform keywords.
+fn create_test_ocr_result_multi(paragraphs: &[&str]) -> ComprehensiveOCRResult {
+    let mut words = Vec::new();
+    let mut lines = Vec::new();
+    let mut paragraph_data = Vec::new();
+    let mut y = 50u32;
+
+    for (paragraph_id, text) in paragraphs.iter().enumerate() {
+        let paragraph_words: Vec<WordData> = text
+            .split_whitespace()
+            .enumerate()
+            .map(|(i, word)| WordData {
+                text: word.to_string(),
+                confidence: 0.95,
+                bounding_box: OcrBoundingBox {
+                    x: (i * 50) as u32,
+                    y,
+                    width: 40,
+                    height: 20,
+                },
+                font_size_estimate: Some(12.0),
+                text_type: None,
+                line_id: paragraph_id,
+                paragraph_id,
+            })
+            .collect();
+
+        let bounding_box = OcrBoundingBox {
+            x: 0,
+            y,
+            width: 800,
+            height: 100,
+        };
+
+        lines.push(LineData {
+            text: text.to_string(),
+            bounding_box: bounding_box.clone(),
+            confidence: 0.95,
+            word_count: paragraph_words.len(),
+        });
+
+        paragraph_data.push(ParagraphData {
+            text: text.to_string(),
+            bounding_box,
+            confidence: 0.95,
+            line_count: 1,
+        });
+
+        words.extend(paragraph_words);
+        y += 100;
+    }
+
+    ComprehensiveOCRResult {
+        raw_text: paragraphs.join("\n\n"),
+        words,
+        lines,
+        paragraphs: paragraph_data,
+        screen_regions: vec![],
+        confidence: 0.95,
+        processing_time_ms: 100,
+    }
+}
+
 fn create_test_vision_analysis() -> ScreenAnalysis {
     ScreenAnalysis {
         timestamp: Utc::now(),
@@ -242,6 +309,132 @@ async fn test_detect_leetcode_challenge() {
     assert_eq!(problems[0].test_cases.len(), 2);
 }
 
+#[tokio::test]
+async fn test_detect_codeforces_challenge() {
+    let mut detector = CodingProblemDetector::new(DetectionConfig::default());
+
+    let challenge_text = "1234A. Watermelon
+
+        Time limit per test: 1 second
+        Memory limit per test: 256 megabytes
+
+        Read input from standard input, write output to standard output.
+
+        One hot summer day Pete and his friend Billy decided to buy a watermelon.";
+
+    let ocr_result = create_test_ocr_result(challenge_text);
+    let mut vision_analysis = create_test_vision_analysis();
+    vision_analysis.app_context.detected_applications[0].app_name = Some("Chrome - Codeforces".to_string());
+
+    let problems = detector.detect_problems(&ocr_result, &vision_analysis).await.unwrap();
+
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0].problem_type, CodingProblemType::AlgorithmChallenge));
+    assert_eq!(problems[0].platform, Some(CodingPlatform::Codeforces));
+    assert!(problems[0].title.starts_with("1234A."));
+}
+
+#[tokio::test]
+async fn test_detect_codesignal_challenge() {
+    let mut detector = CodingProblemDetector::new(DetectionConfig::default());
+
+    let challenge_text = "CodeSignal Arcade - Certified Assessment
+
+        Problem Statement
+        Given an array, find the largest pair sum.
+
+        Example 1:
+        Input: [1, 2, 3]
+        Output: 5";
+
+    let ocr_result = create_test_ocr_result(challenge_text);
+    let mut vision_analysis = create_test_vision_analysis();
+    vision_analysis.app_context.detected_applications[0].app_name = Some("Chrome - CodeSignal".to_string());
+
+    let problems = detector.detect_problems(&ocr_result, &vision_analysis).await.unwrap();
+
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0].problem_type, CodingProblemType::AlgorithmChallenge));
+    assert_eq!(problems[0].platform, Some(CodingPlatform::CodeSignal));
+}
+
+#[tokio::test]
+async fn test_detect_advent_of_code_challenge() {
+    let mut detector = CodingProblemDetector::new(DetectionConfig::default());
+
+    let challenge_text = "Advent of Code - Day 1: Sonar Sweep ---
+
+        As the submarine drops below the surface of the ocean, it shifts to
+        rely on its sonar to help navigate.
+
+        Your puzzle answer was 1233.
+        Puzzle Input:
+        199
+        200
+        208";
+
+    let ocr_result = create_test_ocr_result(challenge_text);
+    let mut vision_analysis = create_test_vision_analysis();
+    vision_analysis.app_context.detected_applications[0].app_name = Some("Chrome - Advent of Code".to_string());
+
+    let problems = detector.detect_problems(&ocr_result, &vision_analysis).await.unwrap();
+
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0].problem_type, CodingProblemType::AlgorithmChallenge));
+    assert_eq!(problems[0].platform, Some(CodingPlatform::AdventOfCode));
+}
+
+#[tokio::test]
+async fn test_detect_structural_problem_valid_parentheses() {
+    let mut detector = CodingProblemDetector::new(DetectionConfig::default());
+
+    let ocr_result = create_test_ocr_result_multi(&[
+        "Valid Parentheses",
+        "Given a string containing just the characters parentheses and brackets \
+         determine whether the string is valid according to matching order rules \
+         A string is considered valid when every opening symbol has a corresponding \
+         closing symbol in the correct sequence",
+        "Constraints 1 <= n <= 100 where n represents the length of the input string",
+        "Example 1\nInput: s = ()[]{}\nOutput: true",
+        "def is_valid(s):\n    stack = []\n    for char in s:\n        if char in '([{':\n            stack.append(char)\n    return len(stack) == 0",
+    ]);
+    let vision_analysis = create_test_vision_analysis();
+
+    let problems = detector.detect_problems(&ocr_result, &vision_analysis).await.unwrap();
+
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0].problem_type, CodingProblemType::AlgorithmChallenge));
+    assert_eq!(problems[0].title, "Valid Parentheses");
+    assert!(problems[0].confidence >= DetectionConfig::default().min_confidence_threshold);
+    assert!(!problems[0].constraints.is_empty());
+    assert!(!problems[0].code_context.visible_code.is_empty());
+}
+
+#[tokio::test]
+async fn test_detect_structural_problem_maximum_subarray() {
+    let mut detector = CodingProblemDetector::new(DetectionConfig::default());
+
+    let ocr_result = create_test_ocr_result_multi(&[
+        "Maximum Subarray",
+        "Given an integer array that may contain negative numbers compute the \
+         contiguous subarray which has the largest possible sum and return that \
+         sum as the final answer",
+        "Limits 1 <= n <= 1000 where n denotes the size of the array",
+        "Example 1\nInput: nums = -2 1 -3 4 -1 2 1 -5 4\nOutput: 6",
+        "def max_subarray(nums):\n    best = nums[0]\n    current = nums[0]\n    for num in nums[1:]:\n        current = max(num, current + num)\n        if current > best:\n            best = current\n    return best",
+    ]);
+    let vision_analysis = create_test_vision_analysis();
+
+    let problems = detector.detect_problems(&ocr_result, &vision_analysis).await.unwrap();
+
+    assert_eq!(problems.len(), 1);
+    assert!(matches!(problems[0].problem_type, CodingProblemType::AlgorithmChallenge));
+    assert_eq!(problems[0].title, "Maximum Subarray");
+    assert!(problems[0].confidence >= DetectionConfig::default().min_confidence_threshold);
+    assert!(!problems[0].constraints.is_empty());
+    assert!(!problems[0].code_context.visible_code.is_empty());
+}
+
 #[tokio::test]
 async fn test_detect_test_failure() {
     let mut detector = CodingProblemDetector::new(DetectionConfig::default());
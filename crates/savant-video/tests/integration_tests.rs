@@ -1,7 +1,9 @@
 use savant_video::*;
 use savant_video::llm_provider::{LLMProvider, MockLLMProvider};
+use savant_video::processor::{batch_process_existing_files, BatchProgress};
 use tempfile::TempDir;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
 use chrono::Utc;
 
 async fn setup_test_processor() -> (IntegratedProcessor, tokio::sync::mpsc::UnboundedReceiver<ProcessingEvent>) {
@@ -83,6 +85,7 @@ async fn test_full_processing_pipeline() {
             detected_applications: vec![],
             activity_classification: None,
             visual_context: None,
+            frame_format: FrameFormat::Png,
         },
     };
     
@@ -142,6 +145,7 @@ async fn test_coding_problem_detection_with_real_screenshot() {
             detected_applications: vec![],
             activity_classification: None,
             visual_context: None,
+            frame_format: FrameFormat::Png,
         },
     };
     
@@ -195,6 +199,7 @@ async fn test_change_detection() {
             detected_applications: vec![],
             activity_classification: None,
             visual_context: None,
+            frame_format: FrameFormat::Png,
         },
     };
     
@@ -344,6 +349,7 @@ async fn test_multiple_screenshots_processing() {
                 detected_applications: vec![],
                 activity_classification: None,
                 visual_context: None,
+                frame_format: FrameFormat::Png,
             },
         };
         
@@ -410,4 +416,70 @@ async fn test_concurrent_frame_processing() {
     
     // Should be reasonably fast even for multiple frames
     assert!(avg_time_per_frame < 5000, "Processing should be efficient");
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn test_batch_process_existing_files_reports_structured_progress_events() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Two readable PNGs, sorted before the unreadable one.
+    let good_image = image::DynamicImage::new_rgb8(16, 16);
+    let good_path_a = temp_dir.path().join("a_good.png");
+    let good_path_b = temp_dir.path().join("b_good.png");
+    good_image.save(&good_path_a).unwrap();
+    good_image.save(&good_path_b).unwrap();
+
+    // A PNG-named file with its read permissions revoked, so loading it fails.
+    let bad_path = temp_dir.path().join("c_unreadable.png");
+    std::fs::write(&bad_path, b"not a real png").unwrap();
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&bad_path).unwrap().permissions();
+        perms.set_mode(0o000);
+        std::fs::set_permissions(&bad_path, perms).unwrap();
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let progress_callback: Box<dyn Fn(BatchProgress) + Send + Sync> =
+        Box::new(move |event| events_clone.lock().unwrap().push(event));
+
+    let config = CaptureConfig::default();
+    let result =
+        batch_process_existing_files(temp_dir.path(), config, Some(progress_callback)).await;
+
+    // Restore permissions so the TempDir can clean itself up on drop.
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(&bad_path).unwrap().permissions();
+        perms.set_mode(0o644);
+        std::fs::set_permissions(&bad_path, perms).unwrap();
+    }
+
+    let frames = result.unwrap();
+    assert_eq!(frames.len(), 2, "the unreadable file should be skipped, not abort the batch");
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 5, "Started + 2 FileDone + 1 FileError + Finished");
+
+    assert!(matches!(events[0], BatchProgress::Started { total: 3 }));
+
+    let errors: Vec<_> = events
+        .iter()
+        .filter(|e| matches!(e, BatchProgress::FileError { .. }))
+        .collect();
+    assert_eq!(errors.len(), 1);
+    if let BatchProgress::FileError { path, .. } = errors[0] {
+        assert_eq!(path, &bad_path);
+    }
+
+    let done: Vec<_> = events
+        .iter()
+        .filter(|e| matches!(e, BatchProgress::FileDone { .. }))
+        .collect();
+    assert_eq!(done.len(), 2);
+
+    assert!(matches!(
+        events[4],
+        BatchProgress::Finished { succeeded: 2, failed: 1 }
+    ));
+}
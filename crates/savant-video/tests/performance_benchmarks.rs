@@ -71,6 +71,7 @@ async fn benchmark_single_frame_processing() {
             detected_applications: vec![],
             activity_classification: None,
             visual_context: None,
+            frame_format: FrameFormat::Png,
         },
     };
     
@@ -188,6 +189,7 @@ async fn benchmark_with_real_screenshots() {
                 detected_applications: vec![],
                 activity_classification: None,
                 visual_context: None,
+                frame_format: FrameFormat::Png,
             },
         };
         
@@ -0,0 +1,67 @@
+//! Exercises the same OCR-block -> sensitivity check -> redact pipeline the
+//! video capture daemon runs in production (`savant-video-cli/src/main.rs`),
+//! rather than just `privacy.rs`'s isolated unit tests which hand-construct
+//! already-flagged elements.
+
+use image::DynamicImage;
+use savant_video::{PrivacyController, PrivacySettings};
+use savant_vision::{BoundingBox, ElementProperties, ElementType, VisualElement};
+
+/// Builds the `VisualElement`s the daemon builds from OCR text blocks: one
+/// per block, `is_sensitive` set by `PrivacyController::is_sensitive_content`.
+fn elements_from_ocr_text(controller: &PrivacyController, blocks: &[(&str, BoundingBox)]) -> Vec<VisualElement> {
+    blocks
+        .iter()
+        .map(|(text, bbox)| VisualElement {
+            element_type: ElementType::TextField,
+            bounding_box: bbox.clone(),
+            properties: ElementProperties {
+                color_scheme: None,
+                text_content: Some(text.to_string()),
+                is_interactive: false,
+                state: None,
+                app_context: None,
+                is_sensitive: controller.is_sensitive_content(Some(text)),
+            },
+            confidence: bbox.confidence,
+        })
+        .collect()
+}
+
+#[test]
+fn test_ocr_derived_elements_redact_sensitive_text_and_leave_the_rest() {
+    let controller = PrivacyController::new(PrivacySettings::default());
+
+    let blocks = [
+        (
+            "password: hunter2",
+            BoundingBox { x: 1, y: 1, width: 3, height: 3, confidence: 0.95 },
+        ),
+        (
+            "Welcome back!",
+            BoundingBox { x: 6, y: 6, width: 3, height: 3, confidence: 0.95 },
+        ),
+    ];
+    let elements = elements_from_ocr_text(&controller, &blocks);
+    assert!(elements[0].properties.is_sensitive);
+    assert!(!elements[1].properties.is_sensitive);
+
+    let original = image::RgbaImage::from_pixel(10, 10, image::Rgba([200, 100, 50, 255]));
+    let mut image = DynamicImage::ImageRgba8(original.clone());
+
+    controller.redact(&mut image, &elements);
+
+    let redacted = image.to_rgba8();
+    // The password field's region is blacked out...
+    for y in 1..4 {
+        for x in 1..4 {
+            assert_eq!(*redacted.get_pixel(x, y), image::Rgba([0, 0, 0, 255]));
+        }
+    }
+    // ...but the non-sensitive greeting's region is untouched.
+    for y in 6..9 {
+        for x in 6..9 {
+            assert_eq!(*redacted.get_pixel(x, y), *original.get_pixel(x, y));
+        }
+    }
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use image::{DynamicImage, GenericImageView};
 use serde::{Deserialize, Serialize};
 
-use crate::{AppContext, VisualElement};
+use crate::{AppContext, BoundingBox, ElementType, VisualElement};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisualContext {
@@ -180,8 +180,8 @@ impl ContextAnalyzer {
     ) -> Result<VisualContext> {
         
         // Analyze colors and theme
-        let theme_info = self.color_analyzer.analyze_theme(image)?;
-        let dominant_colors = self.color_analyzer.extract_dominant_colors(image)?;
+        let theme_info = self.color_analyzer.analyze_theme(image, visual_elements)?;
+        let dominant_colors = self.color_analyzer.extract_dominant_colors(image, visual_elements)?;
 
         // Analyze layout structure
         let layout_analysis = self.layout_analyzer.analyze_layout(image, visual_elements)?;
@@ -315,36 +315,48 @@ impl ColorAnalyzer {
         Self
     }
 
-    fn analyze_theme(&self, image: &DynamicImage) -> Result<ThemeInfo> {
+    /// Builds a luminance histogram over UI-chrome pixels (everything that
+    /// isn't a large image/video element) and decides dark mode from the
+    /// modal luminance bucket. A photo or gradient dominating the frame no
+    /// longer skews the average toward "light" just because it's bright -
+    /// only the actual chrome is sampled.
+    fn analyze_theme(&self, image: &DynamicImage, visual_elements: &[VisualElement]) -> Result<ThemeInfo> {
         let rgba_image = image.to_rgba8();
-        let mut color_counts: std::collections::HashMap<[u8; 3], u32> = std::collections::HashMap::new();
-        
-        // Sample pixels to determine dominant colors
-        let sample_rate = 10;
-        for (i, pixel) in rgba_image.pixels().enumerate() {
-            if i % sample_rate == 0 {
-                let rgb = [pixel[0], pixel[1], pixel[2]];
-                *color_counts.entry(rgb).or_insert(0) += 1;
-            }
+        let excluded = media_regions(visual_elements);
+
+        const BUCKET_COUNT: usize = 32;
+        const BUCKET_WIDTH: u32 = 256 / BUCKET_COUNT as u32;
+        let mut histogram = [0u32; BUCKET_COUNT];
+        let mut color_counts = sample_chrome_colors(&rgba_image, &excluded);
+
+        // If the whole frame is covered by media, fall back to sampling
+        // everything rather than reporting on an empty histogram
+        if color_counts.is_empty() {
+            color_counts = sample_chrome_colors(&rgba_image, &[]);
         }
 
-        // Find dominant background color
+        for (color, count) in &color_counts {
+            let luminance = luminance(color);
+            let bucket = ((luminance * 255.0) as u32 / BUCKET_WIDTH).min(BUCKET_COUNT as u32 - 1);
+            histogram[bucket as usize] += count;
+        }
+
+        let modal_bucket = histogram
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, count)| **count)
+            .map(|(bucket, _)| bucket)
+            .unwrap_or(0);
+        let modal_luminance = (modal_bucket as f32 * BUCKET_WIDTH as f32 + BUCKET_WIDTH as f32 / 2.0) / 255.0;
+        let is_dark_mode = modal_luminance < 0.5;
+
         let most_common_color = color_counts
             .iter()
             .max_by_key(|(_, count)| *count)
             .map(|(color, _)| *color)
             .unwrap_or([255, 255, 255]);
+        let background_color = hex(&most_common_color);
 
-        let background_color = format!("#{:02x}{:02x}{:02x}", 
-            most_common_color[0], most_common_color[1], most_common_color[2]);
-
-        // Determine if dark mode based on background brightness
-        let brightness = (most_common_color[0] as f32 * 0.299 + 
-                         most_common_color[1] as f32 * 0.587 + 
-                         most_common_color[2] as f32 * 0.114) / 255.0;
-        let is_dark_mode = brightness < 0.5;
-
-        // Estimate text color based on theme
         let text_color = if is_dark_mode {
             "#ffffff".to_string()
         } else {
@@ -365,33 +377,77 @@ impl ColorAnalyzer {
         })
     }
 
-    fn extract_dominant_colors(&self, image: &DynamicImage) -> Result<Vec<String>> {
+    /// Top-k dominant colors over UI-chrome pixels, excluding large
+    /// image/video elements so a single bright photo doesn't crowd out the
+    /// chrome's actual palette.
+    fn extract_dominant_colors(&self, image: &DynamicImage, visual_elements: &[VisualElement]) -> Result<Vec<String>> {
         let rgba_image = image.to_rgba8();
-        let mut color_counts: std::collections::HashMap<[u8; 3], u32> = std::collections::HashMap::new();
-        
-        // Sample pixels
-        let sample_rate = 20;
-        for (i, pixel) in rgba_image.pixels().enumerate() {
-            if i % sample_rate == 0 {
-                let rgb = [pixel[0], pixel[1], pixel[2]];
-                *color_counts.entry(rgb).or_insert(0) += 1;
-            }
+        let excluded = media_regions(visual_elements);
+
+        let mut color_counts = sample_chrome_colors(&rgba_image, &excluded);
+        if color_counts.is_empty() {
+            color_counts = sample_chrome_colors(&rgba_image, &[]);
         }
 
-        // Get top 5 most common colors
         let mut sorted_colors: Vec<_> = color_counts.iter().collect();
-        sorted_colors.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        sorted_colors.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
 
         let dominant_colors = sorted_colors
             .iter()
             .take(5)
-            .map(|(color, _)| format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2]))
+            .map(|(color, _)| hex(color))
             .collect();
 
         Ok(dominant_colors)
     }
 }
 
+/// Bounding boxes of large image/video elements, whose pixels should be
+/// excluded from chrome-theme analysis
+fn media_regions(visual_elements: &[VisualElement]) -> Vec<BoundingBox> {
+    visual_elements
+        .iter()
+        .filter(|e| matches!(e.element_type, ElementType::Image | ElementType::Video))
+        .map(|e| e.bounding_box.clone())
+        .collect()
+}
+
+fn is_excluded(x: u32, y: u32, excluded: &[BoundingBox]) -> bool {
+    excluded.iter().any(|region| {
+        x >= region.x && x < region.x + region.width && y >= region.y && y < region.y + region.height
+    })
+}
+
+fn sample_chrome_colors(
+    rgba_image: &image::ImageBuffer<image::Rgba<u8>, Vec<u8>>,
+    excluded: &[BoundingBox],
+) -> std::collections::HashMap<[u8; 3], u32> {
+    let (width, height) = rgba_image.dimensions();
+    let mut color_counts = std::collections::HashMap::new();
+
+    const SAMPLE_STEP: u32 = 4;
+    for y in (0..height).step_by(SAMPLE_STEP as usize) {
+        for x in (0..width).step_by(SAMPLE_STEP as usize) {
+            if is_excluded(x, y, excluded) {
+                continue;
+            }
+            let pixel = rgba_image.get_pixel(x, y);
+            let rgb = [pixel[0], pixel[1], pixel[2]];
+            *color_counts.entry(rgb).or_insert(0) += 1;
+        }
+    }
+
+    color_counts
+}
+
+fn luminance(rgb: &[u8; 3]) -> f32 {
+    (rgb[0] as f32 * 0.299 + rgb[1] as f32 * 0.587 + rgb[2] as f32 * 0.114) / 255.0
+}
+
+fn hex(rgb: &[u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
 #[derive(Debug)]
 struct LayoutAnalyzer;
 
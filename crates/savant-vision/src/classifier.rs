@@ -1,7 +1,9 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use image::DynamicImage;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 use crate::{AppContext, AppType, VisualElement, ElementType};
 
@@ -66,6 +68,13 @@ pub enum Activity {
         screen_saver: bool,
         last_activity_mins: Option<u32>,
     },
+    /// An activity label produced entirely by a user-supplied rule (see
+    /// [`ActivityClassifier::from_rules_file`]) rather than one of the built-in
+    /// variants above.
+    Custom {
+        label: String,
+        category: Option<String>,
+    },
     Unknown,
 }
 
@@ -212,7 +221,6 @@ struct RequiredIndicator {
 
 #[derive(Debug, Clone)]
 struct OptionalIndicator {
-    #[allow(dead_code)]
     indicator_type: IndicatorType,
     pattern: String,
     bonus_weight: f32,
@@ -220,7 +228,6 @@ struct OptionalIndicator {
 
 #[derive(Debug, Clone)]
 struct ExclusionPattern {
-    #[allow(dead_code)]
     indicator_type: IndicatorType,
     pattern: String,
 }
@@ -231,6 +238,121 @@ struct ContextAnalyzer {
     analyzer_type: String,
 }
 
+/// On-disk shape of a rules file loaded by [`ActivityClassifier::from_rules_file`],
+/// selected by extension (`.json` or `.toml`).
+#[derive(Debug, Deserialize)]
+struct RulesFile {
+    #[serde(default)]
+    rules: Vec<CustomRuleSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomRuleSpec {
+    label: String,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default = "default_confidence_threshold")]
+    confidence_threshold: f32,
+    #[serde(default)]
+    required: Vec<IndicatorSpec>,
+    #[serde(default)]
+    optional: Vec<OptionalIndicatorSpec>,
+    #[serde(default)]
+    exclusions: Vec<IndicatorSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndicatorSpec {
+    #[serde(rename = "type")]
+    indicator_type: String,
+    pattern: String,
+    #[serde(default = "default_weight")]
+    weight: f32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OptionalIndicatorSpec {
+    #[serde(rename = "type")]
+    indicator_type: String,
+    pattern: String,
+    #[serde(default = "default_weight")]
+    bonus_weight: f32,
+}
+
+impl CustomRuleSpec {
+    fn into_classification_rule(self) -> Result<ClassificationRule> {
+        let required_indicators = self
+            .required
+            .into_iter()
+            .map(|spec| {
+                Ok(RequiredIndicator {
+                    indicator_type: parse_indicator_type(&spec.indicator_type)?,
+                    pattern: spec.pattern,
+                    weight: spec.weight,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let optional_indicators = self
+            .optional
+            .into_iter()
+            .map(|spec| {
+                Ok(OptionalIndicator {
+                    indicator_type: parse_indicator_type(&spec.indicator_type)?,
+                    pattern: spec.pattern,
+                    bonus_weight: spec.bonus_weight,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let exclusion_patterns = self
+            .exclusions
+            .into_iter()
+            .map(|spec| {
+                Ok(ExclusionPattern {
+                    indicator_type: parse_indicator_type(&spec.indicator_type)?,
+                    pattern: spec.pattern,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ClassificationRule {
+            activity: Activity::Custom { label: self.label, category: self.category },
+            required_indicators,
+            optional_indicators,
+            exclusion_patterns,
+            confidence_threshold: self.confidence_threshold,
+        })
+    }
+}
+
+fn default_confidence_threshold() -> f32 {
+    0.6
+}
+
+fn default_weight() -> f32 {
+    0.5
+}
+
+fn parse_indicator_type(indicator_type: &str) -> Result<IndicatorType> {
+    match indicator_type {
+        "application" => Ok(IndicatorType::ApplicationPresence),
+        "window_title" => Ok(IndicatorType::WindowTitle),
+        "ui_element" => Ok(IndicatorType::VisualElement),
+        "text" => Ok(IndicatorType::TextPattern),
+        other => Err(anyhow!(
+            "unknown indicator type '{other}' (expected application, window_title, ui_element, or text)"
+        )),
+    }
+}
+
+/// Matches `pattern` as a regex against `text`. Invalid patterns are treated as a
+/// non-match rather than a hard error, since a rule's pattern is only validated at
+/// load time in [`ActivityClassifier::from_rules_file`].
+fn indicator_pattern_matches(pattern: &str, text: &str) -> bool {
+    Regex::new(pattern).map(|re| re.is_match(text)).unwrap_or(false)
+}
+
 impl ActivityClassifier {
     pub fn new() -> Self {
         let mut classification_rules = Vec::new();
@@ -323,6 +445,35 @@ impl ActivityClassifier {
         }
     }
 
+    /// Loads additional classification rules from a TOML or JSON file (selected by
+    /// extension; anything other than `.json` is parsed as TOML) and merges them with
+    /// the built-in rules from [`ActivityClassifier::new`]. Lets users teach the
+    /// classifier their own app/window-title/UI mappings (e.g. "Figma" -> a custom
+    /// "Design" activity) without recompiling.
+    ///
+    /// Custom rules are validated and compiled eagerly, so a typo'd indicator type or
+    /// invalid regex is reported here rather than silently never matching at
+    /// classification time.
+    pub fn from_rules_file(path: &Path) -> Result<Self> {
+        let mut classifier = Self::new();
+
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read rules file {}: {}", path.display(), e))?;
+        let rules_file: RulesFile = if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            serde_json::from_str(&contents)
+                .map_err(|e| anyhow!("Failed to parse rules file {} as JSON: {}", path.display(), e))?
+        } else {
+            toml::from_str(&contents)
+                .map_err(|e| anyhow!("Failed to parse rules file {} as TOML: {}", path.display(), e))?
+        };
+
+        for rule_spec in rules_file.rules {
+            classifier.classification_rules.push(rule_spec.into_classification_rule()?);
+        }
+
+        Ok(classifier)
+    }
+
     pub async fn classify_activity(
         &self,
         _image: &DynamicImage,
@@ -344,6 +495,11 @@ impl ActivityClassifier {
         let visual_evidence = self.analyze_visual_elements(visual_elements)?;
         all_evidence.extend(visual_evidence);
 
+        // Analyze window titles (mainly feeds WindowTitle-pattern rules, e.g. custom
+        // rules loaded via `from_rules_file`)
+        let window_evidence = self.analyze_window_titles(&app_context.active_windows);
+        all_evidence.extend(window_evidence);
+
         // Apply classification rules
         for rule in &self.classification_rules {
             let score = self.evaluate_rule(rule, &all_evidence, app_context)?;
@@ -465,6 +621,18 @@ impl ActivityClassifier {
         Ok(evidence)
     }
 
+    fn analyze_window_titles(&self, active_windows: &[crate::WindowInfo]) -> Vec<Evidence> {
+        active_windows
+            .iter()
+            .map(|window| Evidence {
+                evidence_type: EvidenceType::WindowLayout,
+                description: window.title.clone(),
+                confidence: 1.0,
+                weight: 0.5,
+            })
+            .collect()
+    }
+
     fn evaluate_rule(&self, rule: &ClassificationRule, evidence: &[Evidence], _app_context: &AppContext) -> Result<f32> {
         let mut score = 0.0;
         let mut required_met = 0;
@@ -506,20 +674,23 @@ impl ActivityClassifier {
                 IndicatorType::ApplicationPresence => {
                     e.description.contains(&indicator.pattern)
                 }
+                IndicatorType::WindowTitle => indicator_pattern_matches(&indicator.pattern, &e.description),
                 _ => false,
             }
         })
     }
 
     fn evidence_matches_optional(&self, evidence: &[Evidence], indicator: &OptionalIndicator) -> bool {
-        evidence.iter().any(|e| {
-            e.description.contains(&indicator.pattern)
+        evidence.iter().any(|e| match indicator.indicator_type {
+            IndicatorType::WindowTitle => indicator_pattern_matches(&indicator.pattern, &e.description),
+            _ => e.description.contains(&indicator.pattern),
         })
     }
 
     fn evidence_matches_exclusion(&self, evidence: &[Evidence], exclusion: &ExclusionPattern) -> bool {
-        evidence.iter().any(|e| {
-            e.description.contains(&exclusion.pattern)
+        evidence.iter().any(|e| match exclusion.indicator_type {
+            IndicatorType::WindowTitle => indicator_pattern_matches(&exclusion.pattern, &e.description),
+            _ => e.description.contains(&exclusion.pattern),
         })
     }
 
@@ -535,11 +706,22 @@ impl ActivityClassifier {
             Activity::Gaming { .. } => "gaming".to_string(),
             Activity::SystemManagement { .. } => "system_management".to_string(),
             Activity::Idle { .. } => "idle".to_string(),
+            Activity::Custom { label, .. } => format!("custom:{label}"),
             Activity::Unknown => "unknown".to_string(),
         }
     }
 
     fn key_to_activity(&self, key: &str, app_context: &AppContext) -> Result<Activity> {
+        if let Some(label) = key.strip_prefix("custom:") {
+            let custom = self.classification_rules.iter().find_map(|rule| match &rule.activity {
+                Activity::Custom { label: rule_label, .. } if rule_label == label => Some(rule.activity.clone()),
+                _ => None,
+            });
+            if let Some(activity) = custom {
+                return Ok(activity);
+            }
+        }
+
         match key {
             "coding" => {
                 let (language, editor) = self.detect_coding_context(app_context)?;
@@ -628,3 +810,92 @@ impl ActivityClassifier {
         Ok(indicators)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BoundingBox, WindowInfo, WindowState};
+
+    fn app_context_with_window_title(title: &str) -> AppContext {
+        AppContext {
+            detected_applications: Vec::new(),
+            active_windows: vec![WindowInfo {
+                title: title.to_string(),
+                app_name: "Figma".to_string(),
+                bounds: BoundingBox { x: 0, y: 0, width: 100, height: 100, confidence: 1.0 },
+                window_level: 0,
+                is_focused: true,
+                is_minimized: false,
+                is_fullscreen: false,
+            }],
+            browser_context: None,
+            ide_context: None,
+            meeting_context: None,
+            desktop_environment: None,
+        }
+    }
+
+    fn write_rules_file(contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("savant_vision_test_rules_{}_{}.toml", std::process::id(), contents.len()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_custom_rule_from_file_classifies_matching_window_title() {
+        let rules_path = write_rules_file(
+            r#"
+            [[rules]]
+            label = "Design"
+            category = "Productivity"
+            confidence_threshold = 0.5
+
+            [[rules.required]]
+            type = "window_title"
+            pattern = "(?i)figma"
+            weight = 0.8
+            "#,
+        );
+
+        let classifier = ActivityClassifier::from_rules_file(&rules_path).unwrap();
+        std::fs::remove_file(&rules_path).ok();
+
+        let app_context = app_context_with_window_title("Untitled - Figma");
+        let classification = classifier
+            .classify_activity(
+                &DynamicImage::new_rgb8(1, 1),
+                &app_context,
+                &[],
+            )
+            .await
+            .unwrap();
+
+        match classification.primary_activity {
+            Activity::Custom { label, category } => {
+                assert_eq!(label, "Design");
+                assert_eq!(category.as_deref(), Some("Productivity"));
+            }
+            other => panic!("expected a custom Design activity, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_rules_file_rejects_unknown_indicator_type() {
+        let rules_path = write_rules_file(
+            r#"
+            [[rules]]
+            label = "Design"
+
+            [[rules.required]]
+            type = "not_a_real_type"
+            pattern = "figma"
+            "#,
+        );
+
+        let result = ActivityClassifier::from_rules_file(&rules_path);
+        std::fs::remove_file(&rules_path).ok();
+
+        assert!(result.is_err());
+    }
+}
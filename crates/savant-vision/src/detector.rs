@@ -1,9 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use image::{DynamicImage, ImageBuffer, Rgba};
+use image::{DynamicImage, GenericImageView, ImageBuffer, Rgba};
 use serde::{Deserialize, Serialize};
 
-use crate::{AppContext, AppType, BoundingBox, DetectedApp, ElementType, VisualElement, WindowState};
+use crate::{
+    AppContext, AppType, BoundingBox, DetectedApp, ElementType, IDEType, IndicatorType, TabInfo,
+    VisualElement, VisualIndicator, WindowState,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetectionResult {
@@ -161,6 +164,7 @@ impl UIDetector {
                     is_interactive: true,
                     state: None,
                     app_context: None,
+                    is_sensitive: false,
                 },
                 confidence: 0.6,
             }));
@@ -222,6 +226,13 @@ impl AppDetector {
         let app_detections = self.app_signatures.match_signatures(image, visual_elements).await?;
         detected_applications.extend(app_detections);
 
+        // JetBrains IDEs share a near-identical dark theme, so the title bar and
+        // gutter styling (not color alone) are what discriminate them from each
+        // other and from VSCode
+        if let Some(jetbrains_app) = self.detect_jetbrains_ide(image, visual_elements) {
+            detected_applications.push(jetbrains_app);
+        }
+
         // Detect browser context
         let browser_context = self.detect_browser_context(image, visual_elements).await?;
 
@@ -241,9 +252,124 @@ impl AppDetector {
         })
     }
 
-    async fn detect_browser_context(&self, _image: &DynamicImage, _visual_elements: &[VisualElement]) -> Result<Option<crate::BrowserContext>> {
-        // Placeholder for browser context detection
-        Ok(None)
+    /// JetBrains title bars follow `project – file – IDE Name [path]`, using an
+    /// en/em dash as the separator, which VSCode's `file - project - Visual
+    /// Studio Code` format does not. Combined with the shared Darcula gutter
+    /// styling this discriminates the specific JetBrains product from VSCode
+    /// and from its siblings.
+    fn detect_jetbrains_ide(&self, image: &DynamicImage, visual_elements: &[VisualElement]) -> Option<DetectedApp> {
+        let (ide_type, title_text) = visual_elements.iter().find_map(|element| {
+            let text = element.properties.text_content.as_ref()?;
+            jetbrains_ide_from_title(text).map(|ide_type| (ide_type, text.clone()))
+        })?;
+
+        let mut visual_indicators = vec![VisualIndicator {
+            indicator_type: IndicatorType::WindowTitle,
+            value: title_text,
+            position: BoundingBox {
+                x: 0,
+                y: 0,
+                width: image.width(),
+                height: 0,
+                confidence: 0.9,
+            },
+            confidence: 0.9,
+        }];
+
+        // The title bar alone is already a strong signal; the shared Darcula
+        // gutter/accent coloring below is corroborating, not load-bearing
+        let gutter_score = self
+            .app_signatures
+            .check_color_patterns(image, &jetbrains_darcula_pattern())
+            .unwrap_or(0.0);
+        let mut confidence = 0.75 + gutter_score * 0.2;
+        if gutter_score > 0.05 {
+            visual_indicators.push(VisualIndicator {
+                indicator_type: IndicatorType::ColorScheme,
+                value: "darcula-gutter".to_string(),
+                position: BoundingBox {
+                    x: 0,
+                    y: 0,
+                    width: image.width(),
+                    height: image.height(),
+                    confidence: gutter_score,
+                },
+                confidence: gutter_score,
+            });
+        }
+        confidence = confidence.min(1.0);
+
+        Some(DetectedApp {
+            app_type: AppType::IDE(ide_type),
+            app_name: None,
+            confidence,
+            visual_indicators,
+            screen_region: BoundingBox {
+                x: 0,
+                y: 0,
+                width: image.width(),
+                height: image.height(),
+                confidence,
+            },
+            window_state: WindowState::Focused,
+        })
+    }
+
+    async fn detect_browser_context(&self, image: &DynamicImage, _visual_elements: &[VisualElement]) -> Result<Option<crate::BrowserContext>> {
+        let Some(tabs) = self.detect_tabs(image).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(crate::BrowserContext {
+            browser_type: crate::BrowserType::Other("unknown".to_string()),
+            visible_tabs: tabs,
+            current_url: None,
+            page_type: crate::PageType::Other,
+            navigation_elements: Vec::new(),
+        }))
+    }
+
+    /// Locates the tab strip along the top of the window, OCRs each tab's
+    /// title, and marks the active tab by its distinct background elevation.
+    /// Returns `None` if no tab bar is found (e.g. the window isn't a browser).
+    async fn detect_tabs(&self, image: &DynamicImage) -> Result<Option<Vec<TabInfo>>> {
+        let segments = find_tab_segments(image);
+        if segments.is_empty() {
+            return Ok(None);
+        }
+
+        let active_index = active_tab_index(image, &segments);
+
+        let ocr_config = savant_ocr::OCRConfig::default();
+        let ocr = savant_ocr::OCRProcessor::new(ocr_config)?;
+
+        let mut tabs = Vec::with_capacity(segments.len());
+        for (i, segment) in segments.iter().enumerate() {
+            let roi = savant_ocr::BoundingBox {
+                x: segment.x,
+                y: segment.y,
+                width: segment.width,
+                height: segment.height,
+            };
+            let result = ocr.process_region(image, roi).await?;
+            let title = result
+                .text_blocks
+                .iter()
+                .map(|block| block.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim()
+                .to_string();
+
+            tabs.push(TabInfo {
+                title,
+                url: None,
+                is_active: i == active_index,
+                favicon: None,
+            });
+        }
+
+        Ok(Some(tabs))
     }
 
     async fn detect_ide_context(&self, _image: &DynamicImage, _visual_elements: &[VisualElement]) -> Result<Option<crate::IDEContext>> {
@@ -400,3 +526,114 @@ impl AppSignatureDatabase {
         diff_r <= tolerance as i16 && diff_g <= tolerance as i16 && diff_b <= tolerance as i16
     }
 }
+
+/// Parses a JetBrains-style title bar (`project – file – IDE Name`) and
+/// returns the specific `IDEType`, or `None` if the text doesn't match the
+/// expected dash-separated format.
+fn jetbrains_ide_from_title(title: &str) -> Option<IDEType> {
+    let last_segment = title.split(['–', '—']).last()?.trim();
+    if !title.contains('–') && !title.contains('—') {
+        return None;
+    }
+
+    if last_segment.starts_with("RustRover") {
+        Some(IDEType::RustRover)
+    } else if last_segment.starts_with("PyCharm") {
+        Some(IDEType::PyCharm)
+    } else if last_segment.starts_with("IntelliJ IDEA") {
+        Some(IDEType::IntelliJ)
+    } else {
+        None
+    }
+}
+
+/// JetBrains' shared Darcula theme: near-black editor background with a
+/// slightly lighter gutter strip
+fn jetbrains_darcula_pattern() -> Vec<ColorPattern> {
+    vec![ColorPattern {
+        dominant_colors: vec![[43, 43, 43], [49, 51, 53]],
+        tolerance: 10,
+    }]
+}
+
+/// Tab bars sit in this y-range below the window's title bar
+const TAB_BAR_Y: u32 = 20;
+const TAB_BAR_HEIGHT: u32 = 34;
+/// Below this width a "tab" is almost certainly noise rather than an
+/// overflowing real tab
+const MIN_TAB_WIDTH: u32 = 40;
+/// A column is a tab boundary if its average color jumps by more than this
+const TAB_EDGE_THRESHOLD: i16 = 18;
+
+/// Scans a horizontal strip for vertical color-boundary columns and returns
+/// one `BoundingBox` per tab. A single segment spanning almost the full
+/// width is treated as one maximized tab; many narrow segments indicate
+/// overflow, and are still each reported individually.
+fn find_tab_segments(image: &DynamicImage) -> Vec<BoundingBox> {
+    let (width, height) = image.dimensions();
+    if height <= TAB_BAR_Y + TAB_BAR_HEIGHT || width < MIN_TAB_WIDTH {
+        return Vec::new();
+    }
+
+    let rgba = image.to_rgba8();
+    let sample_y = TAB_BAR_Y + TAB_BAR_HEIGHT / 2;
+
+    let mut boundaries = vec![0u32];
+    let mut prev = rgba.get_pixel(0, sample_y);
+    for x in 1..width {
+        let pixel = rgba.get_pixel(x, sample_y);
+        let diff = (pixel[0] as i16 - prev[0] as i16).abs()
+            + (pixel[1] as i16 - prev[1] as i16).abs()
+            + (pixel[2] as i16 - prev[2] as i16).abs();
+        if diff > TAB_EDGE_THRESHOLD {
+            boundaries.push(x);
+        }
+        prev = pixel;
+    }
+    boundaries.push(width);
+
+    boundaries
+        .windows(2)
+        .filter_map(|pair| {
+            let (start, end) = (pair[0], pair[1]);
+            let segment_width = end.saturating_sub(start);
+            if segment_width < MIN_TAB_WIDTH {
+                return None;
+            }
+            Some(BoundingBox {
+                x: start,
+                y: TAB_BAR_Y,
+                width: segment_width,
+                height: TAB_BAR_HEIGHT,
+                confidence: 0.6,
+            })
+        })
+        .collect()
+}
+
+/// The active tab's background is elevated relative to its inactive
+/// siblings, so it stands out as the outlier against the modal brightness
+/// of the strip rather than matching a fixed color.
+fn active_tab_index(image: &DynamicImage, segments: &[BoundingBox]) -> usize {
+    let rgba = image.to_rgba8();
+    let brightness = |segment: &BoundingBox| -> f32 {
+        let cx = segment.x + segment.width / 2;
+        let cy = segment.y + segment.height / 2;
+        let pixel = rgba.get_pixel(cx.min(rgba.width() - 1), cy.min(rgba.height() - 1));
+        (pixel[0] as f32 + pixel[1] as f32 + pixel[2] as f32) / 3.0
+    };
+
+    let brightnesses: Vec<f32> = segments.iter().map(brightness).collect();
+    let mut sorted = brightnesses.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median = sorted[sorted.len() / 2];
+
+    brightnesses
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            (*a - median).abs().partial_cmp(&(*b - median).abs()).unwrap()
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
@@ -2,16 +2,22 @@ use anyhow::Result;
 use chrono::{DateTime, Utc};
 use image::DynamicImage;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
 
 pub mod detector;
 pub mod classifier;
 pub mod analyzer;
 pub mod patterns;
+pub mod meeting;
+pub mod overlay;
 
 pub use detector::{ObjectDetector, UIDetector, AppDetector, DetectionResult};
 pub use classifier::{ActivityClassifier, Activity, ActivityClassification};
-pub use analyzer::{ContextAnalyzer, VisualContext, Evidence};
+pub use analyzer::{ContextAnalyzer, VisualContext, Evidence, AttentionArea};
 pub use patterns::{PatternMatcher, VisualPattern};
+pub use meeting::{MeetingStateTracker, MeetingTransition, MeetingTransitionEvent};
+pub use overlay::draw_overlay;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoundingBox {
@@ -56,6 +62,9 @@ pub struct ElementProperties {
     pub is_interactive: bool,
     pub state: Option<String>, // "active", "disabled", "selected", etc.
     pub app_context: Option<String>,
+    /// Whether this element is known to hold sensitive input (e.g. a
+    /// password field), marked by a detector that recognizes the context.
+    pub is_sensitive: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -310,17 +319,31 @@ pub struct VisionAnalyzer {
 }
 
 impl VisionAnalyzer {
-    pub fn new(_config: VisionConfig) -> Result<Self> {
+    pub fn new(config: VisionConfig) -> Result<Self> {
+        let activity_classifier = match &config.rules_path {
+            Some(path) => ActivityClassifier::from_rules_file(path)?,
+            None => ActivityClassifier::new(),
+        };
+
         Ok(Self {
             object_detector: ObjectDetector::new(),
             ui_detector: UIDetector::new(),
             app_detector: AppDetector::new(),
-            activity_classifier: ActivityClassifier::new(),
+            activity_classifier,
             context_analyzer: ContextAnalyzer::new(),
-            pattern_matcher: PatternMatcher::new(),
+            pattern_matcher: PatternMatcher::with_threshold(config.pattern_matching_threshold),
         })
     }
 
+    /// Builds a `VisionAnalyzer` once and wraps it in an `Arc` so the
+    /// detector setup cost (signature tables, pattern compilation) is paid a
+    /// single time and the analyzer can be shared across tasks processing
+    /// thousands of frames. `analyze_screen` takes `&self`, so the `Arc` can
+    /// be cloned cheaply per frame instead of reconstructing the analyzer.
+    pub fn shared(config: VisionConfig) -> Result<Arc<Self>> {
+        Ok(Arc::new(Self::new(config)?))
+    }
+
     pub async fn analyze_screen(&self, image: &DynamicImage) -> Result<ScreenAnalysis> {
         let start_time = std::time::Instant::now();
 
@@ -380,6 +403,9 @@ pub struct VisionConfig {
     pub enable_activity_classification: bool,
     pub enable_ui_analysis: bool,
     pub pattern_matching_threshold: f32,
+    /// Path to a TOML/JSON rules file merged with the built-in activity-classification
+    /// rules. See [`ActivityClassifier::from_rules_file`].
+    pub rules_path: Option<PathBuf>,
 }
 
 impl Default for VisionConfig {
@@ -389,6 +415,7 @@ impl Default for VisionConfig {
             enable_activity_classification: true,
             enable_ui_analysis: true,
             pattern_matching_threshold: 0.6,
+            rules_path: None,
         }
     }
 }
@@ -2,6 +2,7 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use image::ImageReader;
 use savant_vision::{VisionAnalyzer, ScreenAnalysis, VisionConfig};
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::path::PathBuf;
 use tokio;
@@ -16,6 +17,12 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Generate a shell completion script (bash, zsh, fish, or powershell) to stdout
+    Completions {
+        #[arg(value_enum)]
+        shell: savant_core::completions::Shell,
+    },
+
     /// Analyze a screenshot image
     Analyze {
         /// Path to the image file
@@ -37,6 +44,11 @@ enum Commands {
         /// Enable UI element detection
         #[arg(long)]
         detect_ui: bool,
+
+        /// Path to a TOML/JSON rules file merged with the built-in
+        /// activity-classification rules
+        #[arg(long)]
+        rules: Option<PathBuf>,
     },
 
     /// Process images from stdin (for pipeline usage)
@@ -66,6 +78,25 @@ enum Commands {
         /// Number of iterations
         #[arg(short, long, default_value = "10")]
         iterations: u32,
+
+        /// Previously-saved benchmark results (JSON) to compare this run against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Write this run's benchmark results as JSON to this path
+        #[arg(long)]
+        save: Option<PathBuf>,
+    },
+
+    /// Draw a debug overlay of detected visual elements and attention areas
+    Overlay {
+        /// Path to the image file
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// Path to write the annotated PNG to
+        #[arg(short, long)]
+        output: PathBuf,
     },
 }
 
@@ -75,15 +106,22 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    if let Commands::Completions { shell } = &cli.command {
+        savant_core::completions::print_completions::<Cli>(*shell);
+        return Ok(());
+    }
+
     match cli.command {
+        Commands::Completions { .. } => unreachable!("handled above"),
         Commands::Analyze {
             input,
             format,
             detect_apps,
             classify_activity,
             detect_ui,
+            rules,
         } => {
-            analyze_image(input, format, detect_apps, classify_activity, detect_ui).await?;
+            analyze_image(input, format, detect_apps, classify_activity, detect_ui, rules).await?;
         }
         Commands::Process { format, config } => {
             process_from_stdin(format, config).await?;
@@ -91,8 +129,11 @@ async fn main() -> Result<()> {
         Commands::Test { input } => {
             test_vision_analysis(input).await?;
         }
-        Commands::Benchmark { input, iterations } => {
-            benchmark_analysis(input, iterations).await?;
+        Commands::Benchmark { input, iterations, baseline, save } => {
+            benchmark_analysis(input, iterations, baseline, save).await?;
+        }
+        Commands::Overlay { input, output } => {
+            draw_overlay_image(input, output).await?;
         }
     }
 
@@ -105,12 +146,14 @@ async fn analyze_image(
     _detect_apps: bool,
     _classify_activity: bool,
     _detect_ui: bool,
+    rules: Option<PathBuf>,
 ) -> Result<()> {
     // Load image
     let image = ImageReader::open(&input)?.decode()?;
 
     // Create vision analyzer
-    let analyzer = VisionAnalyzer::new(VisionConfig::default())?;
+    let config = VisionConfig { rules_path: rules, ..VisionConfig::default() };
+    let analyzer = VisionAnalyzer::new(config)?;
     let analysis = analyzer.analyze_screen(&image).await?;
 
     // Output results based on format
@@ -261,7 +304,81 @@ async fn test_vision_analysis(input: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
-async fn benchmark_analysis(input: PathBuf, iterations: u32) -> Result<()> {
+/// A benchmark run's results, serializable so `--save`/`--baseline` can persist and
+/// later reload them across separate `savant-vision benchmark` invocations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BenchmarkResults {
+    iterations: u32,
+    successful_runs: u32,
+    average_time_ms: f64,
+    images_per_second: f64,
+    total_time_ms: f64,
+}
+
+impl BenchmarkResults {
+    fn pass_rate(&self) -> f64 {
+        if self.iterations == 0 {
+            0.0
+        } else {
+            self.successful_runs as f64 / self.iterations as f64
+        }
+    }
+}
+
+/// The change between a `baseline` and a `current` `BenchmarkResults`, for a run to
+/// compare against a previously-saved one.
+#[derive(Debug, Clone, PartialEq)]
+struct BenchmarkDelta {
+    speed_change_pct: f64,
+    pass_rate_delta_pp: f64,
+}
+
+impl BenchmarkDelta {
+    fn is_faster(&self) -> bool {
+        self.speed_change_pct >= 0.0
+    }
+}
+
+/// Positive `speed_change_pct` means `current` is faster than `baseline`; negative
+/// means it's slower. `pass_rate_delta_pp` is the change in pass rate, in percentage
+/// points.
+fn benchmark_delta(baseline: &BenchmarkResults, current: &BenchmarkResults) -> BenchmarkDelta {
+    let speed_change_pct = if baseline.average_time_ms > 0.0 {
+        (baseline.average_time_ms - current.average_time_ms) / baseline.average_time_ms * 100.0
+    } else {
+        0.0
+    };
+    let pass_rate_delta_pp = (current.pass_rate() - baseline.pass_rate()) * 100.0;
+
+    BenchmarkDelta { speed_change_pct, pass_rate_delta_pp }
+}
+
+fn print_benchmark_delta(baseline: &BenchmarkResults, current: &BenchmarkResults) {
+    let delta = benchmark_delta(baseline, current);
+    let direction = if delta.is_faster() { "faster" } else { "slower" };
+
+    println!("\n=== Comparison to baseline ===");
+    println!(
+        "Average time: {:.1}ms -> {:.1}ms ({:.1}% {})",
+        baseline.average_time_ms,
+        current.average_time_ms,
+        delta.speed_change_pct.abs(),
+        direction
+    );
+    println!(
+        "Pass rate: {:.1}% -> {:.1}% ({:+.1}pp)",
+        baseline.pass_rate() * 100.0,
+        current.pass_rate() * 100.0,
+        delta.pass_rate_delta_pp
+    );
+}
+
+async fn benchmark_analysis(
+    input: PathBuf,
+    iterations: u32,
+    baseline: Option<PathBuf>,
+    save: Option<PathBuf>,
+) -> Result<()> {
     println!("Benchmarking vision analysis performance...");
 
     let image = ImageReader::open(&input)?.decode()?;
@@ -287,16 +404,144 @@ async fn benchmark_analysis(input: PathBuf, iterations: u32) -> Result<()> {
         }
     }
 
-    if successful_runs > 0 {
-        let average_time = total_time / successful_runs;
-        println!("\n=== Benchmark Results ===");
-        println!("Successful runs: {}/{}", successful_runs, iterations);
-        println!("Average processing time: {:?}", average_time);
-        println!("Images per second: {:.2}", 1000.0 / average_time.as_millis() as f64);
-        println!("Total time: {:?}", total_time);
-    } else {
+    if successful_runs == 0 {
         println!("No successful runs completed.");
+        return Ok(());
+    }
+
+    let average_time = total_time / successful_runs;
+    let results = BenchmarkResults {
+        iterations,
+        successful_runs,
+        average_time_ms: average_time.as_secs_f64() * 1000.0,
+        images_per_second: 1000.0 / (average_time.as_secs_f64() * 1000.0),
+        total_time_ms: total_time.as_secs_f64() * 1000.0,
+    };
+
+    println!("\n=== Benchmark Results ===");
+    println!("Successful runs: {}/{}", results.successful_runs, results.iterations);
+    println!("Average processing time: {:.1}ms", results.average_time_ms);
+    println!("Images per second: {:.2}", results.images_per_second);
+    println!("Total time: {:.1}ms", results.total_time_ms);
+
+    if let Some(baseline_path) = baseline {
+        let baseline_contents = std::fs::read_to_string(&baseline_path).map_err(|e| {
+            anyhow::anyhow!("Failed to read baseline {}: {}", baseline_path.display(), e)
+        })?;
+        let baseline_results: BenchmarkResults = serde_json::from_str(&baseline_contents)
+            .map_err(|e| anyhow::anyhow!("Failed to parse baseline {}: {}", baseline_path.display(), e))?;
+        print_benchmark_delta(&baseline_results, &results);
+    }
+
+    if let Some(save_path) = save {
+        std::fs::write(&save_path, serde_json::to_string_pretty(&results)?)
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", save_path.display(), e))?;
+        println!("\nSaved benchmark results to {}", save_path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_delta_reports_faster_run_and_unchanged_pass_rate() {
+        let baseline = BenchmarkResults {
+            iterations: 10,
+            successful_runs: 10,
+            average_time_ms: 100.0,
+            images_per_second: 10.0,
+            total_time_ms: 1000.0,
+        };
+        let current = BenchmarkResults {
+            iterations: 10,
+            successful_runs: 10,
+            average_time_ms: 80.0,
+            images_per_second: 12.5,
+            total_time_ms: 800.0,
+        };
+
+        let delta = benchmark_delta(&baseline, &current);
+
+        assert!(delta.is_faster());
+        assert_eq!(delta.speed_change_pct, 20.0);
+        assert_eq!(delta.pass_rate_delta_pp, 0.0);
     }
 
+    #[test]
+    fn test_benchmark_delta_reports_slower_run_and_pass_rate_regression() {
+        let baseline = BenchmarkResults {
+            iterations: 10,
+            successful_runs: 10,
+            average_time_ms: 100.0,
+            images_per_second: 10.0,
+            total_time_ms: 1000.0,
+        };
+        let current = BenchmarkResults {
+            iterations: 10,
+            successful_runs: 8,
+            average_time_ms: 150.0,
+            images_per_second: 6.67,
+            total_time_ms: 1200.0,
+        };
+
+        let delta = benchmark_delta(&baseline, &current);
+
+        assert!(!delta.is_faster());
+        assert_eq!(delta.speed_change_pct, -50.0);
+        assert_eq!(delta.pass_rate_delta_pp, -20.0);
+    }
+
+    #[test]
+    fn test_save_and_load_baseline_roundtrips_and_feeds_delta_computation() {
+        let path = std::env::temp_dir()
+            .join(format!("savant_vision_test_baseline_{}.json", std::process::id()));
+
+        let saved = BenchmarkResults {
+            iterations: 5,
+            successful_runs: 5,
+            average_time_ms: 200.0,
+            images_per_second: 5.0,
+            total_time_ms: 1000.0,
+        };
+        std::fs::write(&path, serde_json::to_string_pretty(&saved).unwrap()).unwrap();
+
+        let loaded: BenchmarkResults =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.average_time_ms, saved.average_time_ms);
+
+        // Simulate a mutated (slower) timing for the current run.
+        let current = BenchmarkResults { average_time_ms: 250.0, ..loaded.clone() };
+        let delta = benchmark_delta(&loaded, &current);
+
+        assert!(!delta.is_faster());
+        assert_eq!(delta.speed_change_pct, -25.0);
+    }
+}
+
+async fn draw_overlay_image(input: PathBuf, output: PathBuf) -> Result<()> {
+    let image = ImageReader::open(&input)?.decode()?;
+
+    let analyzer = VisionAnalyzer::new(VisionConfig::default())?;
+    let analysis = analyzer.analyze_screen(&image).await?;
+
+    let overlaid = savant_vision::draw_overlay(
+        &image,
+        &analysis.visual_elements,
+        &analysis.visual_context.attention_areas,
+    );
+    overlaid.save(&output)?;
+
+    println!(
+        "Wrote overlay with {} visual element(s) and {} attention area(s) to {}",
+        analysis.visual_elements.len(),
+        analysis.visual_context.attention_areas.len(),
+        output.display()
+    );
+
     Ok(())
 }
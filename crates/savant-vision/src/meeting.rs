@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::MeetingContext;
+
+/// A single state change between two consecutive `MeetingContext` readings,
+/// intended to feed the sync crate's `AudioEventType`/`VideoEventType`
+/// pipelines (e.g. `MicMuted` maps to an audio event, `ScreenShareStarted`
+/// to a video event).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingTransitionEvent {
+    pub transition: MeetingTransition,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeetingTransition {
+    MicMuted,
+    MicUnmuted,
+    CameraOn,
+    CameraOff,
+    ScreenShareStarted,
+    ScreenShareStopped,
+    RecordingStarted,
+    RecordingStopped,
+}
+
+/// Diffs consecutive `MeetingContext` frames and emits the transitions
+/// between them, so callers only hear about a mute/camera/share toggle once
+/// rather than re-deriving it from the raw booleans every frame.
+#[derive(Debug, Default)]
+pub struct MeetingStateTracker {
+    last_context: Option<MeetingContext>,
+}
+
+impl MeetingStateTracker {
+    pub fn new() -> Self {
+        Self { last_context: None }
+    }
+
+    /// Compares `context` against the last-seen context and returns any
+    /// transitions, then stores `context` as the new baseline.
+    pub fn diff(&mut self, context: &MeetingContext, timestamp: DateTime<Utc>) -> Vec<MeetingTransitionEvent> {
+        let mut transitions = Vec::new();
+
+        if let Some(prev) = &self.last_context {
+            if prev.microphone_on && !context.microphone_on {
+                transitions.push(MeetingTransition::MicMuted);
+            } else if !prev.microphone_on && context.microphone_on {
+                transitions.push(MeetingTransition::MicUnmuted);
+            }
+
+            if prev.camera_on && !context.camera_on {
+                transitions.push(MeetingTransition::CameraOff);
+            } else if !prev.camera_on && context.camera_on {
+                transitions.push(MeetingTransition::CameraOn);
+            }
+
+            if prev.is_screen_sharing && !context.is_screen_sharing {
+                transitions.push(MeetingTransition::ScreenShareStopped);
+            } else if !prev.is_screen_sharing && context.is_screen_sharing {
+                transitions.push(MeetingTransition::ScreenShareStarted);
+            }
+
+            if prev.is_recording && !context.is_recording {
+                transitions.push(MeetingTransition::RecordingStopped);
+            } else if !prev.is_recording && context.is_recording {
+                transitions.push(MeetingTransition::RecordingStarted);
+            }
+        }
+
+        self.last_context = Some(context.clone());
+
+        transitions
+            .into_iter()
+            .map(|transition| MeetingTransitionEvent { transition, timestamp })
+            .collect()
+    }
+}
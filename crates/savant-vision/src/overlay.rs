@@ -0,0 +1,125 @@
+//! Debug overlay drawing for the vision pipeline.
+//!
+//! Renders detected [`VisualElement`] bounding boxes (color-coded by [`ElementType`])
+//! and [`AttentionArea`]s onto a copy of the source image, so a human can visually
+//! inspect what a frame was actually detected as without parsing raw JSON.
+
+use crate::{AttentionArea, ElementType, VisualElement};
+use image::{DynamicImage, Rgba, RgbaImage};
+use imageproc::drawing::draw_hollow_rect_mut;
+use imageproc::rect::Rect;
+
+/// Attention areas are drawn in a single fixed color, distinct from every
+/// `element_color`, so they read as a separate overlay layer rather than another
+/// element type.
+fn attention_area_color() -> Rgba<u8> {
+    Rgba([255, 255, 255, 255])
+}
+
+/// Outline color for each `ElementType`'s bounding boxes, so different kinds of
+/// detected elements are visually distinguishable in the overlay.
+fn element_color(element_type: &ElementType) -> Rgba<u8> {
+    match element_type {
+        ElementType::Window => Rgba([255, 0, 0, 255]),
+        ElementType::Button => Rgba([0, 200, 0, 255]),
+        ElementType::TextField => Rgba([0, 128, 255, 255]),
+        ElementType::Image => Rgba([255, 0, 255, 255]),
+        ElementType::Video => Rgba([255, 128, 0, 255]),
+        ElementType::Menu => Rgba([128, 0, 255, 255]),
+        ElementType::Icon => Rgba([0, 255, 255, 255]),
+        ElementType::Text => Rgba([255, 255, 0, 255]),
+        ElementType::StatusBar => Rgba([128, 128, 0, 255]),
+        ElementType::Toolbar => Rgba([0, 128, 128, 255]),
+        ElementType::Browser => Rgba([255, 192, 203, 255]),
+        ElementType::IDE => Rgba([0, 0, 255, 255]),
+        ElementType::VideoCall => Rgba([255, 165, 0, 255]),
+        ElementType::Chat => Rgba([0, 255, 128, 255]),
+        ElementType::Terminal => Rgba([160, 160, 160, 255]),
+    }
+}
+
+/// Draws `visual_elements`' bounding boxes (color-coded by `ElementType`) and
+/// `attention_areas` onto a copy of `image`. Boxes are clipped to the image bounds by
+/// the underlying `imageproc` drawing calls rather than causing an error.
+pub fn draw_overlay(
+    image: &DynamicImage,
+    visual_elements: &[VisualElement],
+    attention_areas: &[AttentionArea],
+) -> RgbaImage {
+    let mut canvas = image.to_rgba8();
+
+    for element in visual_elements {
+        let rect = Rect::at(element.bounding_box.x as i32, element.bounding_box.y as i32)
+            .of_size(element.bounding_box.width.max(1), element.bounding_box.height.max(1));
+        draw_hollow_rect_mut(&mut canvas, rect, element_color(&element.element_type));
+    }
+
+    for area in attention_areas {
+        let rect = Rect::at(area.region.x as i32, area.region.y as i32)
+            .of_size(area.region.width.max(1), area.region.height.max(1));
+        draw_hollow_rect_mut(&mut canvas, rect, attention_area_color());
+    }
+
+    canvas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{AttentionReason, ContentArea, ContentType};
+    use crate::{BoundingBox, ElementProperties};
+    use image::{GenericImageView, ImageBuffer, Rgb};
+
+    fn blank_image(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(ImageBuffer::from_pixel(width, height, Rgb([10, 10, 10])))
+    }
+
+    fn sample_element(x: u32, y: u32, width: u32, height: u32) -> VisualElement {
+        VisualElement {
+            element_type: ElementType::Button,
+            bounding_box: BoundingBox { x, y, width, height, confidence: 1.0 },
+            properties: ElementProperties {
+                color_scheme: None,
+                text_content: None,
+                is_interactive: true,
+                state: None,
+                app_context: None,
+                is_sensitive: false,
+            },
+            confidence: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_overlay_draws_into_element_bounding_box_region() {
+        let image = blank_image(100, 100);
+        let element = sample_element(10, 10, 30, 20);
+
+        let overlaid = draw_overlay(&image, &[element], &[]);
+
+        assert_eq!(overlaid.dimensions(), (100, 100));
+        // The box outline is drawn along its top edge, which must now differ from the
+        // untouched background color.
+        let outline_pixel = overlaid.get_pixel(15, 10);
+        assert_ne!(outline_pixel.0, [10, 10, 10, 255]);
+
+        // A point well outside every box/attention-area stays untouched.
+        let untouched_pixel = overlaid.get_pixel(90, 90);
+        assert_eq!(untouched_pixel.0, [10, 10, 10, 255]);
+    }
+
+    #[test]
+    fn test_overlay_draws_attention_area_region() {
+        let image = blank_image(100, 100);
+        let attention_area = AttentionArea {
+            region: ContentArea { x: 50, y: 50, width: 20, height: 20, content_type: ContentType::Unknown },
+            attention_score: 0.9,
+            reason: AttentionReason::BrightColors,
+        };
+
+        let overlaid = draw_overlay(&image, &[], &[attention_area]);
+
+        let outline_pixel = overlaid.get_pixel(55, 50);
+        assert_eq!(outline_pixel.0, [255, 255, 255, 255]);
+    }
+}
@@ -2,6 +2,16 @@ use anyhow::Result;
 use image::DynamicImage;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Default `VisionConfig::pattern_matching_threshold`, used when a `PatternMatcher` is
+/// built via [`PatternMatcher::new`] rather than [`PatternMatcher::with_threshold`].
+const DEFAULT_PATTERN_MATCHING_THRESHOLD: f32 = 0.6;
+/// Number of distinct frame regions to keep match results cached for.
+const MATCH_CACHE_CAPACITY: usize = 256;
+/// Side length of the thumbnail used to compute the perceptual hash cache key.
+const PERCEPTUAL_HASH_SIZE: u32 = 8;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisualPattern {
@@ -49,23 +59,49 @@ pub struct PatternMatch {
     pub bounding_box: Option<crate::BoundingBox>,
 }
 
+/// Patterns are the same fixed set for every `PatternMatcher`, so they're compiled into
+/// this map once and cheaply cloned per instance instead of being rebuilt every time
+/// `PatternMatcher::new` runs (which happens on every `VisionAnalyzer::new`).
+static BUILTIN_PATTERNS: OnceLock<HashMap<String, VisualPattern>> = OnceLock::new();
+
+fn builtin_patterns() -> &'static HashMap<String, VisualPattern> {
+    BUILTIN_PATTERNS.get_or_init(|| {
+        let mut patterns = HashMap::new();
+        PatternMatcher::load_builtin_patterns(&mut patterns);
+        patterns
+    })
+}
+
 #[derive(Debug)]
 pub struct PatternMatcher {
     patterns: HashMap<String, VisualPattern>,
     #[allow(dead_code)]
     app_patterns: AppPatternDatabase,
+    /// Match results keyed by a perceptual hash of the input image, so repeated calls
+    /// on similar frames (e.g. consecutive frames of a mostly-static screen) skip
+    /// re-evaluating every pattern indicator. Note the cache key ignores
+    /// `PatternMatchContext`, so a cache hit assumes the accompanying text/visual
+    /// elements are also effectively unchanged between similar frames.
+    match_cache: Arc<Mutex<lru::LruCache<u64, Vec<PatternMatch>>>>,
+    /// Mirrors `VisionConfig::pattern_matching_threshold`: the minimum confidence a
+    /// match must reach to be returned, on top of each pattern's own
+    /// `confidence_threshold`.
+    threshold: f32,
 }
 
 impl PatternMatcher {
     pub fn new() -> Self {
-        let mut patterns = HashMap::new();
-
-        // Load built-in patterns
-        Self::load_builtin_patterns(&mut patterns);
+        Self::with_threshold(DEFAULT_PATTERN_MATCHING_THRESHOLD)
+    }
 
+    pub fn with_threshold(threshold: f32) -> Self {
         Self {
-            patterns,
+            patterns: builtin_patterns().clone(),
             app_patterns: AppPatternDatabase::new(),
+            match_cache: Arc::new(Mutex::new(lru::LruCache::new(
+                NonZeroUsize::new(MATCH_CACHE_CAPACITY).unwrap(),
+            ))),
+            threshold,
         }
     }
 
@@ -224,17 +260,26 @@ impl PatternMatcher {
     }
 
     pub async fn match_patterns(&self, image: &DynamicImage, context: &PatternMatchContext) -> Result<Vec<PatternMatch>> {
+        let cache_key = perceptual_hash(image);
+        if let Some(cached) = self.match_cache.lock().unwrap().get(&cache_key) {
+            return Ok(cached.clone());
+        }
+
         let mut matches = Vec::new();
 
         for pattern in self.patterns.values() {
             if let Some(pattern_match) = self.evaluate_pattern(image, pattern, context).await? {
-                matches.push(pattern_match);
+                if pattern_match.confidence >= self.threshold {
+                    matches.push(pattern_match);
+                }
             }
         }
 
         // Sort by confidence
         matches.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
 
+        self.match_cache.lock().unwrap().put(cache_key, matches.clone());
+
         Ok(matches)
     }
 
@@ -487,3 +532,79 @@ impl AppPatternDatabase {
 
     // Methods for loading and matching app-specific patterns would go here
 }
+
+/// Average hash (aHash) of `image`: shrink to an 8x8 grayscale thumbnail, then set bit
+/// `i` when thumbnail pixel `i` is at or above the thumbnail's mean brightness. Frames
+/// that look alike (consecutive captures of a mostly-static screen) hash identically or
+/// near-identically, which is what [`PatternMatcher::match_patterns`] relies on to reuse
+/// cached match results instead of re-evaluating every pattern.
+fn perceptual_hash(image: &DynamicImage) -> u64 {
+    let thumbnail = image
+        .resize_exact(PERCEPTUAL_HASH_SIZE, PERCEPTUAL_HASH_SIZE, image::imageops::FilterType::Triangle)
+        .to_luma8();
+
+    let pixels: Vec<u32> = thumbnail.pixels().map(|p| p[0] as u32).collect();
+    let average = pixels.iter().sum::<u32>() / pixels.len() as u32;
+
+    pixels.iter().enumerate().fold(0u64, |hash, (i, &pixel)| {
+        if pixel >= average {
+            hash | (1 << i)
+        } else {
+            hash
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgba};
+    use std::time::Instant;
+
+    fn sample_image() -> DynamicImage {
+        let buffer = ImageBuffer::from_fn(400, 400, |x, y| {
+            Rgba([((x + y) % 256) as u8, ((x * 2) % 256) as u8, ((y * 3) % 256) as u8, 255])
+        });
+        DynamicImage::ImageRgba8(buffer)
+    }
+
+    fn empty_context() -> PatternMatchContext {
+        PatternMatchContext {
+            extracted_text: Vec::new(),
+            visual_elements: Vec::new(),
+            screen_width: 400,
+            screen_height: 400,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repeated_matching_on_similar_frames_is_faster_with_cache() {
+        let matcher = PatternMatcher::new();
+        let image = sample_image();
+        let context = empty_context();
+
+        let start_uncached = Instant::now();
+        let first = matcher.match_patterns(&image, &context).await.unwrap();
+        let uncached_duration = start_uncached.elapsed();
+
+        let start_cached = Instant::now();
+        let second = matcher.match_patterns(&image, &context).await.unwrap();
+        let cached_duration = start_cached.elapsed();
+
+        assert_eq!(first.len(), second.len());
+        assert!(
+            cached_duration < uncached_duration,
+            "expected a cache hit ({cached_duration:?}) to be faster than the initial match ({uncached_duration:?})"
+        );
+    }
+
+    #[test]
+    fn test_perceptual_hash_matches_identical_frames_and_differs_for_distinct_ones() {
+        let image_a = sample_image();
+        let image_b = sample_image();
+        assert_eq!(perceptual_hash(&image_a), perceptual_hash(&image_b));
+
+        let solid_white = DynamicImage::ImageRgba8(ImageBuffer::from_pixel(400, 400, Rgba([255, 255, 255, 255])));
+        assert_ne!(perceptual_hash(&image_a), perceptual_hash(&solid_white));
+    }
+}
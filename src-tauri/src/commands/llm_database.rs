@@ -8,7 +8,7 @@ use std::sync::Arc;
 use tauri::State;
 use tokio::sync::Mutex;
 
-use savant_db::{TranscriptDatabase, QueryProcessor, QueryOptimizer, UserFeedback, LLMQueryResult, LLMConfig, LLMClientFactory};
+use savant_db::{TranscriptDatabase, QueryProcessor, QueryOptimizer, QuerySecurityManager, UserFeedback, LLMQueryResult, LLMConfig, LLMClientFactory};
 use savant_mcp::MCPServer;
 
 /// Shared MCP server state
@@ -20,6 +20,9 @@ pub struct NaturalQueryRequest {
     pub query: String,
     pub session_id: Option<String>,
     pub include_context: Option<bool>,
+    /// If true, return the generated SQL and bound parameters without
+    /// executing the query, so a cautious user can review it first.
+    pub preview: Option<bool>,
 }
 
 /// Enhanced response from natural language database query
@@ -38,6 +41,8 @@ pub struct NaturalQueryResponse {
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suggestions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<String>,
 }
 
 /// MCP server status
@@ -70,16 +75,28 @@ pub async fn natural_language_query(
     let query_processor = QueryProcessor::new(database.pool.clone(), llm_client.clone());
     
     let session_id = request.session_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-    
+    let preview = request.preview.unwrap_or(false);
+
     // Process query with LLM-powered understanding
     match query_processor.process_query(&request.query, &session_id).await {
         Ok(llm_result) => {
             // Execute the structured query (placeholder - would need actual execution logic)
             let execution_time = start_time.elapsed().as_millis() as u64;
-            
+
+            let complexity = if preview {
+                let security = QuerySecurityManager::read_only();
+                Some(format!("{:?}", security.estimate_query_cost(&llm_result.sql_query)))
+            } else {
+                None
+            };
+
             // Format results for response
-            let summary = format_llm_query_summary(&llm_result, &request.query, execution_time);
-            
+            let summary = if preview {
+                format!("Preview: {}", format_llm_query_summary(&llm_result, &request.query, execution_time))
+            } else {
+                format_llm_query_summary(&llm_result, &request.query, execution_time)
+            };
+
             Ok(NaturalQueryResponse {
                 success: true,
                 results: serde_json::json!({
@@ -97,11 +114,12 @@ pub async fn natural_language_query(
                 session_id: Some(session_id),
                 error: None,
                 suggestions: None,
+                complexity,
             })
         }
         Err(e) => {
             let execution_time = start_time.elapsed().as_millis() as u64;
-            
+
             Ok(NaturalQueryResponse {
                 success: false,
                 results: serde_json::Value::Null,
@@ -114,6 +132,7 @@ pub async fn natural_language_query(
                 session_id: Some(session_id),
                 error: Some(e.to_string()),
                 suggestions: None,
+                complexity: None,
             })
         }
     }
@@ -181,20 +200,30 @@ pub async fn get_mcp_server_status(
 pub async fn test_database_connection(
     database: State<'_, Arc<TranscriptDatabase>>,
 ) -> Result<serde_json::Value, String> {
+    let health = database.health_check().await.map_err(|e| e.to_string())?;
+    if !health.healthy {
+        return Ok(serde_json::json!({
+            "success": false,
+            "error": health.error,
+            "latency_ms": health.latency_ms
+        }));
+    }
+
     // Test basic database operations
     match database.get_speaker_stats().await {
         Ok(stats) => {
             let conversations = database.list_conversations(Some(5)).await
                 .unwrap_or_default();
-            
+
             Ok(serde_json::json!({
                 "success": true,
                 "speaker_count": stats.len(),
                 "conversation_count": conversations.len(),
                 "database_path": "Connected successfully",
+                "latency_ms": health.latency_ms,
                 "capabilities": [
                     "natural_language_queries",
-                    "speaker_analytics", 
+                    "speaker_analytics",
                     "semantic_search",
                     "conversation_analysis"
                 ]
@@ -250,7 +279,7 @@ pub async fn search_conversations(
     let search_limit = limit.unwrap_or(20);
     
     // Try semantic search first, fall back to text search
-    let results = database.text_search(&query, search_limit).await
+    let results = database.text_search(&query, search_limit, savant_db::DEFAULT_CONTEXT_CHARS).await
         .map_err(|e| e.to_string())?;
     
     let formatted_results: Vec<serde_json::Value> = results.into_iter().map(|result| {
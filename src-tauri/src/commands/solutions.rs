@@ -140,6 +140,7 @@ pub async fn process_screen_capture(
                 detected_applications: vec![],
                 activity_classification: None,
                 visual_context: None,
+                frame_format: savant_video::FrameFormat::Png,
             },
         };
 